@@ -0,0 +1,270 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+use crate::native::{Range, Vec};
+use crate::raw_token::RawToken;
+
+const MAX_SOURCE_SIZE: usize = 1024;
+const MAX_SCOPE_LEVEL: usize = 16;
+const MAX_LINE_COUNT: usize = 8;
+const VARIANT_COUNT: u8 = 30;
+
+impl<'a> Arbitrary<'a> for RawToken<Range<usize>, Vec<Range<usize>>> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		let source_size = u.int_in_range(0..=MAX_SOURCE_SIZE)?;
+		let raw_token = match u.int_in_range(0..=VARIANT_COUNT - 1)? {
+			0 => Self::Empty,
+			1 => Self::Invalid,
+			2 => Self::ScopeLevel(u.int_in_range(0..=MAX_SCOPE_LEVEL)?),
+			3 => Self::Block {
+				lines: arbitrary_lines(u, source_size)?,
+				tab_level: u.int_in_range(0..=MAX_SCOPE_LEVEL)?
+			},
+			4 => Self::LineComment(arbitrary_range(u, 0, source_size)?),
+			5 => Self::BlockComment(arbitrary_lines(u, source_size)?),
+			6 => Self::Simplex(arbitrary_range(u, 0, source_size)?),
+			7 => Self::Complex(arbitrary_range(u, 0, source_size)?),
+			8 => {
+				let label = arbitrary_range(u, 0, source_size)?;
+				let content = arbitrary_range(u, label.end, source_size)?;
+				Self::Attacher(label, content)
+			},
+			9 => Self::AttacherNoContent(arbitrary_range(u, 0, source_size)?),
+			10 => Self::LineOthertongue(arbitrary_range(u, 0, source_size)?),
+			11 => Self::BlockOthertongue(arbitrary_lines(u, source_size)?),
+			12 => {
+				let segments = arbitrary_lines(u, source_size)?;
+				let label_end = segments.last().map_or(0, |segment| segment.end);
+				let content = arbitrary_range(u, label_end, source_size)?;
+				Self::NamespacedAttacher(segments, content)
+			},
+			13 => Self::MetaBlockComment {
+				meta: arbitrary_range(u, 0, source_size)?,
+				lines: arbitrary_lines(u, source_size)?
+			},
+			14 => Self::UnclosedBlock(arbitrary_lines(u, source_size)?),
+			15 => Self::UnclosedBlockComment(arbitrary_lines(u, source_size)?),
+			16 => Self::UnclosedBlockOthertongue(arbitrary_lines(u, source_size)?),
+			17 => {
+				let lines_collected = arbitrary_lines(u, source_size)?;
+				let limit = lines_collected.len();
+				Self::BlockTooLong { lines_collected, limit }
+			},
+			18 => Self::BlockCommentWithHeader {
+				header: arbitrary_range(u, 0, source_size)?,
+				lines: arbitrary_lines(u, source_size)?
+			},
+			19 => {
+				let label = arbitrary_range(u, 0, source_size)?;
+				let items = arbitrary_lines_from(u, label.end, source_size)?;
+				Self::ListAttacher(label, items)
+			},
+			20 => {
+				let paragraph_count = u.int_in_range(0..=MAX_LINE_COUNT)?;
+				let mut paragraphs = Vec::with_capacity(paragraph_count);
+				let mut lower_bound = 0;
+				for _ in 0..paragraph_count {
+					let lines = arbitrary_lines_from(u, lower_bound, source_size)?;
+					lower_bound = lines.last().map_or(lower_bound, |line| line.end);
+					paragraphs.push(lines);
+				}
+				Self::BlockParagraphs(paragraphs)
+			},
+			21 => Self::TaggedBlockOthertongue {
+				tag: arbitrary_range(u, 0, source_size)?,
+				lines: arbitrary_lines(u, source_size)?
+			},
+			22 => {
+				let lines = arbitrary_lines(u, source_size)?;
+				let required = lines.len() + 1;
+				Self::BlockTooShort { lines, required }
+			},
+			23 => Self::MalformedBlock(arbitrary_lines(u, source_size)?),
+			24 => {
+				let label = arbitrary_range(u, 0, source_size)?;
+				let lines = arbitrary_lines_from(u, label.end, source_size)?;
+				Self::InlineBlockAttacher(label, lines)
+			},
+			25 => Self::EmptyBlock,
+			26 => Self::InvalidLabel(arbitrary_range(u, 0, source_size)?),
+			27 => Self::BlankLine(arbitrary_range(u, 0, source_size)?),
+			28 => {
+				let segments = arbitrary_lines(u, source_size)?;
+				let label_end = segments.last().map_or(0, |segment| segment.end);
+				let content = arbitrary_range(u, label_end, source_size)?;
+				Self::DottedAttacher { segments, content }
+			},
+			_ => {
+				let expected_depth = u.int_in_range(1..=MAX_SCOPE_LEVEL)?;
+				Self::MismatchedBlockClose {
+					actual_depth: u.int_in_range(0..=expected_depth - 1)?,
+					expected_depth,
+					lines: arbitrary_lines(u, source_size)?
+				}
+			}
+		};
+
+		Ok(raw_token)
+	}
+}
+
+/// Returns a source where the boundaries of the raw token are valid.
+///
+/// The source is filled with lowercase letters except for the tabs of a scope level raw token.
+///
+/// ## Notes
+/// Only available with the `arbitrary` feature.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::{RawToken, generate_source_for_token};
+///
+/// let raw_token = RawToken::Attacher(0..2, 4..7);
+/// assert_eq!(generate_source_for_token(&raw_token), b"aaaaaaa");
+///
+/// let raw_token = RawToken::ScopeLevel(2);
+/// assert_eq!(generate_source_for_token(&raw_token), b"\t\t");
+/// ```
+pub fn generate_source_for_token(raw_token: &RawToken<Range<usize>, Vec<Range<usize>>>)
+-> Vec<u8> {
+	let source_size = match raw_token {
+		RawToken::Empty | RawToken::Invalid | RawToken::EmptyBlock => 0,
+		RawToken::ScopeLevel(level) => return vec![b'\t'; *level],
+		RawToken::LineComment(boundary)
+		| RawToken::Simplex(boundary)
+		| RawToken::Complex(boundary)
+		| RawToken::AttacherNoContent(boundary)
+		| RawToken::InvalidLabel(boundary)
+		| RawToken::BlankLine(boundary)
+		| RawToken::LineOthertongue(boundary) => boundary.end,
+		RawToken::Attacher(label, content) => label.end.max(content.end),
+		RawToken::NamespacedAttacher(segments, content)
+		| RawToken::DottedAttacher { segments, content } => {
+			segments.iter().map(|segment| segment.end).max().unwrap_or(0).max(content.end)
+		},
+		RawToken::ListAttacher(label, items)
+		| RawToken::InlineBlockAttacher(label, items) => {
+			items.iter().map(|item| item.end).max().unwrap_or(0).max(label.end)
+		},
+		RawToken::Block { lines, .. }
+		| RawToken::UnclosedBlock(lines)
+		| RawToken::BlockComment(lines)
+		| RawToken::UnclosedBlockComment(lines)
+		| RawToken::BlockOthertongue(lines)
+		| RawToken::UnclosedBlockOthertongue(lines)
+		| RawToken::MismatchedBlockClose { lines, .. }
+		| RawToken::BlockTooLong { lines_collected: lines, .. }
+		| RawToken::BlockTooShort { lines, .. }
+		| RawToken::MalformedBlock(lines) => {
+			lines.iter().map(|line| line.end).max().unwrap_or(0)
+		},
+		RawToken::BlockParagraphs(paragraphs) => {
+			paragraphs.iter().flatten().map(|line| line.end).max().unwrap_or(0)
+		},
+		RawToken::MetaBlockComment { meta, lines }
+		| RawToken::BlockCommentWithHeader { header: meta, lines }
+		| RawToken::TaggedBlockOthertongue { tag: meta, lines } => {
+			lines.iter().map(|line| line.end).max().unwrap_or(0).max(meta.end)
+		}
+	};
+
+	vec![b'a'; source_size]
+}
+
+fn arbitrary_range(u: &mut Unstructured, lower_bound: usize, upper_bound: usize)
+-> Result<Range<usize>> {
+	let start = u.int_in_range(lower_bound..=upper_bound)?;
+	let end = u.int_in_range(start..=upper_bound)?;
+	Ok(start..end)
+}
+
+fn arbitrary_lines(u: &mut Unstructured, source_size: usize) -> Result<Vec<Range<usize>>> {
+	arbitrary_lines_from(u, 0, source_size)
+}
+
+fn arbitrary_lines_from(u: &mut Unstructured, mut lower_bound: usize, source_size: usize)
+-> Result<Vec<Range<usize>>> {
+	let line_count = u.int_in_range(1..=MAX_LINE_COUNT)?;
+	let mut lines = Vec::with_capacity(line_count);
+	for _ in 0..line_count {
+		let line = arbitrary_range(u, lower_bound, source_size)?;
+		lower_bound = line.end;
+		lines.push(line);
+	}
+
+	Ok(lines)
+}
+
+#[cfg(test)]
+mod t {
+	use arbitrary::{Arbitrary, Unstructured};
+	use crate::native::{Range, Vec};
+	use crate::raw_token::RawToken;
+	use super::generate_source_for_token;
+
+	fn is_valid(boundary: &Range<usize>, source_size: usize) -> bool {
+		boundary.start <= boundary.end && boundary.end <= source_size
+	}
+
+	#[test]
+	fn can_generate_valid_boundaries() {
+		let data: Vec<u8> = (0..4096).map(|index| (index * 37 % 251) as u8).collect();
+		let mut u = Unstructured::new(&data);
+
+		while !u.is_empty() {
+			let raw_token = RawToken::<Range<usize>, Vec<Range<usize>>>::arbitrary(&mut u).unwrap();
+			let source_size = generate_source_for_token(&raw_token).len();
+			let is_valid = match &raw_token {
+				RawToken::Empty
+				| RawToken::Invalid
+				| RawToken::EmptyBlock
+				| RawToken::ScopeLevel(_) => true,
+				RawToken::LineComment(boundary)
+				| RawToken::Simplex(boundary)
+				| RawToken::Complex(boundary)
+				| RawToken::AttacherNoContent(boundary)
+				| RawToken::InvalidLabel(boundary)
+				| RawToken::BlankLine(boundary)
+				| RawToken::LineOthertongue(boundary) => is_valid(boundary, source_size),
+				RawToken::Attacher(label, content) => {
+					is_valid(label, source_size)
+					&& is_valid(content, source_size)
+					&& label.end <= content.start
+				},
+				RawToken::NamespacedAttacher(segments, content)
+				| RawToken::DottedAttacher { segments, content } => {
+					segments.iter().all(|segment| is_valid(segment, source_size))
+					&& is_valid(content, source_size)
+					&& segments.iter().all(|segment| segment.end <= content.start)
+				},
+				RawToken::ListAttacher(label, items)
+				| RawToken::InlineBlockAttacher(label, items) => {
+					is_valid(label, source_size)
+					&& items.iter().all(|item| is_valid(item, source_size))
+					&& items.iter().all(|item| label.end <= item.start)
+				},
+				RawToken::Block { lines, .. }
+				| RawToken::UnclosedBlock(lines)
+				| RawToken::BlockComment(lines)
+				| RawToken::UnclosedBlockComment(lines)
+				| RawToken::BlockOthertongue(lines)
+				| RawToken::UnclosedBlockOthertongue(lines)
+				| RawToken::MismatchedBlockClose { lines, .. }
+				| RawToken::BlockTooLong { lines_collected: lines, .. }
+				| RawToken::BlockTooShort { lines, .. }
+				| RawToken::MalformedBlock(lines) => {
+					lines.iter().all(|line| is_valid(line, source_size))
+				},
+				RawToken::BlockParagraphs(paragraphs) => {
+					paragraphs.iter().flatten().all(|line| is_valid(line, source_size))
+				},
+				RawToken::MetaBlockComment { meta, lines }
+				| RawToken::BlockCommentWithHeader { header: meta, lines }
+				| RawToken::TaggedBlockOthertongue { tag: meta, lines } => {
+					is_valid(meta, source_size)
+					&& lines.iter().all(|line| is_valid(line, source_size))
+				}
+			};
+
+			assert!(is_valid, "Invalid boundaries in {:?}", raw_token);
+		}
+	}
+}
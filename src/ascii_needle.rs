@@ -0,0 +1,38 @@
+const ASCII_BYTES: [u8; 128] = {
+	let mut bytes = [0; 128];
+	let mut index = 0;
+	while index < bytes.len() {
+		bytes[index] = index as u8;
+		index += 1;
+	}
+	bytes
+};
+
+const ASCII_CHARACTERS: &str = match core::str::from_utf8(&ASCII_BYTES) {
+	Ok(characters) => characters,
+	Err(_) => panic!("ASCII bytes should be valid UTF-8.")
+};
+
+/// Returns the needle which can be compared with the sources for the byte.
+///
+/// Sources can only be compared with static strings. Bytes that are not ASCII characters do not
+/// have needles.
+pub fn ascii_needle(byte: u8) -> Option<&'static str> {
+	ASCII_CHARACTERS.get(byte as usize..byte as usize + 1)
+}
+
+#[cfg(test)]
+mod t {
+	use super::ascii_needle;
+
+	#[test]
+	fn can_get_needle_of_ascii_character() {
+		assert_eq!(ascii_needle(b'#'), Some("#"));
+		assert_eq!(ascii_needle(b'\t'), Some("\t"));
+	}
+
+	#[test]
+	fn cannot_get_needle_of_non_ascii_byte() {
+		assert_eq!(ascii_needle(0x80), None);
+	}
+}
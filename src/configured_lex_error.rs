@@ -0,0 +1,25 @@
+use crate::lex_error::LexError;
+use crate::scope_error::ScopeError;
+
+/// Contains the error returned by the lexers which follow a lexer configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfiguredLexError {
+	/// The configuration has rejected the indentation of a line.
+	Scope(ScopeError),
+
+	/// A token has been rejected, like a block which has fewer lines than the minimum. The skipped
+	/// range covers the rejected token.
+	Token(LexError)
+}
+
+impl From<ScopeError> for ConfiguredLexError {
+	fn from(error: ScopeError) -> Self {
+		Self::Scope(error)
+	}
+}
+
+impl From<LexError> for ConfiguredLexError {
+	fn from(error: LexError) -> Self {
+		Self::Token(error)
+	}
+}
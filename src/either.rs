@@ -0,0 +1,12 @@
+/// Represents a value which came from one of two places.
+///
+/// It is used by [`interleave_token_queues()`] to tell which token queue a token came from.
+///
+/// [`interleave_token_queues()`]: ./secondary_lexers/fn.interleave_token_queues.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<A, B> {
+	/// Contains the value from the first place.
+	Left(A),
+	/// Contains the value from the second place.
+	Right(B)
+}
@@ -0,0 +1,10 @@
+/// Contains the info of a line which has more initial tabs than allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExcessTabsError {
+	/// The number of tabs counted before stopping. It is more than the limit but the line may have
+	/// more tabs.
+	pub found: usize,
+
+	/// The maximum number of tabs allowed.
+	pub limit: usize
+}
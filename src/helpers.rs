@@ -1,12 +1,79 @@
-/// Contains `count_tabs()` counter.
+/// Contains `count_tabs()`, `count_tabs_bounded()`, and `count_tabs_with_width()` counters, and
+/// `find_indentation_end()`.
 mod count_tabs;
 
-/// Contains `find_line_ending()`.
+/// Contains `find_line_ending()`, `find_line_ending_and_next()`, `find_line_ending_bounded()`,
+/// `find_line_start()`, `find_line_ending_unicode()`, and `find_line_ending_memchr()`.
 mod find_line_ending;
 
-/// Contains `block()` lexer.
+/// Contains `block()`, `block_n()`, `block_annotated()`, `block_deindented()`,
+/// `block_with_header()`, `block_with_policy()`, `block_paragraph()`, `block_nested()`, and
+/// `block_scan()` lexers, `block_size()`, `block_end()`, and `has_n_matching_bytes()`.
 mod block;
 
-pub use block::block;
-pub use count_tabs::count_tabs;
-pub use find_line_ending::find_line_ending;
+/// Contains `annotate_source()` and `LineInfo`.
+mod annotate_source;
+
+/// Contains `hash_source()`.
+mod hash_source;
+
+/// Contains `tokens_content_equal()`.
+mod tokens_content_equal;
+
+/// Contains `unescape_simplex()`.
+mod unescape_simplex;
+
+/// Contains `StrSource` source.
+mod str_source;
+
+/// Contains `ByteWindow` which covers the shared bytes of `ArcSource` and `MmapSource`.
+mod byte_window;
+
+/// Contains `ArcSource` source.
+mod arc_source;
+
+/// Contains `MmapSource` source.
+#[cfg(feature = "mmap")]
+mod mmap_source;
+
+pub use annotate_source::{annotate_source, LineInfo};
+pub use arc_source::ArcSource;
+pub use block::{
+	block,
+	block_n,
+	block_annotated,
+	block_deindented,
+	block_end,
+	block_nested,
+	block_paragraph,
+	block_scan,
+	block_size,
+	block_with_header,
+	block_with_policy,
+	has_n_matching_bytes,
+	BlockCapturePolicy
+};
+pub use count_tabs::{
+	count_tabs,
+	count_tabs_bounded,
+	count_tabs_with_width,
+	find_indentation_end
+};
+pub(crate) use block::{add_line, trim_carriage_return};
+pub(crate) use count_tabs::measure_indentation;
+pub use hash_source::hash_source;
+pub use find_line_ending::{
+	find_line_ending,
+	find_line_ending_and_next,
+	find_line_ending_bounded,
+	find_line_start,
+	find_line_ending_unicode
+};
+pub use str_source::StrSource;
+pub use tokens_content_equal::tokens_content_equal;
+pub use unescape_simplex::unescape_simplex;
+
+#[cfg(feature = "mmap")]
+pub use mmap_source::MmapSource;
+#[cfg(feature = "memchr")]
+pub use find_line_ending::find_line_ending_memchr;
@@ -0,0 +1,110 @@
+use crate::abstracts::{AbstractSource, ComparableAbstractSource};
+use crate::helpers::{count_tabs, find_line_ending};
+use crate::native::{Range, Vec};
+use crate::special_characters::{CARRIAGE_RETURN, NEW_LINE, SPACE, TAB};
+
+/// Contains the metadata of a line in the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineInfo {
+	/// The number of the line. The first line is line 1.
+	pub line_number: usize,
+
+	/// The byte range of the line excluding the line ending.
+	pub byte_range: Range<usize>,
+
+	/// The number of tabs at the start of the line.
+	pub leading_tabs: usize,
+
+	/// True if the line contains nothing but tabs and spaces.
+	pub is_blank: bool
+}
+
+/// Returns the metadata of every line in the source.
+///
+/// ## Notes
+/// Carriage returns before new lines are treated as part of the line ending so they are not
+/// included in the byte ranges.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::{annotate_source, LineInfo};
+///
+/// let source = b"a\r\n\t\tb\n\t ";
+/// assert_eq!(annotate_source(&source[..]), vec![
+/// 	LineInfo { line_number: 1, byte_range: 0..1, leading_tabs: 0, is_blank: false },
+/// 	LineInfo { line_number: 2, byte_range: 3..6, leading_tabs: 2, is_blank: false },
+/// 	LineInfo { line_number: 3, byte_range: 7..9, leading_tabs: 1, is_blank: true }
+/// ]);
+/// ```
+pub fn annotate_source<T>(src: T) -> Vec<LineInfo>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone {
+	let mut line_infos = Vec::new();
+	let mut offset = 0;
+
+	loop {
+		let start = offset;
+		let line_ending = find_line_ending(&src, start);
+		let mut end = line_ending;
+		if end > start && src.is_same_needle_at(end - 1, CARRIAGE_RETURN) { end -= 1; }
+
+		let line = src.clone().slice(start, end);
+		let (leading_tabs, _) = count_tabs(line.clone(), 0);
+		let mut is_blank = true;
+		for index in leading_tabs..end - start {
+			if !line.is_same_needle_at(index, TAB) && !line.is_same_needle_at(index, SPACE) {
+				is_blank = false;
+				break;
+			}
+		}
+
+		line_infos.push(LineInfo {
+			line_number: line_infos.len() + 1,
+			byte_range: start..end,
+			leading_tabs,
+			is_blank
+		});
+
+		if src.is_same_needle_at(line_ending, NEW_LINE) {
+			offset = line_ending + 1;
+		} else {
+			break;
+		}
+	}
+
+	line_infos
+}
+
+#[cfg(test)]
+mod t {
+	use super::{annotate_source, LineInfo};
+
+	#[test]
+	fn can_annotate_empty_source() {
+		assert_eq!(annotate_source(&b""[..]), vec![
+			LineInfo { line_number: 1, byte_range: 0..0, leading_tabs: 0, is_blank: true }
+		]);
+	}
+
+	#[test]
+	fn can_annotate_lines_with_crlf_endings() {
+		let source = b"a\r\n\tb|\r\n\r\n";
+
+		assert_eq!(annotate_source(&source[..]), vec![
+			LineInfo { line_number: 1, byte_range: 0..1, leading_tabs: 0, is_blank: false },
+			LineInfo { line_number: 2, byte_range: 3..6, leading_tabs: 1, is_blank: false },
+			LineInfo { line_number: 3, byte_range: 8..8, leading_tabs: 0, is_blank: true },
+			LineInfo { line_number: 4, byte_range: 10..10, leading_tabs: 0, is_blank: true }
+		]);
+	}
+
+	#[test]
+	fn can_annotate_tabbed_blank_lines() {
+		let source = b"\t\t\n \t";
+
+		assert_eq!(annotate_source(&source[..]), vec![
+			LineInfo { line_number: 1, byte_range: 0..2, leading_tabs: 2, is_blank: true },
+			LineInfo { line_number: 2, byte_range: 3..5, leading_tabs: 0, is_blank: true }
+		]);
+	}
+}
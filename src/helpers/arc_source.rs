@@ -0,0 +1,130 @@
+use crate::native::Arc;
+use crate::abstracts::{AbstractSource, ComparableAbstractSource};
+use crate::measurable_abstract_source::MeasurableAbstractSource;
+use super::byte_window::ByteWindow;
+
+/// Represents a source made from shared bytes.
+///
+/// It allows several threads to lex the same source without copying it. Cloning the source only
+/// increments the reference count of the bytes while slicing the source only changes the offsets
+/// it covers.
+///
+/// ## Notes
+/// The traits of the source cannot be implemented for `Arc<[u8]>` directly because both of them
+/// are defined outside of this crate. Therefore, the shared bytes are wrapped by this source.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::sync::Arc;
+/// use chearmyp_lexer::helpers::ArcSource;
+/// use chearmyp_lexer::primary_lexers::attacher;
+/// use chearmyp_lexer::RawToken;
+///
+/// let bytes: Arc<[u8]> = Arc::from(&b"hello:\tworld"[..]);
+/// let source = ArcSource::new(bytes);
+/// let (raw_token, last_index) = attacher
+/// 	::<ArcSource, Range<usize>, Vec<Range<usize>>>(source.clone(), 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..5, 7..12));
+/// assert_eq!(last_index, 12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArcSource {
+	bytes: Arc<[u8]>,
+	window: ByteWindow
+}
+
+impl ArcSource {
+	/// Creates a source from shared bytes.
+	pub fn new(bytes: Arc<[u8]>) -> Self {
+		let window = ByteWindow::new(bytes.len());
+		Self {
+			bytes,
+			window
+		}
+	}
+
+	/// Returns the bytes covered by the source. It is empty if the source has been sliced past its
+	/// end.
+	pub fn as_bytes(&self) -> &[u8] {
+		self.window.of(&self.bytes)
+	}
+}
+
+impl From<Arc<[u8]>> for ArcSource {
+	fn from(bytes: Arc<[u8]>) -> Self {
+		Self::new(bytes)
+	}
+}
+
+impl AbstractSource for ArcSource {
+	fn is_empty_at(&self, offset: usize) -> bool {
+		offset >= self.window.len()
+	}
+
+	fn forward_slice(mut self, offset: usize) -> Self {
+		self.window = self.window.forward_slice(offset);
+		self
+	}
+
+	fn slice(mut self, start: usize, end: usize) -> Self {
+		self.window = self.window.slice(start, end);
+		self
+	}
+}
+
+impl MeasurableAbstractSource for ArcSource {
+	fn byte_len(&self) -> usize {
+		self.window.len()
+	}
+}
+
+impl ComparableAbstractSource<&'static str> for ArcSource {
+	fn is_same_needle_at(&self, offset: usize, needle: &'static str) -> bool {
+		self.window.has_needle_at(&self.bytes, offset, needle)
+	}
+}
+
+impl AsRef<[u8]> for ArcSource {
+	fn as_ref(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::abstracts::{AbstractSource, ComparableAbstractSource, SimpleAbstractToken};
+	use crate::native::{Arc, Range, Vec, VecDeque};
+	use crate::secondary_lexers::lex;
+	use crate::token::Token;
+	use super::ArcSource;
+
+	#[test]
+	fn can_slice_without_copying() {
+		let bytes: Arc<[u8]> = Arc::from(&b"abc|def"[..]);
+		let source = ArcSource::new(bytes.clone());
+
+		let sliced = source.clone().slice(1, 5).forward_slice(1);
+
+		assert_eq!(sliced.as_bytes(), b"c|d");
+		assert!(sliced.is_same_needle_at(1, "|"));
+		assert!(!sliced.is_same_needle_at(3, "|"));
+		assert!(sliced.is_empty_at(3));
+		assert_eq!(Arc::strong_count(&bytes), 3);
+	}
+
+	#[test]
+	fn can_be_lexed() {
+		let source = ArcSource::from(Arc::<[u8]>::from(&b"a\n\tb|"[..]));
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&source, VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_simplex(3..4)
+		]));
+	}
+}
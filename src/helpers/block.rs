@@ -4,17 +4,52 @@ use crate::abstracts::{
 	ComparableAbstractSource,
 	AbstractBoundaryCollection
 };
-use crate::helpers::find_line_ending;
+use crate::helpers::{find_indentation_end, find_line_ending};
+use crate::helpers::count_tabs::measure_indentation;
+use crate::native::{Range, Vec};
 use crate::raw_token::{RawToken, RawTokenInfo};
-use crate::special_characters::{NEW_LINE, TAB};
+use crate::seekable_abstract_source::SeekableAbstractSource;
+use crate::special_characters::{CARRIAGE_RETURN, NEW_LINE, SPACE, TAB};
 
 /// Returns the recognized block and the last seen index.
 ///
 /// This is a generalization of blocks in chearmyp. It will return a vector of lines that are in the
-/// block.
+/// block. A closed block keeps the tab count as its tab level so the indentation of its lines can
+/// be stripped later.
+///
+/// ## Notes
+/// The block may be opened with more than 3 special characters. In that case, it must be closed
+/// with the same number of special characters.
+///
+/// If the closing special characters have fewer leading tabs than the tab count, it will return a
+/// mismatched block close raw token variant with the lines collected before the closing line. If
+/// the source ends before the closing special characters, it will return an unclosed block raw
+/// token variant with the lines collected.
+///
+/// If the closing special characters are followed by characters other than tabs or spaces, it will
+/// return a malformed block raw token variant with the lines collected. The closing line is still
+/// consumed so the lexing may continue after it.
+///
+/// If the closing special characters are right after the opening line, it will return an empty
+/// block raw token variant. No collection of lines is created for it unless the block is required
+/// to have lines.
+///
+/// The fifth argument is the minimum number of lines that the block must have. If a closed block
+/// has fewer lines, it will return a block too short raw token variant with the lines collected.
+/// There is no minimum if it is 0.
+///
+/// The sixth argument is the maximum number of lines that the block may have. If the block has
+/// more lines, it will return a block too long raw token variant with the lines collected so far
+/// and the index where the next line starts. It does not look for the closing special characters
+/// anymore. There is no maximum if it is `None`.
+///
+/// The last argument is the number of spaces that make one level of indentation, like the indent
+/// width of `LexerConfig`. The closing special characters may then be indented with runs of spaces
+/// as well as tabs. Only tabs indent them if it is 0.
 ///
 /// ## Example
 /// ```
+/// use std::ops::Range;
 /// use chearmyp_lexer::RawToken;
 /// use chearmyp_lexer::helpers::block;
 ///
@@ -23,81 +58,911 @@ use crate::special_characters::{NEW_LINE, TAB};
 /// @@@
 /// hello world
 /// @@@";
-/// let (block, last_seen_index) = block(&sample_block[..], 1, 0, special_character);
-/// assert_eq!(block, RawToken::Block(vec![5..16]));
+/// let (raw_token, last_seen_index)
+/// 	= block(&sample_block[..], 1, 0, special_character, 0, None, 0);
+/// assert_eq!(raw_token, RawToken::Block { lines: vec![5..16], tab_level: 0 });
+/// assert_eq!(last_seen_index, 20);
+///
+/// let long_block = b"@@@\nhello\nworld\n@@@";
+/// let (raw_token, last_seen_index)
+/// 	= block(&long_block[..], 0, 0, special_character, 0, Some(1), 0);
+/// assert_eq!(raw_token, RawToken::BlockTooLong { lines_collected: vec![4..9], limit: 1 });
+/// assert_eq!(last_seen_index, 10);
+///
+/// let empty_block = b"@@@\n@@@";
+/// let (raw_token, last_seen_index): (RawToken<Range<usize>, Vec<Range<usize>>>, _)
+/// 	= block(&empty_block[..], 0, 0, special_character, 0, None, 0);
+/// assert_eq!(raw_token, RawToken::EmptyBlock);
+/// assert_eq!(last_seen_index, 7);
+///
+/// let (raw_token, last_seen_index)
+/// 	= block(&empty_block[..], 0, 0, special_character, 1, None, 0);
+/// assert_eq!(raw_token, RawToken::BlockTooShort { lines: vec![7..7], required: 1 });
+/// assert_eq!(last_seen_index, 7);
+///
+/// let malformed_block = b"@@@\nhello\n@@@ world\n";
+/// let (raw_token, last_seen_index)
+/// 	= block(&malformed_block[..], 0, 0, special_character, 0, None, 0);
+/// assert_eq!(raw_token, RawToken::MalformedBlock(vec![4..9]));
 /// assert_eq!(last_seen_index, 20);
+///
+/// let spaced_block = b"  @@@\n  hello\n  @@@";
+/// let (raw_token, last_seen_index)
+/// 	= block(&spaced_block[..], 2, 1, special_character, 0, None, 2);
+/// assert_eq!(raw_token, RawToken::Block { lines: vec![6..13], tab_level: 1 });
+/// assert_eq!(last_seen_index, 19);
+/// ```
+pub fn block<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str,
+	min_lines: usize,
+	max_lines: Option<usize>,
+	indent_width: usize
+) -> RawTokenInfo<U, V>
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let options = BlockOptions { min_lines, max_lines, indent_width, ..BlockOptions::default() };
+	lex_block(src, offset, tab_count, special_character, options).0
+}
+
+/// Returns the recognized block whose delimiters have an exact number of special characters and
+/// the last seen index.
+///
+/// This works like [`block()`] but it needs the number of special characters in the opening line
+/// as the fifth argument. The closing line must have the same number. There is no minimum or
+/// maximum number of lines.
+///
+/// ## Notes
+/// If the opening line has a different number of special characters, it will return an invalid raw
+/// token variant with the offset. Since blocks need at least 3 special characters, there is no
+/// block if the number is less than 3.
+///
+/// ## Example
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::RawToken;
+/// use chearmyp_lexer::helpers::block_n;
+///
+/// let sample_block = b"@@@@\nhello\n@@@\n@@@@";
+/// let (raw_token, last_seen_index) = block_n(&sample_block[..], 0, 0, "@", 4);
+/// assert_eq!(raw_token, RawToken::Block { lines: vec![5..10, 11..14], tab_level: 0 });
+/// assert_eq!(last_seen_index, 19);
+///
+/// let (raw_token, last_seen_index): (RawToken<Range<usize>, Vec<Range<usize>>>, _)
+/// 	= block_n(&sample_block[..], 0, 0, "@", 3);
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_seen_index, 0);
+/// ```
+///
+/// [`block()`]: ./fn.block.html
+pub fn block_n<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str,
+	delimiter_count: usize
+) -> RawTokenInfo<U, V>
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	if count_special_characters(&src, offset, special_character) != delimiter_count {
+		let raw_token = if src.is_empty_at(offset) { RawToken::Empty } else { RawToken::Invalid };
+		return (raw_token, offset);
+	}
+
+	block(src, offset, tab_count, special_character, 0, None, 0)
+}
+
+/// Returns the recognized block, the last seen index, and the lines paired with their excess tabs.
+///
+/// This works like [`block()`] but it also pairs each line in the block with the number of leading
+/// tabs it has beyond the tab count. Lines with fewer tabs than the tab count have 0 excess tabs.
+/// The pairs will be empty if the block has no lines or there is no block.
+///
+/// ## Example
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::RawToken;
+/// use chearmyp_lexer::helpers::block_annotated;
+///
+/// let sample_block = b"\t@@@\n\thello\n\t\t\tworld\n\t@@@";
+/// let ((block, last_seen_index), annotated_lines) = block_annotated
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&sample_block[..], 1, 1, "@");
+/// assert_eq!(block, RawToken::Block { lines: vec![5..11, 12..20], tab_level: 1 });
+/// assert_eq!(last_seen_index, 25);
+/// assert_eq!(annotated_lines, vec![(5..11, 0), (12..20, 2)]);
 /// ```
-pub fn block<T, U, V>(src: T, offset: usize, tab_count: usize, special_character: &'static str)
--> RawTokenInfo<U, V>
+///
+/// [`block()`]: ./fn.block.html
+pub fn block_annotated<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str
+) -> (RawTokenInfo<U, V>, Vec<(U, usize)>)
 where
-	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str>,
 	U: AbstractBoundary<usize>,
 	V: AbstractBoundaryCollection<usize, U> {
-	let has_special_characters = has_3_special_characters(&src, offset, special_character);
-	if has_special_characters {
+	let options = BlockOptions::default();
+	let (info, annotated_lines, _) = lex_block(src, offset, tab_count, special_character, options);
+	(info, annotated_lines)
+}
+
+/// Returns the recognized block whose lines do not include the indentation and the last seen
+/// index.
+///
+/// This works like [`block()`] but the start of each line is moved after the tabs required by the
+/// tab count. Lines with fewer tabs than the tab count keep their actual start.
+///
+/// ## Example
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::RawToken;
+/// use chearmyp_lexer::helpers::block_deindented;
+///
+/// let sample_block = b"\t\t@@@\n\t\thello\n\t\t\tworld\n\t!\n\t\t@@@";
+/// let (block, last_seen_index) = block_deindented
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&sample_block[..], 2, 2, "@");
+/// assert_eq!(block, RawToken::Block { lines: vec![8..13, 16..22, 23..25], tab_level: 2 });
+/// assert_eq!(last_seen_index, 31);
+/// ```
+///
+/// [`block()`]: ./fn.block.html
+pub fn block_deindented<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str
+) -> RawTokenInfo<U, V>
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let options = BlockOptions { is_deindented: true, ..BlockOptions::default() };
+	lex_block(src, offset, tab_count, special_character, options).0
+}
+
+/// Returns the recognized block whose opening line is a header, the last seen index, and the
+/// header.
+///
+/// This works like [`block()`] but the content after the opening special characters until the line
+/// ending is returned as the header instead of being the first line of the block. The header will
+/// be an empty boundary if the opening special characters are followed by a new line or there is no
+/// block. Like [`block()`], it needs the minimum and the maximum number of lines, and the indent
+/// width.
+///
+/// ## Example
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::RawToken;
+/// use chearmyp_lexer::helpers::block_with_header;
+///
+/// let sample_block = b"@@@ greeting\nhello world\n@@@";
+/// let ((block, last_seen_index), header) = block_with_header
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&sample_block[..], 0, 0, "@", 0, None, 0);
+/// assert_eq!(block, RawToken::Block { lines: vec![13..24], tab_level: 0 });
+/// assert_eq!(last_seen_index, 28);
+/// assert_eq!(header, 3..12);
+/// ```
+///
+/// [`block()`]: ./fn.block.html
+pub fn block_with_header<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str,
+	min_lines: usize,
+	max_lines: Option<usize>,
+	indent_width: usize
+) -> (RawTokenInfo<U, V>, U)
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let options = BlockOptions {
+		has_header: true,
+		min_lines,
+		max_lines,
+		indent_width,
+		..BlockOptions::default()
+	};
+	let (info, _, header) = lex_block(src, offset, tab_count, special_character, options);
+	(info, U::new(header.start, header.end))
+}
+
+/// Returns the recognized block whose lines are captured according to the policy and the last seen
+/// index.
+///
+/// This works like [`block()`] but the last argument decides which lines are captured. The lines
+/// that are empty or only have tabs are considered empty lines.
+///
+/// ## Notes
+/// If the policy groups the lines into paragraphs, a closed block will be returned as a block
+/// paragraphs raw token variant. Mismatched and unclosed blocks are still returned with the
+/// non-empty lines collected since there is no variant for their paragraphs.
+///
+/// ## Example
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::RawToken;
+/// use chearmyp_lexer::helpers::{block_with_policy, BlockCapturePolicy};
+///
+/// let sample_block = b"@@@\na\nb\n\n\nc\n@@@";
+/// let (block, last_seen_index) = block_with_policy
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(
+/// 		&sample_block[..],
+/// 		0,
+/// 		0,
+/// 		"@",
+/// 		BlockCapturePolicy::NonEmptyLines
+/// 	);
+/// assert_eq!(block, RawToken::Block { lines: vec![4..5, 6..7, 10..11], tab_level: 0 });
+/// assert_eq!(last_seen_index, 15);
+///
+/// let (block, last_seen_index) = block_with_policy
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(
+/// 		&sample_block[..],
+/// 		0,
+/// 		0,
+/// 		"@",
+/// 		BlockCapturePolicy::ParagraphBlocks
+/// 	);
+/// assert_eq!(block, RawToken::BlockParagraphs(vec![vec![4..5, 6..7], vec![10..11]]));
+/// assert_eq!(last_seen_index, 15);
+/// ```
+///
+/// [`block()`]: ./fn.block.html
+pub fn block_with_policy<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str,
+	policy: BlockCapturePolicy
+) -> RawTokenInfo<U, V>
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let options = BlockOptions { policy, ..BlockOptions::default() };
+	lex_block(src, offset, tab_count, special_character, options).0
+}
+
+/// Returns the paragraphs in the recognized block and the last seen index.
+///
+/// This works like [`block()`] but the lines are grouped into paragraphs. The lines that are empty
+/// or only have tabs separate the paragraphs and are not part of any paragraph.
+///
+/// ## Notes
+/// It will return no paragraphs if the block has no lines other than empty lines or if the block
+/// has not been closed properly. Use [`block_with_policy()`] to know how the block ended.
+///
+/// ## Example
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::helpers::block_paragraph;
+///
+/// let sample_block = b"@@@\na\nb\n\nc\n@@@";
+/// let (paragraphs, last_seen_index) = block_paragraph
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&sample_block[..], 0, 0, "@");
+/// assert_eq!(paragraphs, vec![vec![4..5, 6..7], vec![9..10]]);
+/// assert_eq!(last_seen_index, 14);
+/// ```
+///
+/// [`block()`]: ./fn.block.html
+/// [`block_with_policy()`]: ./fn.block_with_policy.html
+pub fn block_paragraph<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str
+) -> (Vec<V>, usize)
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let policy = BlockCapturePolicy::ParagraphBlocks;
+	match block_with_policy(src, offset, tab_count, special_character, policy) {
+		(RawToken::BlockParagraphs(paragraphs), offset) => (paragraphs, offset),
+		(_, offset) => (Vec::new(), offset)
+	}
+}
+
+/// Contains the ways to capture the lines of a block.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BlockCapturePolicy {
+	/// Captures every line, including the empty ones. It is used by [`block()`].
+	///
+	/// [`block()`]: ./fn.block.html
+	#[default]
+	AllLines,
+
+	/// Captures the lines that are not empty.
+	NonEmptyLines,
+
+	/// Groups the consecutive non-empty lines into paragraphs. The empty lines separate them.
+	ParagraphBlocks
+}
+
+/// Returns the boundaries of the lines in the block lazily if the block has been found.
+///
+/// This works like [`block()`] but it does not collect the lines into a boundary collection. Each
+/// line is yielded as a pair of its start and end only when the iterator is advanced. It will
+/// return `None` if there are no 3 special characters at the offset.
+///
+/// ## Notes
+/// The iterator stops at the closing special characters, at a mismatched closing line, or at the
+/// end of the source. Therefore, it cannot tell whether the block has been closed. Use [`block()`]
+/// to know how the block ended.
+///
+/// ## Example
+/// ```
+/// use chearmyp_lexer::helpers::block_scan;
+///
+/// let sample_block = b"@@@\nhello\nworld\n@@@";
+/// let lines = block_scan(&sample_block[..], 0, 0, "@").unwrap();
+/// assert_eq!(lines.collect::<Vec<_>>(), vec![(4, 9), (10, 15)]);
+///
+/// let line_count = block_scan(&sample_block[..], 0, 0, "@").map(Iterator::count);
+/// assert_eq!(line_count, Some(2));
+///
+/// assert!(block_scan(&b"hello"[..], 0, 0, "@").is_none());
+/// ```
+///
+/// [`block()`]: ./fn.block.html
+pub fn block_scan<T>(src: T, offset: usize, tab_count: usize, special_character: &'static str)
+-> Option<impl Iterator<Item = (usize, usize)>>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone {
+	BlockScanner::new(src, offset, tab_count, special_character, false, None, 0)
+		.map(|(scanner, _)| scanner.map(|(start, end, _)| (start, end)))
+}
+
+/// Returns the total number of bytes in the lines of the block if the block has been found.
+///
+/// This works like [`block_scan()`] but the lengths of the lines are added together instead of
+/// being yielded. No boundary or collection is created. It will return `None` if there are no 3
+/// special characters at the offset.
+///
+/// ## Notes
+/// The lines include their leading tabs but not their line endings. Like [`block_scan()`], the
+/// lines of an unclosed block are counted up to the end of the source.
+///
+/// ## Example
+/// ```
+/// use chearmyp_lexer::helpers::block_size;
+///
+/// let sample_block = b"@@@\nhello\n\tworld\n@@@";
+/// assert_eq!(block_size(&sample_block[..], 0, 0, "@"), Some(11));
+/// assert_eq!(block_size(&b"@@@\n@@@"[..], 0, 0, "@"), Some(0));
+/// assert_eq!(block_size(&b"hello"[..], 0, 0, "@"), None);
+/// ```
+///
+/// [`block_scan()`]: ./fn.block_scan.html
+pub fn block_size<T>(src: T, offset: usize, tab_count: usize, special_character: &'static str)
+-> Option<usize>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone {
+	block_scan(src, offset, tab_count, special_character)
+		.map(|lines| lines.map(|(start, end)| end - start).sum())
+}
+
+/// Returns the recognized block which may contain other blocks and the last seen index.
+///
+/// This works like [`block()`] but the special characters inside the block may open and close
+/// nested blocks. The rest of the opening line is skipped like a header. The block closes once its
+/// closing line is found while there are no open nested blocks.
+///
+/// ## Notes
+/// A line of special characters opens a nested block if it is indented deeper than the innermost
+/// open block, if it has a different number of special characters, if it has trailing content, or
+/// if it is right after an opening line. Otherwise, it closes the innermost open block. The lines
+/// of the nested blocks are part of the returned lines.
+///
+/// If the source ends while a block is still open, the special characters are not treated as
+/// nested anymore and the block is lexed like [`block()`] instead. Therefore, unclosed blocks,
+/// mismatched closing lines, and closing lines with trailing content are returned like in
+/// [`block()`].
+///
+/// The last argument is the indent width like in [`block()`]. The levels of the nested special
+/// characters are counted in the same unit.
+///
+/// ## Example
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::RawToken;
+/// use chearmyp_lexer::helpers::block_nested;
+///
+/// let sample_block = b"@@@\n@@@\nhello\n@@@\n@@@\nworld";
+/// let (raw_token, last_seen_index) = block_nested
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&sample_block[..], 0, 0, "@", 0);
+/// assert_eq!(raw_token, RawToken::Block { lines: vec![4..7, 8..13, 14..17], tab_level: 0 });
+/// assert_eq!(last_seen_index, 22);
+///
+/// let unclosed_block = b"@@@\nhello\n\t@@@\nworld";
+/// let (raw_token, last_seen_index) = block_nested
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&unclosed_block[..], 0, 0, "@", 0);
+/// assert_eq!(raw_token, RawToken::UnclosedBlock(vec![4..9, 10..14, 15..20]));
+/// assert_eq!(last_seen_index, 21);
+/// ```
+///
+/// [`block()`]: ./fn.block.html
+pub fn block_nested<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str,
+	indent_width: usize
+) -> RawTokenInfo<U, V>
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let options = BlockOptions {
+		has_header: true,
+		is_nested: true,
+		indent_width,
+		..BlockOptions::default()
+	};
+	match lex_block(src.clone(), offset, tab_count, special_character, options).0 {
+		(RawToken::UnclosedBlock(_), _) => {
+			let options = BlockOptions { has_header: true, indent_width, ..BlockOptions::default() };
+			lex_block(src, offset, tab_count, special_character, options).0
+		},
+		info => info
+	}
+}
+
+/// Returns the last seen index of the block if the block has been found.
+///
+/// This works like [`block_size()`] but it returns where the block ends instead. The rest of the
+/// opening line is skipped like a header. It will return `None` if there are no 3 special
+/// characters at the offset.
+///
+/// ## Notes
+/// There is no maximum number of lines. Therefore, it can find the end of a block that [`block()`]
+/// stopped collecting because the block has too many lines. Like [`block()`], the last seen index
+/// of an unclosed block is one more than the length of the source. The last argument is the indent
+/// width like in [`block()`].
+///
+/// ## Example
+/// ```
+/// use chearmyp_lexer::helpers::block_end;
+///
+/// let sample_block = b"@@@\nhello\nworld\n@@@\nnext";
+/// assert_eq!(block_end(&sample_block[..], 0, 0, "@", 0), Some(20));
+/// assert_eq!(block_end(&b"@@@\nhello"[..], 0, 0, "@", 0), Some(10));
+/// assert_eq!(block_end(&b"hello"[..], 0, 0, "@", 0), None);
+/// ```
+///
+/// [`block()`]: ./fn.block.html
+/// [`block_size()`]: ./fn.block_size.html
+pub fn block_end<T>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str,
+	indent_width: usize
+) -> Option<usize>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone {
+	let scanner = BlockScanner::new(
+		src,
+		offset,
+		tab_count,
+		special_character,
+		true,
+		None,
+		indent_width
+	);
+	scanner.map(|(mut scanner, _)| {
+		scanner.by_ref().for_each(drop);
+		scanner.offset
+	})
+}
+
+/// Returns true if the needle repeats the number of times in the source starting at the offset.
+///
+/// Each repetition is expected to occupy one byte so the needle should be a single ASCII character,
+/// like the special characters of blocks. Blocks need at least 3 of them to be opened.
+///
+/// ## Notes
+/// It always returns true if the number is 0.
+///
+/// ## Example
+/// ```
+/// use chearmyp_lexer::helpers::has_n_matching_bytes;
+///
+/// let sample = b"a@@@@";
+/// assert!(has_n_matching_bytes(&&sample[..], 1, 3, "@"));
+/// assert!(has_n_matching_bytes(&&sample[..], 1, 4, "@"));
+/// assert!(!has_n_matching_bytes(&&sample[..], 1, 5, "@"));
+/// assert!(!has_n_matching_bytes(&&sample[..], 0, 3, "@"));
+/// ```
+pub fn has_n_matching_bytes<T>(src: &T, offset: usize, n: usize, needle: &'static str) -> bool
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	if n > 0 && src.is_empty_at(offset + n - 1) {
+		false
+	} else {
+		(offset..offset + n).all(|index| src.is_same_needle_at(index, needle))
+	}
+}
+
+type LexedBlock<U, V> = (RawTokenInfo<U, V>, Vec<(U, usize)>, Range<usize>);
+
+#[derive(Default)]
+struct BlockOptions {
+	is_deindented: bool,
+	has_header: bool,
+	is_nested: bool,
+	min_lines: usize,
+	max_lines: Option<usize>,
+	indent_width: usize,
+	policy: BlockCapturePolicy
+}
+
+fn lex_block<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str,
+	options: BlockOptions
+) -> LexedBlock<U, V>
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let mut annotated_lines = Vec::new();
+	let is_empty = src.is_empty_at(offset);
+	let scanner = BlockScanner::new(
+		src,
+		offset,
+		tab_count,
+		special_character,
+		options.has_header,
+		options.max_lines,
+		options.indent_width
+	);
+
+	if let Some((mut scanner, header)) = scanner {
+		if options.is_nested {
+			let nesting = NestedDelimiters { is_after_opening: true, ..NestedDelimiters::default() };
+			scanner.nesting = Some(nesting);
+		}
+
 		let mut lines = None;
-		let mut offset = offset + 3;
-		offset += if src.is_same_needle_at(offset, NEW_LINE) { 1 } else { 0 };
+		let mut line_count = 0;
+		let mut paragraphs = Vec::new();
+		let mut paragraph = None;
 
-		loop {
-			let start = offset;
-			let end = find_line_ending(&src, start);
-			if start == end && src.is_empty_at(end) { break; }
-			let line = src.clone().slice(start, end);
+		for (start, end, leading_tab_count) in scanner.by_ref() {
+			let excess_tab_count = leading_tab_count.saturating_sub(tab_count);
+			annotated_lines.push((U::new(start, end), excess_tab_count));
 
-			let mut indent_size = tab_count;
-			while indent_size > 0 {
-				indent_size -= 1;
-				if !line.is_same_needle_at(indent_size, TAB) { break; }
+			if options.policy != BlockCapturePolicy::AllLines && end - start == leading_tab_count {
+				paragraphs.extend(paragraph.take());
+				continue;
 			}
 
-			offset = end;
+			let start = if options.is_deindented && leading_tab_count >= tab_count {
+				start + tab_count
+			} else {
+				start
+			};
 
-			if indent_size == 0 && has_3_special_characters(&line, tab_count, special_character) {
-				if src.is_same_needle_at(offset, NEW_LINE) { offset += 1; }
-				break;
+			lines = add_line(lines, start, end);
+			line_count += 1;
+			if options.policy == BlockCapturePolicy::ParagraphBlocks {
+				paragraph = add_line(paragraph, start, end);
 			}
+		}
 
-			offset += 1;
-			lines = lines.map(|mut lines: V| {
-				lines.add(U::new(start, end));
-				lines
-			}).or_else(|| {
-				Some(V::new(start, end))
-			});
+		paragraphs.extend(paragraph);
+
+		let offset = scanner.offset;
+		let is_closed = matches!(scanner.ending, Some(BlockEnding::Closed));
+		if is_closed && annotated_lines.is_empty() && options.min_lines == 0 {
+			return ((RawToken::EmptyBlock, offset), annotated_lines, header);
 		}
 
-		lines = lines.or_else(|| {
-			Some(V::new(offset, offset))
-		});
+		let lines = lines.unwrap_or_else(|| V::new(offset, offset));
+		let raw_token = match scanner.ending {
+			Some(BlockEnding::Mismatched(actual_depth)) => RawToken::MismatchedBlockClose {
+				actual_depth,
+				expected_depth: tab_count,
+				lines
+			},
+			Some(BlockEnding::TooLong(limit)) => RawToken::BlockTooLong {
+				lines_collected: lines,
+				limit
+			},
+			Some(BlockEnding::Closed) if line_count < options.min_lines => RawToken::BlockTooShort {
+				lines,
+				required: options.min_lines
+			},
+			Some(BlockEnding::Closed) if options.policy == BlockCapturePolicy::ParagraphBlocks => {
+				RawToken::BlockParagraphs(paragraphs)
+			},
+			Some(BlockEnding::Closed) => RawToken::Block { lines, tab_level: tab_count },
+			Some(BlockEnding::Malformed) => RawToken::MalformedBlock(lines),
+			Some(BlockEnding::Unclosed) | None => RawToken::UnclosedBlock(lines)
+		};
 
-		(RawToken::Block(lines.unwrap()), offset)
+		((raw_token, offset), annotated_lines, header)
 	} else {
-		let raw_token = if src.is_empty_at(offset) { RawToken::Empty } else { RawToken::Invalid };
-		(raw_token, offset)
+		let raw_token = if is_empty { RawToken::Empty } else { RawToken::Invalid };
+		((raw_token, offset), annotated_lines, offset..offset)
+	}
+}
+
+pub(crate) fn add_line<U, V>(lines: Option<V>, start: usize, end: usize) -> Option<V>
+where
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	lines.map(|mut lines: V| {
+		lines.add(U::new(start, end));
+		lines
+	}).or_else(|| {
+		Some(V::new(start, end))
+	})
+}
+
+enum BlockEnding {
+	Closed,
+	Malformed,
+	Mismatched(usize),
+	TooLong(usize),
+	Unclosed
+}
+
+struct BlockScanner<T> {
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str,
+	delimiter_size: usize,
+	max_lines: Option<usize>,
+	indent_width: usize,
+	line_count: usize,
+	nesting: Option<NestedDelimiters>,
+	ending: Option<BlockEnding>
+}
+
+impl<T> BlockScanner<T>
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str> {
+	fn new(
+		src: T,
+		offset: usize,
+		tab_count: usize,
+		special_character: &'static str,
+		has_header: bool,
+		max_lines: Option<usize>,
+		indent_width: usize
+	) -> Option<(Self, Range<usize>)> {
+		if !has_n_matching_bytes(&src, offset, 3, special_character) { return None; }
+
+		let delimiter_size = count_special_characters(&src, offset, special_character);
+		let mut offset = offset + delimiter_size;
+		let header_start = offset;
+		if has_header { offset = find_line_ending(&src, offset); }
+		let header = header_start..trim_carriage_return(&src, header_start, offset);
+		if src.is_same_needle_at(offset, CARRIAGE_RETURN)
+		&& src.is_same_needle_at(offset + 1, NEW_LINE) {
+			offset += 1;
+		}
+		offset += if src.is_same_needle_at(offset, NEW_LINE) { 1 } else { 0 };
+
+		let scanner = Self {
+			src,
+			offset,
+			tab_count,
+			special_character,
+			delimiter_size,
+			max_lines,
+			indent_width,
+			line_count: 0,
+			nesting: None,
+			ending: None
+		};
+		Some((scanner, header))
+	}
+
+	fn end(&mut self, ending: BlockEnding) -> Option<(usize, usize, usize)> {
+		self.ending = Some(ending);
+		None
+	}
+}
+
+impl<T> Iterator for BlockScanner<T>
+where
+	T: SeekableAbstractSource + ComparableAbstractSource<&'static str> {
+	// The start, the end, and the number of leading tabs of a line. The runs of spaces as long as
+	// the indent width are counted as tabs.
+	type Item = (usize, usize, usize);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.ending.is_some() { return None; }
+
+		let src = &self.src;
+		let start = self.offset;
+		let end = find_line_ending(src, start);
+		if start == end && src.is_empty_at(end) { return self.end(BlockEnding::Unclosed); }
+		let line = src.view(start, end);
+
+		self.offset = end;
+
+		let (leading_tab_count, indentation_size)
+			= measure_indentation(&line, usize::MAX, self.indent_width);
+		let special_character = self.special_character;
+		let delimiter_size = count_special_characters(&line, indentation_size, special_character);
+
+		let is_nested_delimiter = match self.nesting.as_mut() {
+			Some(nesting) => nesting.follow(
+				leading_tab_count,
+				delimiter_size,
+				has_trailing_content(&line, indentation_size + delimiter_size, end - start),
+				(self.tab_count, self.delimiter_size)
+			),
+			None => false
+		};
+
+		if !is_nested_delimiter && is_closing_delimiter(
+			&line,
+			0,
+			self.tab_count,
+			self.indent_width,
+			self.special_character,
+			self.delimiter_size
+		) {
+			if src.is_same_needle_at(end, NEW_LINE) { self.offset += 1; }
+			let indentation_end = find_indentation_end(&line, self.tab_count, self.indent_width);
+			let closer_end = indentation_end + self.delimiter_size;
+			return if has_trailing_content(&line, closer_end, end - start) {
+				self.end(BlockEnding::Malformed)
+			} else {
+				self.end(BlockEnding::Closed)
+			};
+		}
+
+		if leading_tab_count < self.tab_count && delimiter_size == self.delimiter_size {
+			if src.is_same_needle_at(end, NEW_LINE) { self.offset += 1; }
+			return self.end(BlockEnding::Mismatched(leading_tab_count));
+		}
+
+		if let Some(limit) = self.max_lines.filter(|&limit| self.line_count == limit) {
+			self.offset = start;
+			return self.end(BlockEnding::TooLong(limit));
+		}
+
+		// The carriage return of CRLF line endings is not part of the content.
+		let end = trim_carriage_return(src, start, end);
+
+		self.offset += 1;
+		self.line_count += 1;
+		Some((start, end, leading_tab_count))
+	}
+}
+
+/// Contains the levels and the sizes of the delimiters of the nested blocks which are still open.
+#[derive(Default)]
+struct NestedDelimiters {
+	open_delimiters: Vec<(usize, usize)>,
+	is_after_opening: bool
+}
+
+impl NestedDelimiters {
+	/// Returns true if the line opens or closes a nested block. Otherwise, the line may still close
+	/// the outer block whose level and delimiter size are the last argument.
+	fn follow(
+		&mut self,
+		level: usize,
+		delimiter_size: usize,
+		has_trailing_content: bool,
+		outer_delimiter: (usize, usize)
+	) -> bool {
+		let is_after_opening = self.is_after_opening;
+		self.is_after_opening = false;
+		if level < outer_delimiter.0 || delimiter_size < 3 { return false; }
+
+		let (open_level, open_size) = self.open_delimiters
+			.last()
+			.copied()
+			.unwrap_or(outer_delimiter);
+		let is_closing = !has_trailing_content
+			&& delimiter_size == open_size
+			&& (level < open_level || (level == open_level && !is_after_opening));
+
+		if is_closing {
+			self.open_delimiters.pop().is_some()
+		} else {
+			self.open_delimiters.push((level, delimiter_size));
+			self.is_after_opening = true;
+			true
+		}
 	}
 }
 
-fn has_3_special_characters<T>(src: &T, offset: usize, special_character: &'static str) -> bool
+pub(crate) fn trim_carriage_return<T>(src: &T, start: usize, end: usize) -> usize
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> {
-	if src.is_empty_at(offset + 2) {
-		false
-	} else {
-		src.is_same_needle_at(offset, special_character)
-		&& src.is_same_needle_at(offset + 1, special_character)
-		&& src.is_same_needle_at(offset + 2, special_character)
+	if end > start && src.is_same_needle_at(end - 1, CARRIAGE_RETURN) { end - 1 } else { end }
+}
+
+fn has_trailing_content<T>(line: &T, start: usize, end: usize) -> bool
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	(start..end).any(|index| {
+		!line.is_same_needle_at(index, TAB)
+		&& !line.is_same_needle_at(index, SPACE)
+		&& !line.is_same_needle_at(index, CARRIAGE_RETURN)
+	})
+}
+
+/// Returns true if the line has exactly the number of tabs before a delimiter as long as the
+/// delimiter size. The runs of spaces as long as the indent width are counted as tabs. The line is
+/// not required to end after the delimiter.
+fn is_closing_delimiter<T>(
+	src: &T,
+	line_start: usize,
+	tab_count: usize,
+	indent_width: usize,
+	special_character: &'static str,
+	delimiter_size: usize
+) -> bool
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let mut delimiter_start = line_start;
+	for _ in 0..tab_count {
+		if src.is_same_needle_at(delimiter_start, TAB) {
+			delimiter_start += 1;
+		} else if indent_width > 0
+		&& has_n_matching_bytes(src, delimiter_start, indent_width, SPACE) {
+			delimiter_start += indent_width;
+		} else {
+			return false;
+		}
 	}
+
+	count_special_characters(src, delimiter_start, special_character) == delimiter_size
+}
+
+fn count_special_characters<T>(src: &T, offset: usize, special_character: &'static str) -> usize
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let mut count = 0;
+	while src.is_same_needle_at(offset + count, special_character) { count += 1; }
+	count
 }
 
 #[cfg(test)]
 mod t {
 	use crate::native::{Range, Vec};
-	use super::{RawToken, has_3_special_characters, block};
+	use crate::raw_token::RawTokenKind;
+	use super::{
+		RawToken,
+		has_n_matching_bytes,
+		is_closing_delimiter,
+		block,
+		block_annotated,
+		block_deindented,
+		block_paragraph,
+		block_scan,
+		block_size,
+		block_with_header,
+		block_with_policy,
+		BlockCapturePolicy
+	};
 
 	macro_rules! has_3_special_characters {
 		($src:literal $offset:literal $special_character:literal) => {
-			has_3_special_characters::<&[u8]>(&&$src[..], $offset, $special_character)
+			has_n_matching_bytes::<&[u8]>(&&$src[..], $offset, 3, $special_character)
 		};
 	}
 
@@ -121,6 +986,48 @@ mod t {
 		assert!(!has_3_special_characters!(b"aa" 0 "a"), "Double-character string");
 	}
 
+	#[test]
+	fn can_detect_other_numbers_of_special_characters() {
+		assert!(has_n_matching_bytes::<&[u8]>(&&b"b@@@@"[..], 1, 4, "@"));
+		assert!(!has_n_matching_bytes::<&[u8]>(&&b"b@@@@"[..], 1, 5, "@"));
+		assert!(has_n_matching_bytes::<&[u8]>(&&b""[..], 0, 0, "@"));
+	}
+
+	macro_rules! is_closing_delimiter {
+		($src:literal $line_start:literal $tab_count:literal $delimiter_size:literal) => {
+			is_closing_delimiter::<&[u8]>(&&$src[..], $line_start, $tab_count, 0, "#", $delimiter_size)
+		};
+	}
+
+	#[test]
+	fn can_detect_closing_delimiter() {
+		assert!(is_closing_delimiter!(b"###" 0 0 3), "Unindented delimiter");
+		assert!(is_closing_delimiter!(b"\t\t###" 0 2 3), "Indented delimiter");
+		assert!(is_closing_delimiter!(b"a\n\t####\t" 2 1 4), "Longer delimiter after a line");
+	}
+
+	#[test]
+	fn cannot_detect_closing_delimiter_with_other_indentation() {
+		assert!(!is_closing_delimiter!(b"###" 0 1 3), "Less indented delimiter");
+		assert!(!is_closing_delimiter!(b"\t\t###" 0 1 3), "More indented delimiter");
+		assert!(!is_closing_delimiter!(b"a\t###" 0 2 3), "Content before the tabs");
+	}
+
+	#[test]
+	fn can_detect_closing_delimiter_indented_with_spaces() {
+		let source = &b"\t  ###"[..];
+
+		assert!(is_closing_delimiter::<&[u8]>(&source, 0, 2, 2, "#", 3));
+		assert!(!is_closing_delimiter::<&[u8]>(&source, 0, 2, 0, "#", 3));
+		assert!(!is_closing_delimiter::<&[u8]>(&source, 0, 2, 4, "#", 3));
+	}
+
+	#[test]
+	fn cannot_detect_closing_delimiter_with_other_size() {
+		assert!(!is_closing_delimiter!(b"##" 0 0 3), "Shorter delimiter");
+		assert!(!is_closing_delimiter!(b"####" 0 0 3), "Longer delimiter");
+	}
+
 	macro_rules! test {
 		(
 			$(
@@ -129,7 +1036,9 @@ mod t {
 					$offset:literal,
 					$tab_count:literal,
 					and $special_character:literal
-				expecting $variant_name:ident $(with [$($ranges:expr),+])?
+				expecting $variant_name:ident
+					$(with [$($ranges:expr),+])?
+					$({ $($field:ident: $value:expr),+ })?
 				last seen at $last_seen_index:literal
 			)+
 		) => {
@@ -143,12 +1052,17 @@ mod t {
 						&&source[..],
 						$offset,
 						$tab_count,
-						$special_character);
+						$special_character,
+						0,
+						None,
+						0);
 
 					assert_eq!{
 						info,
 						(
-							RawToken::$variant_name$((vec![$($ranges),+]))?,
+							RawToken::$variant_name
+								$((vec![$($ranges),+]))?
+								$({ $($field: $value),+ })?,
 							$last_seen_index
 						)
 					};
@@ -159,16 +1073,36 @@ mod t {
 
 	test!{
 		can_lex_with_proper_content using b"bbb\nc\nbbb", 0, 0, and "b"
-		expecting Block with [4..5] last seen at 9
+		expecting Block { lines: vec![4..5], tab_level: 0 } last seen at 9
 
 		can_lex_with_an_empty_line using b"ddd\nddd", 0, 0, and "d"
-		expecting Block with [7..7] last seen at 7
+		expecting EmptyBlock last seen at 7
 
 		can_lex_with_empty_line_and_tabbed_line using b"eee\n \n\t \n\n \n\teee", 0, 1, and "e"
-		expecting Block with [4..5, 6..8, 9..9, 10..11] last seen at 16
+		expecting Block { lines: vec![4..5, 6..8, 9..9, 10..11], tab_level: 1 } last seen at 16
 
 		can_lex_with_lines_with_fewer_tabs using b"~~~\n\t\t \n\t \n\t\t~~~", 0, 2, and "~"
-		expecting Block with [4..7, 8..10] last seen at 16
+		expecting Block { lines: vec![4..7, 8..10], tab_level: 2 } last seen at 16
+
+		can_lex_with_mismatched_close using b"jjj\n\t\tk\nl\njjj\nm", 0, 2, and "j"
+		expecting MismatchedBlockClose { actual_depth: 0, expected_depth: 2, lines: vec![4..7, 8..9] }
+		last seen at 14
+
+		can_lex_with_partly_indented_mismatched_close using b"jjj\n\tjjj", 0, 2, and "j"
+		expecting MismatchedBlockClose { actual_depth: 1, expected_depth: 2, lines: vec![8..8] }
+		last seen at 8
+
+		can_lex_with_crlf_line_endings using b"bbb\r\nc\r\nd\r\nbbb", 0, 0, and "b"
+		expecting Block { lines: vec![5..6, 8..9], tab_level: 0 } last seen at 14
+
+		can_lex_with_longer_delimiters using b"kkkk\nkkk\nl\nkkkk", 0, 0, and "k"
+		expecting Block { lines: vec![5..8, 9..10], tab_level: 0 } last seen at 15
+
+		can_lex_with_longer_delimiters_and_longer_line using b"kkkk\nkkkkk\nkkkk", 0, 0, and "k"
+		expecting Block { lines: vec![5..10], tab_level: 0 } last seen at 15
+
+		cannot_lex_unclosed using b"nnn\no\np", 0, 0, and "n"
+		expecting UnclosedBlock with [4..5, 6..7] last seen at 8
 
 		cannot_lex_on_empty_line using b"", 0, 0, and "i"
 		expecting Empty last seen at 0
@@ -179,4 +1113,231 @@ mod t {
 		cannot_lex_on_double_character_line using b"ii", 0, 0, and "i"
 		expecting Invalid last seen at 0
 	}
+
+	#[test]
+	fn can_annotate_lines_with_excess_tabs() {
+		let source = b"\t~~~\n\ta\n\t\tb\n\n\t\t\tc\n\t~~~";
+
+		let ((raw_token, last_seen_index), annotated_lines) = block_annotated
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 1, 1, "~");
+
+		assert_eq!(raw_token, RawToken::Block {
+			lines: vec![5..7, 8..11, 12..12, 13..17],
+			tab_level: 1
+		});
+		assert_eq!(last_seen_index, 22);
+		assert_eq!(annotated_lines, vec![(5..7, 0), (8..11, 1), (12..12, 0), (13..17, 2)]);
+	}
+
+	#[test]
+	fn cannot_annotate_empty_block() {
+		let source = b"~~~\n~~~";
+
+		let ((raw_token, _), annotated_lines) = block_annotated
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "~");
+
+		assert_eq!(raw_token, RawToken::EmptyBlock);
+		assert!(annotated_lines.is_empty());
+	}
+
+	#[test]
+	fn can_deindent_lines() {
+		let source = b"\t~~~\n\ta\n\n\t\tb\n\t~~~";
+
+		let info = block_deindented
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 1, 1, "~");
+
+		assert_eq!(info, (RawToken::Block { lines: vec![6..7, 8..8, 10..12], tab_level: 1 }, 17));
+	}
+
+	#[test]
+	fn can_separate_header() {
+		let source = b"\t~~~ a b\n\tc\n\t\td\n\t~~~";
+
+		let ((raw_token, last_seen_index), header) = block_with_header
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 1, 1, "~", 0, None, 0);
+
+		assert_eq!(raw_token, RawToken::Block { lines: vec![9..11, 12..15], tab_level: 1 });
+		assert_eq!(last_seen_index, 20);
+		assert_eq!(header, 4..8);
+	}
+
+	#[test]
+	fn can_separate_empty_header() {
+		let source = b"~~~\ne\nf\n~~~";
+
+		let ((raw_token, _), header) = block_with_header
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "~", 0, None, 0);
+
+		assert_eq!(raw_token, RawToken::Block { lines: vec![4..5, 6..7], tab_level: 0 });
+		assert_eq!(header, 3..3);
+	}
+
+	#[test]
+	fn can_lex_within_line_limit() {
+		let source = b"~~~\na\nb\n~~~";
+
+		let info = block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "~", 0, Some(2), 0);
+
+		assert_eq!(info, (RawToken::Block { lines: vec![4..5, 6..7], tab_level: 0 }, 11));
+	}
+
+	#[test]
+	fn cannot_lex_beyond_line_limit() {
+		let source = b"~~~\na\nb\nc\n~~~";
+
+		let info = block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "~", 0, Some(2), 0);
+
+		let lines_collected = vec![4..5, 6..7];
+		assert_eq!(info, (RawToken::BlockTooLong { lines_collected, limit: 2 }, 8));
+	}
+
+	#[test]
+	fn cannot_lex_with_zero_line_limit() {
+		let source = b"~~~\na\n~~~";
+
+		let (raw_token, last_seen_index) = block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "~", 0, Some(0), 0);
+
+		assert_eq!(raw_token.kind(), RawTokenKind::BlockTooLong);
+		assert_eq!(last_seen_index, 4);
+	}
+
+	#[test]
+	fn cannot_lex_below_minimum_lines() {
+		let source = b"~~~\n~~~";
+
+		let (raw_token, last_seen_index) = block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "~", 1, None, 0);
+
+		assert_eq!(raw_token.kind(), RawTokenKind::BlockTooShort);
+		assert_eq!(last_seen_index, 7);
+	}
+
+	#[test]
+	fn can_lex_with_minimum_lines() {
+		let source = b"~~~\na\n~~~";
+
+		let (raw_token, _) = block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "~", 1, None, 0);
+
+		assert_eq!(raw_token.kind(), RawTokenKind::Block);
+	}
+
+	#[test]
+	fn cannot_close_with_trailing_content() {
+		let source = b"\t~~~\n\ta\n\t~~~ b\nc";
+
+		let (raw_token, last_seen_index) = block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 1, 1, "~", 0, None, 0);
+
+		assert_eq!(raw_token.kind(), RawTokenKind::MalformedBlock);
+		assert_eq!(last_seen_index, 15);
+	}
+
+	#[test]
+	fn can_close_with_trailing_whitespace() {
+		let source = b"~~~\na\n~~~ \t\r\n";
+
+		let (raw_token, last_seen_index) = block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "~", 0, None, 0);
+
+		assert_eq!(raw_token.kind(), RawTokenKind::Block);
+		assert_eq!(last_seen_index, 13);
+	}
+
+	#[test]
+	fn can_close_with_space_indentation() {
+		let source = b"  ~~~\n    a\n\t  b\n  ~~~\nc";
+
+		let (raw_token, last_seen_index) = block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 2, 1, "~", 0, None, 2);
+
+		assert_eq!(raw_token, RawToken::Block { lines: vec![6..11, 12..16], tab_level: 1 });
+		assert_eq!(last_seen_index, 23);
+	}
+
+	#[test]
+	fn can_lex_mismatched_close_with_space_indentation() {
+		let source = b"    ~~~\n    a\n  ~~~";
+
+		let (raw_token, last_seen_index) = block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 4, 2, "~", 0, None, 2);
+
+		assert_eq!(raw_token, RawToken::MismatchedBlockClose {
+			actual_depth: 1,
+			expected_depth: 2,
+			lines: vec![8..13]
+		});
+		assert_eq!(last_seen_index, 19);
+	}
+
+	#[test]
+	fn can_scan_lines_lazily() {
+		let source = b"\t@@@\n\thello\r\n\t\tworld\n@@@\n\t@@@";
+
+		let lines = block_scan(&source[..], 1, 1, "@").map(Iterator::collect::<Vec<_>>);
+
+		assert_eq!(lines, Some(vec![(5, 11), (13, 20)]));
+	}
+
+	#[test]
+	fn can_count_bytes_of_lines() {
+		let source = b"\t@@@\n\thello\r\n\n\t\tworld\n\t@@@";
+
+		assert_eq!(block_size(&source[..], 1, 1, "@"), Some(13));
+		assert_eq!(block_size(&b"@@\nhello"[..], 0, 0, "@"), None);
+	}
+
+	#[test]
+	fn cannot_scan_without_special_characters() {
+		assert!(block_scan(&b"@@\nhello"[..], 0, 0, "@").is_none());
+	}
+
+	#[test]
+	fn can_lex_paragraphs_separated_by_tabbed_lines() {
+		let source = b"\t@@@\n\ta\n\t\n\n\tb\n\t\tc\n\t@@@";
+
+		let info = block_with_policy::<&[u8], Range<usize>, Vec<Range<usize>>>(
+			&source[..],
+			1,
+			1,
+			"@",
+			BlockCapturePolicy::ParagraphBlocks
+		);
+
+		assert_eq!(info, (RawToken::BlockParagraphs(vec![vec![5..7], vec![11..13, 14..17]]), 22));
+	}
+
+	#[test]
+	fn can_lex_non_empty_lines_of_mismatched_block() {
+		let source = b"\t@@@\n\ta\n\n\tb\n@@@";
+
+		let info = block_with_policy::<&[u8], Range<usize>, Vec<Range<usize>>>(
+			&source[..],
+			1,
+			1,
+			"@",
+			BlockCapturePolicy::ParagraphBlocks
+		);
+
+		assert_eq!(info, (RawToken::MismatchedBlockClose {
+			actual_depth: 0,
+			expected_depth: 1,
+			lines: vec![5..7, 9..11]
+		}, 15));
+	}
+
+	#[test]
+	fn cannot_split_unclosed_block_into_paragraphs() {
+		let source = b"@@@\na\n\nb";
+
+		let (paragraphs, last_seen_index) = block_paragraph
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "@");
+
+		assert!(paragraphs.is_empty());
+		assert_eq!(last_seen_index, 9);
+	}
 }
@@ -0,0 +1,74 @@
+/// Represents the range of shared bytes covered by a source.
+///
+/// The sources that share their bytes, like `ArcSource` and `MmapSource`, slice themselves by only
+/// changing the range. The range never grows while being sliced and it may be sliced past its end,
+/// in which case it covers nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ByteWindow {
+	start: usize,
+	end: usize
+}
+
+impl ByteWindow {
+	/// Creates a range that covers all of the bytes.
+	pub(crate) fn new(len: usize) -> Self {
+		Self { start: 0, end: len }
+	}
+
+	/// Returns the number of bytes covered.
+	pub(crate) fn len(&self) -> usize {
+		self.end.saturating_sub(self.start)
+	}
+
+	/// Returns the covered part of the bytes. It is empty if the range is past the bytes.
+	pub(crate) fn of<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+		let end = self.end.min(bytes.len());
+		&bytes[self.start.min(end)..end]
+	}
+
+	/// Returns true if the covered part of the bytes starts with the needle at the offset.
+	pub(crate) fn has_needle_at(&self, bytes: &[u8], offset: usize, needle: &'static str) -> bool {
+		self.of(bytes).get(offset..).map_or(false, |rest| rest.starts_with(needle.as_bytes()))
+	}
+
+	/// Moves the start of the range forward by the offset.
+	pub(crate) fn forward_slice(self, offset: usize) -> Self {
+		Self { start: self.start.saturating_add(offset), end: self.end }
+	}
+
+	/// Narrows the range from the start index up to the end index (exclusive), which are relative
+	/// to the current start.
+	pub(crate) fn slice(self, start: usize, end: usize) -> Self {
+		Self {
+			start: self.start.saturating_add(start),
+			end: self.end.min(self.start.saturating_add(end))
+		}
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use super::ByteWindow;
+
+	#[test]
+	fn can_slice_within_bytes() {
+		let bytes = b"abc|def";
+		let window = ByteWindow::new(bytes.len()).slice(1, 5).forward_slice(1);
+
+		assert_eq!(window.of(bytes), b"c|d");
+		assert_eq!(window.len(), 3);
+		assert!(window.has_needle_at(bytes, 1, "|"));
+		assert!(!window.has_needle_at(bytes, 3, "|"));
+	}
+
+	#[test]
+	fn can_slice_past_end() {
+		let bytes = b"a\nb";
+		let window = ByteWindow::new(bytes.len());
+
+		assert_eq!(window.forward_slice(5).len(), 0);
+		assert_eq!(window.forward_slice(5).of(bytes), b"");
+		assert!(!window.forward_slice(5).has_needle_at(bytes, 0, "\n"));
+		assert_eq!(window.slice(1, 2).slice(0, 10).of(bytes), b"\n");
+	}
+}
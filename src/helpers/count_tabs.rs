@@ -1,53 +1,164 @@
 use crate::abstracts::{AbstractSource, ComparableAbstractSource};
-use crate::special_characters::TAB;
+use crate::excess_tabs_error::ExcessTabsError;
+use crate::special_characters::{SPACE, TAB};
 
-/// Returns the number of initial tabs in the source.
+/// Returns the number of initial tabs in the source and if it is more than one tab greater than the
+/// previous number of tabs.
 ///
 /// It needs an array of bytes as the first argument (known as source), and the previous number of
 /// tabs worked on (known as old tab count). If it is the first time to check the number of initial
 /// tabs, set the old tab count to 0.
-pub fn count_tabs<T>(src: T, old_tab_count: usize) -> usize
+///
+/// ## Notes
+/// The returned boolean is true when the indentation jumped by more than one level. Such jump
+/// cannot be represented by a valid sequence of scopes.
+///
+/// The tabs are always counted from the start of the source. The old tab count only decides the
+/// returned boolean and whether a line with only tabs keeps its tabs. Therefore, the tabs after
+/// other characters, like in `a\t\t`, are never counted and the number of tabs never exceeds the
+/// length of the source whatever the old tab count is.
+pub fn count_tabs<T>(src: T, old_tab_count: usize) -> (usize, bool)
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> {
-	let mut new_tab_count = old_tab_count;
-
-	loop {
-		if src.is_same_needle_at(new_tab_count, TAB) {
-			new_tab_count += 1;
-		} else if src.is_empty_at(new_tab_count) {
-			if old_tab_count == new_tab_count {
-				new_tab_count = 0;
-			}
-			break;
+	let mut new_tab_count = 0;
+	while src.is_same_needle_at(new_tab_count, TAB) { new_tab_count += 1; }
+
+	if src.is_empty_at(new_tab_count) && old_tab_count == new_tab_count {
+		new_tab_count = 0;
+	}
+
+	(new_tab_count, new_tab_count > old_tab_count + 1)
+}
+
+/// Returns the number of initial tabs in the source or an error if it is more than the maximum.
+///
+/// It works like [`count_tabs()`] but it needs the maximum number of tabs as the third argument
+/// (known as max tabs). It stops counting as soon as the number of tabs exceeds the maximum so the
+/// rest of the indentation will not be scanned. Like [`count_tabs()`], the tabs are always counted
+/// from the start of the source.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::ExcessTabsError;
+/// use chearmyp_lexer::helpers::count_tabs_bounded;
+///
+/// assert_eq!(count_tabs_bounded(&b"\t\ta"[..], 0, 2), Ok(2));
+/// assert_eq!(
+/// 	count_tabs_bounded(&b"\t\t\t\ta"[..], 0, 2),
+/// 	Err(ExcessTabsError { found: 3, limit: 2 })
+/// );
+/// ```
+///
+/// [`count_tabs()`]: ./fn.count_tabs.html
+pub fn count_tabs_bounded<T>(src: T, old_tab_count: usize, max_tabs: usize)
+-> Result<usize, ExcessTabsError>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let mut new_tab_count = 0;
+	while src.is_same_needle_at(new_tab_count, TAB) {
+		new_tab_count += 1;
+
+		if new_tab_count > max_tabs {
+			return Err(ExcessTabsError { found: new_tab_count, limit: max_tabs });
+		}
+	}
+
+	if src.is_empty_at(new_tab_count) && old_tab_count == new_tab_count {
+		new_tab_count = 0;
+	}
+
+	Ok(new_tab_count)
+}
+
+/// Returns the number of initial tabs in the source where a run of spaces may be counted as a tab.
+///
+/// It works like [`count_tabs()`] but it needs the number of spaces per tab as the third argument
+/// (known as tab width). Each run of spaces as long as the tab width is counted as one virtual tab
+/// while each tab character is still counted as one tab. The spaces fewer than the tab width are
+/// not counted. Spaces are never counted if the tab width is 0.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::count_tabs_with_width;
+///
+/// assert_eq!(count_tabs_with_width(&b"    hello"[..], 0, 4), 1);
+/// assert_eq!(count_tabs_with_width(&b"\t  \t hello"[..], 0, 2), 3);
+/// assert_eq!(count_tabs_with_width(&b"    hello"[..], 0, 0), 0);
+/// ```
+///
+/// [`count_tabs()`]: ./fn.count_tabs.html
+pub fn count_tabs_with_width<T>(src: T, old_tab_count: usize, tab_width: usize) -> usize
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let (new_tab_count, indentation_size) = measure_indentation(&src, usize::MAX, tab_width);
+
+	if src.is_empty_at(indentation_size) && old_tab_count == new_tab_count {
+		0
+	} else {
+		new_tab_count
+	}
+}
+
+/// Returns the number of bytes occupied by the initial tabs up to the tab count.
+///
+/// The runs of spaces as long as the tab width are counted as tabs like in
+/// [`count_tabs_with_width()`]. Therefore, the returned index is where the content of the line
+/// starts if the tab count is the number of initial tabs.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::find_indentation_end;
+///
+/// assert_eq!(find_indentation_end(&&b"\t    hello"[..], 2, 4), 5);
+/// ```
+///
+/// [`count_tabs_with_width()`]: ./fn.count_tabs_with_width.html
+pub fn find_indentation_end<T>(src: &T, tab_count: usize, tab_width: usize) -> usize
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	measure_indentation(src, tab_count, tab_width).1
+}
+
+pub(crate) fn measure_indentation<T>(src: &T, max_tab_count: usize, tab_width: usize)
+-> (usize, usize)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let mut tab_count = 0;
+	let mut size = 0;
+
+	while tab_count < max_tab_count {
+		if src.is_same_needle_at(size, TAB) {
+			size += 1;
+		} else if tab_width > 0
+		&& (size..size + tab_width).all(|index| src.is_same_needle_at(index, SPACE)) {
+			size += tab_width;
 		} else {
-			if new_tab_count > 0 {
-				if src.is_same_needle_at(new_tab_count - 1, TAB) {
-					break;
-				} else {
-					new_tab_count -= 1;
-				}
-			} else {
-				break;
-			}
+			break;
 		}
+
+		tab_count += 1;
 	}
 
-	new_tab_count
+	(tab_count, size)
 }
 
 #[cfg(test)]
 mod t {
-	use super::count_tabs;
+	use proptest::{collection, proptest, sample};
+	use crate::excess_tabs_error::ExcessTabsError;
+	use super::{count_tabs, count_tabs_bounded, count_tabs_with_width, find_indentation_end};
 
 	#[test]
 	fn can_count_on_first_time() {
 		let sample = b"a";
 		let old_tab_count = 0;
 		let expected_new_tab_count = 0;
+		let expected_jump = false;
 
-		let count = count_tabs(&sample[..], old_tab_count);
+		let (count, has_jumped) = count_tabs(&sample[..], old_tab_count);
 
 		assert_eq!(count, expected_new_tab_count);
+		assert_eq!(has_jumped, expected_jump);
 	}
 
 	#[test]
@@ -55,10 +166,12 @@ mod t {
 		let sample = b"\t";
 		let old_tab_count = 0;
 		let expected_new_tab_count = 1;
+		let expected_jump = false;
 
-		let count = count_tabs(&sample[..], old_tab_count);
+		let (count, has_jumped) = count_tabs(&sample[..], old_tab_count);
 
 		assert_eq!(count, expected_new_tab_count);
+		assert_eq!(has_jumped, expected_jump);
 	}
 
 	#[test]
@@ -66,10 +179,12 @@ mod t {
 		let sample = b"bcd";
 		let old_tab_count = 3;
 		let expected_new_tab_count = 0;
+		let expected_jump = false;
 
-		let count = count_tabs(&sample[..], old_tab_count);
+		let (count, has_jumped) = count_tabs(&sample[..], old_tab_count);
 
 		assert_eq!(count, expected_new_tab_count);
+		assert_eq!(has_jumped, expected_jump);
 	}
 
 	#[test]
@@ -77,10 +192,12 @@ mod t {
 		let sample = b"\te";
 		let old_tab_count = 1;
 		let expected_new_tab_count = 1;
+		let expected_jump = false;
 
-		let count = count_tabs(&sample[..], old_tab_count);
+		let (count, has_jumped) = count_tabs(&sample[..], old_tab_count);
 
 		assert_eq!(count, expected_new_tab_count);
+		assert_eq!(has_jumped, expected_jump);
 	}
 
 	#[test]
@@ -88,10 +205,12 @@ mod t {
 		let sample = b"\t\tfg";
 		let old_tab_count = 1;
 		let expected_new_tab_count = 2;
+		let expected_jump = false;
 
-		let count = count_tabs(&sample[..], old_tab_count);
+		let (count, has_jumped) = count_tabs(&sample[..], old_tab_count);
 
 		assert_eq!(count, expected_new_tab_count);
+		assert_eq!(has_jumped, expected_jump);
 	}
 
 	#[test]
@@ -99,9 +218,93 @@ mod t {
 		let sample = b"\t\t\tfg";
 		let old_tab_count = 0;
 		let expected_new_tab_count = 3;
+		let expected_jump = true;
 
-		let count = count_tabs(&sample[..], old_tab_count);
+		let (count, has_jumped) = count_tabs(&sample[..], old_tab_count);
 
 		assert_eq!(count, expected_new_tab_count);
+		assert_eq!(has_jumped, expected_jump);
+	}
+
+	#[test]
+	fn can_count_tabs_only_at_start() {
+		assert_eq!(count_tabs(&b"a\t\t\t\t\t"[..], 1), (0, false));
+		assert_eq!(count_tabs(&b"\ta\tb"[..], 3), (1, false));
+		assert_eq!(count_tabs(&b"\tab"[..], 5), (1, false));
+	}
+
+	proptest! {
+		#[test]
+		fn cannot_count_tabs_beyond_source_length(
+			source in collection::vec(sample::select(&b"\t\t\ta \n"[..]), 0..40),
+			old_tab_count in 0..=32_usize
+		) {
+			let (initial_tab_count, _) = count_tabs(&source[..], 0);
+			let (tab_count, _) = count_tabs(&source[..], old_tab_count);
+
+			assert!(tab_count <= source.len(), "Too many tabs in {:?}", source);
+			assert!(tab_count <= initial_tab_count + old_tab_count + 1);
+		}
+
+		#[test]
+		fn can_count_bounded_tabs_like_unbounded_tabs(
+			source in collection::vec(sample::select(&b"\t\t\ta \n"[..]), 0..40),
+			old_tab_count in 0..=32_usize,
+			max_tabs in 0..=8_usize
+		) {
+			let leading_tab_count = source.iter().take_while(|&&byte| byte == b'\t').count();
+			let (tab_count, _) = count_tabs(&source[..], old_tab_count);
+
+			match count_tabs_bounded(&source[..], old_tab_count, max_tabs) {
+				Ok(bounded_tab_count) => assert_eq!(bounded_tab_count, tab_count),
+				Err(error) => {
+					assert!(leading_tab_count > max_tabs, "Early error in {:?}", source);
+					assert_eq!(error, ExcessTabsError { found: max_tabs + 1, limit: max_tabs });
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn can_count_tabs_within_limit() {
+		let sample = b"\t\thij";
+
+		assert_eq!(count_tabs_bounded(&sample[..], 0, 2), Ok(2));
+		assert_eq!(count_tabs_bounded(&sample[..], 3, 2), Ok(2));
+	}
+
+	#[test]
+	fn cannot_count_tabs_beyond_limit() {
+		let sample = b"\t\t\t\t\ti";
+
+		assert_eq!(
+			count_tabs_bounded(&sample[..], 0, 3),
+			Err(ExcessTabsError { found: 4, limit: 3 })
+		);
+		assert_eq!(
+			count_tabs_bounded(&sample[..], 5, 3),
+			Err(ExcessTabsError { found: 4, limit: 3 })
+		);
+	}
+
+	#[test]
+	fn can_count_bounded_tabs_only_at_start() {
+		assert_eq!(count_tabs_bounded(&b"a\t\t"[..], 2, 2), Ok(0));
+		assert_eq!(count_tabs_bounded(&b"a\t\t\t\t"[..], 3, 2), Ok(0));
+		assert_eq!(count_tabs_bounded(&b"\ta\t\t\t"[..], 4, 2), Ok(1));
+	}
+
+	#[test]
+	fn can_count_spaces_as_tabs() {
+		assert_eq!(count_tabs_with_width(&b"\t\tj"[..], 1, 4), 2);
+		assert_eq!(count_tabs_with_width(&b"   \tk"[..], 0, 4), 0);
+		assert_eq!(count_tabs_with_width(&b"     l"[..], 0, 2), 2);
+		assert_eq!(count_tabs_with_width(&b"  "[..], 1, 2), 0);
+	}
+
+	#[test]
+	fn can_find_indentation_end() {
+		assert_eq!(find_indentation_end(&&b"\t    \tm"[..], 2, 4), 5);
+		assert_eq!(find_indentation_end(&&b"\t    \tm"[..], 5, 4), 6);
 	}
 }
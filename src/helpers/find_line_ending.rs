@@ -1,5 +1,10 @@
 use crate::abstracts::{AbstractSource, ComparableAbstractSource};
-use crate::special_characters::NEW_LINE;
+use crate::special_characters::{
+	NEW_LINE,
+	LINE_SEPARATOR,
+	CARRIAGE_RETURN,
+	PARAGRAPH_SEPARATOR
+};
 
 /// Returns the index of the first line ending found in the source.
 ///
@@ -17,16 +22,176 @@ use crate::special_characters::NEW_LINE;
 /// assert_eq!(find_line_ending(&&a[..], 0), 5, "Unskipped line ending");
 /// assert_eq!(find_line_ending(&&a[..], 6), 11, "Skipped line ending through offset");
 /// ```
-pub fn find_line_ending<T>(src: &T, mut offset: usize)-> usize
+pub fn find_line_ending<T>(src: &T, offset: usize)-> usize
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	find_line_ending_bounded(src, offset, usize::MAX).unwrap_or_else(|limit| limit)
+}
+
+/// Returns the index of the first line ending found in the source within a maximum scan distance.
+///
+/// It works like [`find_line_ending()`] but it stops scanning after `max_scan` bytes. `Ok` contains
+/// the index of the line ending or the source's length. `Err` contains the index where the scan
+/// stopped.
+///
+/// ## Notes
+/// Useful to prevent scanning the whole remaining input of a source without line endings.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::find_line_ending_bounded;
+///
+/// let a = b"hello\nworld";
+/// assert_eq!(find_line_ending_bounded(&&a[..], 0, 8), Ok(5), "Line ending within limit");
+/// assert_eq!(find_line_ending_bounded(&&a[..], 6, 8), Ok(11), "Source end within limit");
+/// assert_eq!(find_line_ending_bounded(&&a[..], 0, 3), Err(3), "Limit reached");
+/// ```
+///
+/// [`find_line_ending()`]: ./fn.find_line_ending.html
+pub fn find_line_ending_bounded<T>(src: &T, mut offset: usize, max_scan: usize)
+-> Result<usize, usize>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let limit = offset.saturating_add(max_scan);
 	loop {
 		if src.is_same_needle_at(offset, NEW_LINE) || src.is_empty_at(offset) {
-			break;
+			break Ok(offset);
+		} else if offset == limit {
+			break Err(limit);
 		} else {
 			offset += 1;
 		}
 	}
+}
+
+/// Returns the index of the first line ending found in the source and the index after it.
+///
+/// It works like [`find_line_ending()`] but it also returns where the next line starts. Therefore,
+/// callers do not need to skip the line ending themselves. If there is no line ending, both indices
+/// will be the source's length.
+///
+/// ## Notes
+/// For CRLF line endings, the first index is the index of the carriage return and the second index
+/// is 2 bytes after it.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::find_line_ending_and_next;
+///
+/// let a = b"hello\nworld\r\n!";
+/// assert_eq!(find_line_ending_and_next(&&a[..], 0), (5, 6), "LF line ending");
+/// assert_eq!(find_line_ending_and_next(&&a[..], 6), (11, 13), "CRLF line ending");
+/// assert_eq!(find_line_ending_and_next(&&a[..], 13), (14, 14), "Without line ending");
+/// ```
+///
+/// [`find_line_ending()`]: ./fn.find_line_ending.html
+pub fn find_line_ending_and_next<T>(src: &T, offset: usize) -> (usize, usize)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let line_ending = find_line_ending(src, offset);
+	if src.is_empty_at(line_ending) {
+		(line_ending, line_ending)
+	} else if line_ending > offset && src.is_same_needle_at(line_ending - 1, CARRIAGE_RETURN) {
+		(line_ending - 1, line_ending + 1)
+	} else {
+		(line_ending, line_ending + 1)
+	}
+}
+
+/// Returns the index of the first byte of the line where the offset belongs.
+///
+/// It scans backward from the offset until it finds a line ending or reaches the start of the
+/// source. A line ending at the offset belongs to the line it ends. Together with
+/// [`find_line_ending()`], it returns the range of the line containing any byte position.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::{find_line_ending, find_line_start};
+///
+/// let a = b"hello\nworld\n";
+/// assert_eq!(find_line_start(&&a[..], 3), 0, "First line");
+/// assert_eq!(find_line_start(&&a[..], 5), 0, "Line ending of first line");
+/// assert_eq!(find_line_start(&&a[..], 8), 6, "Second line");
+/// assert_eq!(find_line_start(&&a[..], 6), 6, "Start of second line");
+///
+/// let offset = 9;
+/// let range = find_line_start(&&a[..], offset)..find_line_ending(&&a[..], offset);
+/// assert_eq!(&a[range], b"world");
+/// ```
+///
+/// [`find_line_ending()`]: ./fn.find_line_ending.html
+pub fn find_line_start<T>(src: &T, mut offset: usize) -> usize
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	while offset > 0 && !src.is_same_needle_at(offset - 1, NEW_LINE) {
+		offset -= 1;
+	}
+
+	offset
+}
+
+/// Returns the index of the first line ending found in the source including the Unicode line
+/// separators.
+///
+/// It works like [`find_line_ending()`] but the line separator (U+2028) and the paragraph
+/// separator (U+2029) are line endings too. The index of the first byte of their UTF-8 encodings
+/// will be returned.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::find_line_ending_unicode;
+///
+/// let a = "hello\u{2028}world\u{2029}!".as_bytes();
+/// assert_eq!(find_line_ending_unicode(&a, 0), 5, "Line separator");
+/// assert_eq!(find_line_ending_unicode(&a, 8), 13, "Paragraph separator");
+/// assert_eq!(find_line_ending_unicode(&a, 16), 17, "Without line ending");
+/// ```
+///
+/// [`find_line_ending()`]: ./fn.find_line_ending.html
+pub fn find_line_ending_unicode<T>(src: &T, mut offset: usize) -> usize
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	while !src.is_empty_at(offset)
+	&& !src.is_same_needle_at(offset, NEW_LINE)
+	&& !src.is_same_needle_at(offset, LINE_SEPARATOR)
+	&& !src.is_same_needle_at(offset, PARAGRAPH_SEPARATOR) {
+		offset += 1;
+	}
 
-	return offset;
+	offset
+}
+
+/// Returns the index of the first line ending found in the bytes of the source using `memchr`.
+///
+/// It works like [`find_line_ending()`] but it scans many bytes at once. The source should be
+/// readable as bytes since the abstract sources can only be compared one needle at a time.
+///
+/// ## Notes
+/// Only available with the `memchr` feature. The feature does not change [`find_line_ending()`] or
+/// the lexers which are generic over abstract sources. Call this function directly where the bytes
+/// of the source are at hand.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::{find_line_ending, find_line_ending_memchr};
+///
+/// let a = b"hello\nworld";
+/// assert_eq!(find_line_ending_memchr(&&a[..], 0), 5, "Unskipped line ending");
+/// assert_eq!(find_line_ending_memchr(&&a[..], 6), 11, "Without line ending");
+/// assert_eq!(find_line_ending_memchr(&&a[..], 12), 12, "Offset beyond the source");
+///
+/// for offset in 0..=12 {
+/// 	assert_eq!(find_line_ending_memchr(&&a[..], offset), find_line_ending(&&a[..], offset));
+/// }
+/// ```
+///
+/// [`find_line_ending()`]: ./fn.find_line_ending.html
+#[cfg(feature = "memchr")]
+pub fn find_line_ending_memchr<T>(src: &T, offset: usize) -> usize
+where
+	T: AsRef<[u8]> {
+	let bytes = src.as_ref();
+	bytes.get(offset..)
+		.and_then(|rest| memchr::memchr(NEW_LINE.as_bytes()[0], rest))
+		.map_or(bytes.len().max(offset), |index| offset + index)
 }
@@ -0,0 +1,27 @@
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Returns the FNV-1a digest of the bytes in the source.
+///
+/// The digest can be used as a key to know if a source has changed since it was last lexed. Equal
+/// sources always have equal digests but different sources may rarely have equal digests too.
+///
+/// ## Notes
+/// The source should be readable as bytes since the abstract sources can only be compared one
+/// needle at a time.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::hash_source;
+///
+/// assert_eq!(hash_source(&b""), 0xcbf29ce484222325);
+/// assert_eq!(hash_source(&b"a"), 0xaf63dc4c8601ec8c);
+/// assert_ne!(hash_source(&b"hello"), hash_source(&b"hellp"));
+/// ```
+pub fn hash_source<T>(src: &T) -> u64
+where
+	T: AsRef<[u8]> {
+	src.as_ref().iter().fold(FNV_OFFSET_BASIS, |digest, &byte| {
+		(digest ^ byte as u64).wrapping_mul(FNV_PRIME)
+	})
+}
@@ -0,0 +1,99 @@
+use std::fs::File;
+use std::io::Result;
+use std::path::Path;
+use std::sync::Arc;
+use memmap2::Mmap;
+use crate::abstracts::{AbstractSource, ComparableAbstractSource};
+use crate::measurable_abstract_source::MeasurableAbstractSource;
+use super::byte_window::ByteWindow;
+
+/// Represents a source made from a memory-mapped file.
+///
+/// The file is not loaded into memory before lexing. Cloning the source only increments the
+/// reference count of the map while slicing the source only changes the offsets it covers.
+///
+/// ## Notes
+/// The file must not be modified while it is mapped. Otherwise, the lexers may see inconsistent
+/// bytes.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::helpers::MmapSource;
+/// use chearmyp_lexer::primary_lexers::simplex;
+/// use chearmyp_lexer::RawToken;
+///
+/// let path = std::env::temp_dir().join("chearmyp_lexer_mmap_source_example.txt");
+/// std::fs::write(&path, b"hello|").unwrap();
+///
+/// let source = MmapSource::open(&path).unwrap();
+/// let (raw_token, last_index) = simplex
+/// 	::<MmapSource, Range<usize>, Vec<Range<usize>>>(source, 0, 0);
+/// assert_eq!(raw_token, RawToken::Simplex(0..5));
+/// assert_eq!(last_index, 6);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MmapSource {
+	map: Arc<Mmap>,
+	window: ByteWindow
+}
+
+impl MmapSource {
+	/// Creates a source by memory-mapping the file at the path.
+	pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+		let file = File::open(path)?;
+		// SAFETY: The source never writes to the map. The caller is responsible to not modify the
+		// file while it is mapped, as documented.
+		let map = unsafe { Mmap::map(&file)? };
+		Ok(Self::from_mmap(map))
+	}
+
+	/// Creates a source from an existing memory map.
+	pub fn from_mmap(map: Mmap) -> Self {
+		let window = ByteWindow::new(map.len());
+		Self {
+			map: Arc::new(map),
+			window
+		}
+	}
+
+	/// Returns the bytes covered by the source. It is empty if the source has been sliced past its
+	/// end.
+	pub fn as_bytes(&self) -> &[u8] {
+		self.window.of(&self.map)
+	}
+}
+
+impl AbstractSource for MmapSource {
+	fn is_empty_at(&self, offset: usize) -> bool {
+		offset >= self.window.len()
+	}
+
+	fn forward_slice(mut self, offset: usize) -> Self {
+		self.window = self.window.forward_slice(offset);
+		self
+	}
+
+	fn slice(mut self, start: usize, end: usize) -> Self {
+		self.window = self.window.slice(start, end);
+		self
+	}
+}
+
+impl MeasurableAbstractSource for MmapSource {
+	fn byte_len(&self) -> usize {
+		self.window.len()
+	}
+}
+
+impl ComparableAbstractSource<&'static str> for MmapSource {
+	fn is_same_needle_at(&self, offset: usize, needle: &'static str) -> bool {
+		self.window.has_needle_at(&self.map, offset, needle)
+	}
+}
+
+impl AsRef<[u8]> for MmapSource {
+	fn as_ref(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
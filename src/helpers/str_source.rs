@@ -0,0 +1,123 @@
+use crate::abstracts::{AbstractSource, ComparableAbstractSource};
+use crate::measurable_abstract_source::MeasurableAbstractSource;
+
+/// Represents a source made from a string slice.
+///
+/// The lexers work on byte offsets so the offsets used on this source are byte offsets too. Unlike
+/// the byte slices, the needles will only be matched at character boundaries of the string.
+///
+/// ## Notes
+/// Slicing the source must be done at character boundaries. The lexers only slice before or after
+/// the special characters which are all ASCII so they never slice inside a character.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::helpers::StrSource;
+/// use chearmyp_lexer::primary_lexers::attacher;
+/// use chearmyp_lexer::RawToken;
+///
+/// let source = StrSource::new("name:\tJosé");
+/// let (raw_token, last_index) = attacher
+/// 	::<StrSource, Range<usize>, Vec<Range<usize>>>(source, 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..4, 6..11));
+/// assert_eq!(last_index, 11);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrSource<'a>(&'a str);
+
+impl<'a> StrSource<'a> {
+	/// Creates a source from a string slice which is guaranteed to be valid UTF-8.
+	pub fn new(src: &'a str) -> Self {
+		Self(src)
+	}
+
+	/// Returns the string slice of the source.
+	pub fn as_str(&self) -> &'a str {
+		self.0
+	}
+}
+
+impl<'a> AbstractSource for StrSource<'a> {
+	fn is_empty_at(&self, offset: usize) -> bool {
+		offset >= self.0.len()
+	}
+
+	fn forward_slice(self, offset: usize) -> Self {
+		Self(&self.0[offset..])
+	}
+
+	fn slice(self, start: usize, end: usize) -> Self {
+		Self(&self.0[start..end])
+	}
+}
+
+impl<'a> MeasurableAbstractSource for StrSource<'a> {
+	fn byte_len(&self) -> usize {
+		self.0.len()
+	}
+}
+
+impl<'a> ComparableAbstractSource<&'static str> for StrSource<'a> {
+	fn is_same_needle_at(&self, offset: usize, needle: &'static str) -> bool {
+		self.0.is_char_boundary(offset) && self.0[offset..].starts_with(needle)
+	}
+}
+
+impl<'a> AsRef<[u8]> for StrSource<'a> {
+	fn as_ref(&self) -> &[u8] {
+		self.0.as_bytes()
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::abstracts::{AbstractSource, ComparableAbstractSource};
+	use crate::native::{Range, Vec};
+	use crate::primary_lexers::{attacher, block_othertongue, simplex};
+	use crate::raw_token::RawToken;
+	use super::StrSource;
+
+	#[test]
+	fn can_compare_at_character_boundaries() {
+		let source = StrSource::new("añb|");
+
+		assert!(source.is_same_needle_at(0, "a"));
+		assert!(source.is_same_needle_at(1, "ñ"));
+		assert!(!source.is_same_needle_at(2, "ñ"));
+		assert!(source.is_same_needle_at(3, "b|"));
+		assert!(!source.is_same_needle_at(5, "|"));
+	}
+
+	#[test]
+	fn can_check_emptiness() {
+		let source = StrSource::new("ñ");
+
+		assert!(!source.is_empty_at(1));
+		assert!(source.is_empty_at(2));
+	}
+
+	#[test]
+	fn can_be_lexed_by_primary_lexers() {
+		let (raw_token, last_index) = simplex::<StrSource, Range<usize>, Vec<Range<usize>>>(
+			StrSource::new("ñandú|"),
+			0,
+			0
+		);
+		assert_eq!(raw_token, RawToken::Simplex(0..7));
+		assert_eq!(last_index, 8);
+
+		let (raw_token, last_index) = attacher::<StrSource, Range<usize>, Vec<Range<usize>>>(
+			StrSource::new("héllo:\twörld"),
+			0,
+			0
+		);
+		assert_eq!(raw_token, RawToken::Attacher(0..6, 8..14));
+		assert_eq!(last_index, 14);
+
+		let (raw_token, last_index) = block_othertongue
+			::<StrSource, Range<usize>, Vec<Range<usize>>>(StrSource::new("===\nañb\n==="), 0, 0);
+		assert_eq!(raw_token, create_block!(BlockOthertongue 4..8));
+		assert_eq!(last_index, 12);
+	}
+}
@@ -0,0 +1,142 @@
+use crate::abstracts::AbstractSource;
+use crate::native::Vec;
+use crate::raw_token::RawToken;
+use crate::readable_boundary::{ReadableBoundary, ReadableBoundaryCollection};
+
+/// Returns true if the raw tokens are of the same kind and the bytes they span are equal.
+///
+/// It needs the first raw token and its source as the first two arguments, and the second raw
+/// token and its source as the last two arguments. Unlike comparing the raw tokens directly, the
+/// boundaries may be at different positions as long as they contain the same bytes. Raw tokens with
+/// many boundaries, like attachers, must match in every boundary. Block raw tokens must have the
+/// same number of lines and each line must match the line at the same position.
+///
+/// ## Notes
+/// Only the levels of the scope levels are compared among the numbers in the raw tokens. Other
+/// numbers, like the tab level of the blocks, are ignored.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::RawToken;
+/// use chearmyp_lexer::helpers::tokens_content_equal;
+///
+/// type SampleRawToken = RawToken<Range<usize>, Vec<Range<usize>>>;
+///
+/// let source_a = b"hello";
+/// let source_b = b"say:\thello";
+/// let a: SampleRawToken = RawToken::Complex(0..5);
+/// let b: SampleRawToken = RawToken::Complex(5..10);
+///
+/// assert!(tokens_content_equal(&a, &&source_a[..], &b, &&source_b[..]));
+/// assert!(!tokens_content_equal(&a, &&source_a[..], &RawToken::Simplex(5..10), &&source_b[..]));
+/// ```
+pub fn tokens_content_equal<T, U, V>(a: &RawToken<U, V>, src_a: &T, b: &RawToken<U, V>, src_b: &T)
+-> bool
+where
+	T: AbstractSource + AsRef<[u8]>,
+	U: ReadableBoundary,
+	V: ReadableBoundaryCollection<U> {
+	if a.kind() != b.kind() {
+		return false;
+	}
+
+	if let (RawToken::ScopeLevel(level_a), RawToken::ScopeLevel(level_b)) = (a, b) {
+		return level_a == level_b;
+	}
+
+	collect_parts(a, src_a.as_ref()) == collect_parts(b, src_b.as_ref())
+}
+
+/// Collects the bytes of each boundary. The boundaries in a collection are kept together so that
+/// the lines of different blocks would not be matched across their paragraphs.
+fn collect_parts<'a, U, V>(raw_token: &RawToken<U, V>, bytes: &'a [u8]) -> Vec<Vec<&'a [u8]>>
+where
+	U: ReadableBoundary,
+	V: ReadableBoundaryCollection<U> {
+	let boundary = |boundary: &U| vec![&bytes[boundary.start()..boundary.end()]];
+	let collection = |collection: &V| {
+		collection.boundaries()
+			.iter()
+			.map(|boundary| &bytes[boundary.start()..boundary.end()])
+			.collect()
+	};
+
+	match raw_token {
+		RawToken::Empty | RawToken::Invalid | RawToken::EmptyBlock | RawToken::ScopeLevel(_) => {
+			vec![]
+		},
+		RawToken::Block { lines, .. }
+		| RawToken::UnclosedBlock(lines)
+		| RawToken::BlockComment(lines)
+		| RawToken::UnclosedBlockComment(lines)
+		| RawToken::BlockOthertongue(lines)
+		| RawToken::UnclosedBlockOthertongue(lines)
+		| RawToken::MismatchedBlockClose { lines, .. }
+		| RawToken::BlockTooLong { lines_collected: lines, .. }
+		| RawToken::BlockTooShort { lines, .. }
+		| RawToken::MalformedBlock(lines) => vec![collection(lines)],
+		RawToken::BlockParagraphs(paragraphs) => paragraphs.iter().map(collection).collect(),
+		RawToken::LineComment(text)
+		| RawToken::Simplex(text)
+		| RawToken::Complex(text)
+		| RawToken::AttacherNoContent(text)
+		| RawToken::InvalidLabel(text)
+		| RawToken::BlankLine(text)
+		| RawToken::LineOthertongue(text) => vec![boundary(text)],
+		RawToken::MetaBlockComment { meta: header, lines }
+		| RawToken::BlockCommentWithHeader { header, lines }
+		| RawToken::TaggedBlockOthertongue { tag: header, lines }
+		| RawToken::ListAttacher(header, lines)
+		| RawToken::InlineBlockAttacher(header, lines) => vec![boundary(header), collection(lines)],
+		RawToken::Attacher(label, content) => vec![boundary(label), boundary(content)],
+		RawToken::NamespacedAttacher(segments, content)
+		| RawToken::DottedAttacher { segments, content } => {
+			vec![collection(segments), boundary(content)]
+		}
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use crate::raw_token::RawToken;
+	use super::tokens_content_equal;
+
+	type SampleRawToken = RawToken<Range<usize>, Vec<Range<usize>>>;
+
+	#[test]
+	fn can_compare_attachers_by_all_parts() {
+		let source_a = b"a:\tb";
+		let source_b = b"x\na:\t\tb\na:\tc";
+		let a: SampleRawToken = RawToken::Attacher(0..1, 3..4);
+		let same: SampleRawToken = RawToken::Attacher(2..3, 6..7);
+		let other: SampleRawToken = RawToken::Attacher(8..9, 11..12);
+
+		assert!(tokens_content_equal(&a, &&source_a[..], &same, &&source_b[..]));
+		assert!(!tokens_content_equal(&a, &&source_a[..], &other, &&source_b[..]));
+	}
+
+	#[test]
+	fn can_compare_blocks_line_by_line() {
+		let source_a = b"###\na\nb\n###";
+		let source_b = b"\t###\n\ta\n\tb\n\t###";
+		let a: SampleRawToken = RawToken::BlockComment(vec![4..5, 6..7]);
+		let same: SampleRawToken = RawToken::BlockComment(vec![6..7, 9..10]);
+		let more_lines: SampleRawToken = RawToken::BlockComment(vec![6..7, 9..10, 9..10]);
+		let othertongue: SampleRawToken = RawToken::BlockOthertongue(vec![6..7, 9..10]);
+
+		assert!(tokens_content_equal(&a, &&source_a[..], &same, &&source_b[..]));
+		assert!(!tokens_content_equal(&a, &&source_a[..], &more_lines, &&source_b[..]));
+		assert!(!tokens_content_equal(&a, &&source_a[..], &othertongue, &&source_b[..]));
+	}
+
+	#[test]
+	fn can_compare_scope_levels_by_level() {
+		let source = b"";
+		let a: SampleRawToken = RawToken::ScopeLevel(1);
+
+		assert!(tokens_content_equal(&a, &&source[..], &RawToken::ScopeLevel(1), &&source[..]));
+		assert!(!tokens_content_equal(&a, &&source[..], &RawToken::ScopeLevel(2), &&source[..]));
+	}
+}
@@ -0,0 +1,44 @@
+use crate::native::Vec;
+
+const BACKSLASH: u8 = b'\\';
+
+/// Returns the bytes of the concept without the backslashes that escape other characters.
+///
+/// The concepts lexed by [`simplex_escaped()`] keep the backslashes before the escaped characters.
+/// This strips them so the concept can be shown as it was meant to be. Each backslash escapes the
+/// next byte, so 2 backslashes become one.
+///
+/// ## Notes
+/// A backslash at the end of the concept has nothing to escape so it is kept.
+///
+/// The concepts lexed by [`simplex()`] only escape vertical lines. It does not pair backslashes, so
+/// its concepts never end with an escaped backslash like `path\\` does. Unescaping them still
+/// turns 2 backslashes into one, which differs from how [`simplex()`] has read them.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::helpers::unescape_simplex;
+///
+/// assert_eq!(unescape_simplex(&b"pipe\\|operator"), b"pipe|operator");
+/// assert_eq!(unescape_simplex(&b"path\\\\"), b"path\\");
+/// assert_eq!(unescape_simplex(&b"end\\"), b"end\\");
+/// ```
+///
+/// [`simplex()`]: ../primary_lexers/fn.simplex.html
+/// [`simplex_escaped()`]: ../primary_lexers/fn.simplex_escaped.html
+pub fn unescape_simplex<T>(concept: &T) -> Vec<u8>
+where
+	T: AsRef<[u8]> {
+	let mut bytes = concept.as_ref().iter().copied();
+	let mut unescaped = Vec::new();
+
+	while let Some(byte) = bytes.next() {
+		if byte == BACKSLASH {
+			unescaped.push(bytes.next().unwrap_or(BACKSLASH));
+		} else {
+			unescaped.push(byte);
+		}
+	}
+
+	unescaped
+}
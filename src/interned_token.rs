@@ -0,0 +1,58 @@
+use smallvec::{smallvec, SmallVec};
+use crate::abstracts::AbstractToken;
+use crate::token::TokenKind;
+
+/// Contains the IDs of the strings in an interned token.
+pub type InternedIds = SmallVec<[u32; 2]>;
+
+/// Contains the kind of a token and the IDs of its strings instead of their boundaries.
+///
+/// The IDs are in the order of the boundaries of the token. An attacher has the IDs of its label
+/// and content while a block token has an ID for each line. Since a scope level token has no
+/// string, its only ID is its level.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InternedToken {
+	/// The kind of the token.
+	pub kind: TokenKind,
+
+	/// The IDs of the strings in the token.
+	pub ids: InternedIds
+}
+
+impl AbstractToken<usize, u32, usize, u32, InternedIds> for InternedToken {
+	fn kind(&self) -> TokenKind {
+		self.kind
+	}
+
+	fn new_scope_level(level: usize) -> Self {
+		Self { kind: TokenKind::ScopeLevel, ids: smallvec![level as u32] }
+	}
+
+	fn new_line_comment(comment: u32) -> Self {
+		Self { kind: TokenKind::LineComment, ids: smallvec![comment] }
+	}
+
+	fn new_block_comment(comment: InternedIds) -> Self {
+		Self { kind: TokenKind::BlockComment, ids: comment }
+	}
+
+	fn new_simplex(concept: u32) -> Self {
+		Self { kind: TokenKind::Simplex, ids: smallvec![concept] }
+	}
+
+	fn new_complex(concept: u32) -> Self {
+		Self { kind: TokenKind::Complex, ids: smallvec![concept] }
+	}
+
+	fn new_attacher(label: u32, content: u32) -> Self {
+		Self { kind: TokenKind::Attacher, ids: smallvec![label, content] }
+	}
+
+	fn new_line_othertongue(othertongue: u32) -> Self {
+		Self { kind: TokenKind::LineOthertongue, ids: smallvec![othertongue] }
+	}
+
+	fn new_block_othertongue(othertongue: InternedIds) -> Self {
+		Self { kind: TokenKind::BlockOthertongue, ids: othertongue }
+	}
+}
@@ -0,0 +1,13 @@
+use crate::native::Range;
+use crate::unexpected_token_error::UnexpectedTokenError;
+
+/// Contains the info of the part of the source that was skipped after an unexpected token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LexError {
+	/// The byte range in the source that was skipped. It starts where the token was being lexed and
+	/// ends at the line ending, or after the whole block if a block was rejected.
+	pub skipped_range: Range<usize>,
+
+	/// The error returned by the last possible lexer.
+	pub cause: UnexpectedTokenError
+}
@@ -0,0 +1,195 @@
+/// Contains the options that change how the secondary lexers recognize tokens.
+///
+/// The default configuration makes the lexers behave like the Chearmyp specification.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexerConfig {
+	/// Tries to recognize `label::content` as an attacher before the usual attacher.
+	pub double_colon_attacher: bool,
+
+	/// Tries to recognize `label; content` as an attacher before the usual attacher.
+	pub semicolon_attacher: bool,
+
+	/// Tries to recognize `label -> content` as an attacher before the usual attacher.
+	pub arrow_attacher: bool,
+
+	/// Recognizes block comments which may contain other block comments instead of the usual block
+	/// comments. The minimum and the maximum number of lines are not applied to them.
+	pub nested_block_comments: bool,
+
+	/// Tries to recognize attachers whose labels are separated by the byte into namespace segments
+	/// before the usual attacher. Since the tokens do not have namespaces, the recognized attachers
+	/// keep their whole labels but the labels with empty segments are rejected. Use
+	/// `attacher_namespaced()` on the label to get the boundaries of the segments.
+	pub namespaced_labels: Option<u8>,
+
+	/// Tries to recognize attachers whose labels are separated by periods into segments before the
+	/// usual attacher. Like the namespaced labels, the recognized attachers keep their whole labels
+	/// but the labels with empty segments are rejected. Use `dotted_attacher()` on the label to get
+	/// the boundaries of the segments.
+	pub dotted_labels: bool,
+
+	/// Tries to recognize attachers whose contents are separated by the byte into items before the
+	/// usual attacher. Since the tokens do not have items, the recognized attachers keep their
+	/// whole contents but the contents with empty items are rejected. Use `any_with_list_items()`
+	/// to get the boundaries of the items too.
+	pub list_values: Option<u8>,
+
+	/// Excludes the spaces at the end of the attacher contents from their boundaries. If it is
+	/// false, the contents end right before a tab, a new line, or the end of the source so they may
+	/// have trailing spaces.
+	pub trim_attacher_content: bool,
+
+	/// Makes the attacher contents end before a space followed by a pound sign. The rest of the line
+	/// is treated as an inline comment and skipped without pushing any token.
+	pub inline_comments: bool,
+
+	/// Rejects attachers whose labels start with a pound sign, an equal sign, or a vertical line so
+	/// the lines are lexed as other tokens instead.
+	pub strict_labels: bool,
+
+	/// Limits the number of lines that block comments and block othertongues may have. The fallible
+	/// secondary lexers return an error for a block with more lines. The lenient ones keep the lines
+	/// collected so far as the block and skip the rest until the closing line. Nested block comments
+	/// are not limited. There is no limit if it is `None`.
+	pub max_block_lines: Option<usize>,
+
+	/// Requires the closed block comments and block othertongues to have at least the number of
+	/// lines. The fallible secondary lexers return an error for a block with fewer lines. Nested
+	/// block comments are not required. There is no minimum if it is 0 which is the default.
+	pub min_block_lines: usize,
+
+	/// Requires the opening and closing lines of block othertongues to have exactly the number of
+	/// equal signs. Lines with other numbers of equal signs are lexed as other tokens. It may be
+	/// set to 4 so that `===` can be used as the setext heading underline of Markdown. Any number
+	/// from 3 is accepted if it is `None`.
+	pub othertongue_delimiter_count: Option<usize>,
+
+	/// Makes the closed block comments and block othertongues occupy the new line after their
+	/// closing line. If it is false, the new line is left for the caller to consume. It is true by
+	/// default.
+	pub block_consume_trailing_newline: bool,
+
+	/// Decides what `lex_with_config()` does with the lines that only have tabs and spaces.
+	pub blank_line_policy: BlankLinePolicy,
+
+	/// Makes `lex_with_config()` return an error if the indentation increases by more than one
+	/// level. The levels are counted like the scope levels, including the spaces counted as tabs.
+	pub reject_indentation_jumps: bool,
+
+	/// Makes `lex_with_config()` return an error if a line has more levels of indentation than the
+	/// maximum. The levels are counted like the scope levels, including the spaces counted as tabs.
+	/// The levels beyond the maximum are not counted. There is no maximum if it is `None`.
+	pub max_tab_depth: Option<usize>,
+
+	/// Makes `lex_with_config()` push a scope level token for each level passed when the
+	/// indentation decreases by more than one tab. For example, a decrease from 3 tabs to none
+	/// pushes the scope levels 2, 1, and 0 in order. The additional scope level tokens occupy no
+	/// bytes. If it is false, only the last scope level is pushed.
+	///
+	/// The additional scope level tokens are consecutive so `verify_structure()` rejects their
+	/// kinds. Verify the kinds of the tokens lexed without this option instead.
+	pub emit_intermediate_scope_levels: bool,
+
+	/// Makes `lex_with_config()` push a token for each line ending between the tokens. Since the
+	/// tokens have no variant for line endings, each one is a scope level token of the current
+	/// level which occupies the line ending. The scope level tokens for changes of indentation are
+	/// still pushed after it. Blocks keep the line endings inside them.
+	///
+	/// A line ending token followed by a scope level token or another line ending token makes
+	/// consecutive scope levels so `verify_structure()` rejects their kinds. Verify the kinds of the
+	/// tokens lexed without this option instead.
+	pub emit_newlines: bool,
+
+	/// Decides the byte that indents the lines. Only a tab, which is the default, and a space are
+	/// supported. If it is a space, each run of spaces as long as the indent width is counted as one
+	/// tab, including in the indentation of the closing lines of blocks. Any other byte is treated
+	/// like a tab. The scope levels, the indentation jumps, and the maximum depth are counted in
+	/// this unit. The tabs between inlined tokens and inside blocks are not affected.
+	pub indent_char: u8,
+
+	/// Decides the number of indent characters that make one level of indentation. It is only used
+	/// if the indent character is a space.
+	pub indent_width: usize,
+
+	/// Makes `lex_with_config()` treat a carriage return followed by a new line as one line ending.
+	/// The tokens that end at such line ending do not contain the carriage return. The primary
+	/// lexers are not affected.
+	pub strip_cr: bool
+}
+
+impl Default for LexerConfig {
+	fn default() -> Self {
+		Self {
+			double_colon_attacher: false,
+			semicolon_attacher: false,
+			arrow_attacher: false,
+			nested_block_comments: false,
+			namespaced_labels: None,
+			dotted_labels: false,
+			list_values: None,
+			trim_attacher_content: false,
+			inline_comments: false,
+			strict_labels: false,
+			max_block_lines: None,
+			min_block_lines: 0,
+			othertongue_delimiter_count: None,
+			block_consume_trailing_newline: true,
+			blank_line_policy: BlankLinePolicy::EmitScopeLevel,
+			reject_indentation_jumps: false,
+			max_tab_depth: None,
+			emit_intermediate_scope_levels: false,
+			emit_newlines: false,
+			indent_char: b'\t',
+			indent_width: 4,
+			strip_cr: false
+		}
+	}
+}
+
+impl LexerConfig {
+	/// Returns the number of spaces counted as one tab in the indentation of the lines.
+	///
+	/// It is the indent width if the indent character is a space. Otherwise, it is 0 since only tabs
+	/// indent the lines.
+	///
+	/// ## Examples
+	/// ```
+	/// use chearmyp_lexer::LexerConfig;
+	///
+	/// let config = LexerConfig {
+	/// 	indent_char: b' ',
+	/// 	indent_width: 2,
+	/// 	..LexerConfig::default()
+	/// };
+	///
+	/// assert_eq!(config.indentation_width(), 2);
+	/// assert_eq!(LexerConfig::default().indentation_width(), 0);
+	/// ```
+	pub fn indentation_width(&self) -> usize {
+		if self.indent_char == b' ' {
+			self.indent_width
+		} else {
+			0
+		}
+	}
+}
+
+/// Contains the ways to handle the lines that only have tabs and spaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlankLinePolicy {
+	/// Skips the lines without pushing any token.
+	Ignore,
+
+	/// Lexes the lines like other lines. The tabs may become a scope level token and the spaces may
+	/// become a complex token.
+	EmitScopeLevel,
+
+	/// Pushes a token for each line that has been lexed as a blank line raw token. Since the tokens
+	/// have no variant for blank lines, each one is a scope level token of the current level which
+	/// occupies the tabs and spaces of the line. The indentation of the line is not counted.
+	///
+	/// A blank line token next to a scope level token makes consecutive scope levels so
+	/// `verify_structure()` rejects their kinds. Verify the kinds of the tokens lexed with other
+	/// policies instead.
+	EmitBlankLineToken
+}
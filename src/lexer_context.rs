@@ -0,0 +1,23 @@
+/// Contains the state of the lexer at the time a token was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexerContext {
+	/// The number of tabs stored by the lexer. For scope level tokens, it is their new scope level.
+	pub tab_count: usize,
+
+	/// The number of the line where the token starts. The first line is line 1.
+	pub line: usize
+}
+
+/// Contains the token, its last index occupied in the source, and the state of the lexer at the
+/// time it was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpannedToken<W> {
+	/// The token found.
+	pub token: W,
+
+	/// The last index occupied by the token in the source.
+	pub end_offset: usize,
+
+	/// The state of the lexer at the time the token was found.
+	pub context: LexerContext
+}
@@ -0,0 +1,24 @@
+use crate::abstracts::{
+	AbstractToken,
+	AbstractSource,
+	AbstractBoundary,
+	AbstractBoundaryCollection
+};
+use crate::token_info::TokenInfo;
+
+/// Represents a lexer of custom dialects which can be tried before the built-in lexers.
+///
+/// It can be passed to `any_with_plugins()` to recognize domain-specific syntax like a `@tag`
+/// directive without forking the crate.
+pub trait LexerPlugin<T, U, V, W>
+where
+	T: AbstractSource,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	/// Returns the info of the recognized token and its last seen index in the source if the
+	/// plugin recognizes it. Otherwise, it returns `None` so the next lexer can be tried.
+	///
+	/// The offset is the start of the token. The leading tabs of the line have been skipped.
+	fn try_lex(&self, src: T, offset: usize, tab_count: usize) -> Option<TokenInfo<W>>;
+}
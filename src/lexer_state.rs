@@ -0,0 +1,57 @@
+/// Contains the state that the lexer keeps while it goes through the source.
+///
+/// It can be copied before lexing a part of the source to restore the state later.
+///
+/// ## Notes
+/// The state is expected to hold these invariants:
+/// - The scanned size never exceeds the length of the source.
+/// - The lexer is in a new line if nothing has been scanned yet or the last scanned byte is a new
+///   line.
+/// - The tab count is the level of the last scope level token found, or 0 if there is none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LexerState {
+	/// The number of tabs that the lines are expected to be indented with.
+	pub tab_count: usize,
+
+	/// The number of bytes in the source that have been lexed.
+	pub scanned_size: usize,
+
+	/// Determines if the next token will be the first in its line.
+	pub is_in_new_line: bool
+}
+
+impl Default for LexerState {
+	fn default() -> Self {
+		Self {
+			tab_count: 0,
+			scanned_size: 0,
+			is_in_new_line: true
+		}
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use super::LexerState;
+
+	#[test]
+	fn can_start_in_new_line() {
+		let state = LexerState::default();
+
+		assert_eq!(state, LexerState { tab_count: 0, scanned_size: 0, is_in_new_line: true });
+	}
+
+	#[test]
+	fn can_restore_snapshot() {
+		let mut state = LexerState::default();
+		let snapshot = state;
+
+		state.tab_count = 1;
+		state.scanned_size = 3;
+		state.is_in_new_line = false;
+		assert_ne!(state, snapshot);
+
+		state = snapshot;
+		assert_eq!(state, LexerState::default());
+	}
+}
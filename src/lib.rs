@@ -5,12 +5,41 @@
 //!
 //! ## Features available
 //! - `no_std`: Uses the `core` crate instead of `std` crate.
+//! - `mmap`: Includes `MmapSource` which memory-maps files. It cannot be used with `no_std`.
+//! - `arbitrary`: Implements `Arbitrary` for raw tokens and includes `generate_source_for_token()`.
+//! - `interner`: Includes `lex_interned()` which replaces the strings of tokens with their IDs.
+//! - `async`: Includes `lex_chunked_async()` which lets other Tokio tasks run between chunks. It
+//!   cannot be used with `no_std`.
+//!
+//! ## Bytes consumed by primary lexers
+//! The primary lexers return the last index they have consumed along with the raw token. The
+//! secondary lexers continue from that index so the byte at the index belongs to the next token.
+//!
+//! | Lexer                 | Last index of recognized token                                     |
+//! |-----------------------|--------------------------------------------------------------------|
+//! | `line_comment()`      | At the new line or the end of the source                           |
+//! | `block_comment()`     | After the new line of the closing line or at the end of the source |
+//! | `simplex()`           | After the vertical line                                            |
+//! | `complex()`           | At the tab, the new line, or the end of the source                 |
+//! | `attacher()`          | At the tab or new line after the content, or the end of the source |
+//! | `line_othertongue()`  | At the new line or the end of the source                           |
+//! | `block_othertongue()` | After the new line of the closing line or at the end of the source |
+//!
+//! The unclosed blocks have the last index one more than the length of the source.
+//!
+//! These primary lexers can also be used from the root of the crate, like
+//! `chearmyp_lexer::simplex()`.
+
+#[cfg(all(feature = "no_std", feature = "mmap"))]
+compile_error!("The `mmap` feature cannot be used with the `no_std` feature.");
+
+#[cfg(all(feature = "no_std", feature = "async"))]
+compile_error!("The `async` feature cannot be used with the `no_std` feature.");
 
 #[cfg(feature = "no_std")]
 #[macro_use]
 extern crate alloc;
 
-#[cfg(test)]
 mod native {
 	#[cfg(feature = "no_std")]
 	pub use core::ops::Range;
@@ -18,15 +47,24 @@ mod native {
 	#[cfg(feature = "no_std")]
 	pub use alloc::{
 		vec::Vec,
-		collections::VecDeque
+		string::String,
+		collections::VecDeque,
+		collections::BTreeMap,
+		sync::Arc
 	};
 
 	#[cfg(not(feature = "no_std"))]
 	pub use std::{
 		vec::Vec,
 		ops::Range,
-		collections::VecDeque
+		string::String,
+		collections::VecDeque,
+		collections::BTreeMap,
+		sync::Arc
 	};
+
+	#[cfg(all(not(feature = "no_std"), feature = "interner"))]
+	pub use std::collections::HashMap;
 }
 
 mod abstracts {
@@ -64,7 +102,7 @@ mod token {
 #[macro_use]
 mod test_macros;
 
-/// Contains the type alias used and/or returned by some lexers.
+/// Contains the type aliases used and/or returned by some lexers.
 mod token_info;
 
 /// Contains the data structures and type aliases used and/or returned by most lexers. They can be
@@ -77,6 +115,77 @@ pub mod special_characters;
 /// Contains types of delimeter that lexers search for.
 mod delimeter;
 
+/// Contains the function to compare bytes with the sources.
+mod ascii_needle;
+
+/// Contains the traits to read the boundaries back.
+mod readable_boundary;
+
+/// Contains the implementation to generate arbitrary raw tokens and their sources.
+#[cfg(feature = "arbitrary")]
+mod arbitrary_raw_token;
+
+/// Contains the trait to view parts of the sources.
+mod seekable_abstract_source;
+
+/// Contains the trait to measure the sources.
+mod measurable_abstract_source;
+
+/// Contains the value which came from one of two places.
+mod either;
+
+/// Contains the trait to read the tokens back from token queues.
+mod readable_token_queue;
+
+/// Contains the error returned when scope levels are invalid.
+mod scope_error;
+
+/// Contains the error returned when the last possible lexer returned an unexpected raw token or a
+/// block has been rejected.
+mod unexpected_token_error;
+
+/// Contains the error returned when the kinds of the tokens do not form a valid structure.
+mod structure_error;
+
+/// Contains the error recorded when the lexer skips an unexpected token.
+mod lex_error;
+
+/// Contains the error returned when the configured lexer rejects the source.
+mod configured_lex_error;
+
+/// Contains the error returned when a line has more initial tabs than allowed.
+mod excess_tabs_error;
+
+/// Contains the error returned when the source has more bytes than allowed.
+mod source_too_large_error;
+
+/// Contains the configuration used by secondary lexers.
+mod lexer_config;
+
+/// Contains the state of the lexer that can be kept along with the tokens.
+mod lexer_context;
+
+/// Contains the state kept by the lexer while it goes through the source.
+mod lexer_state;
+
+/// Contains the map of the lines in a source.
+mod source_map;
+
+/// Contains the trait to lex custom dialects.
+mod lexer_plugin;
+
+/// Contains the interner of the strings in the interned tokens.
+#[cfg(feature = "interner")]
+mod string_interner;
+
+/// Contains the token whose strings have been interned.
+#[cfg(feature = "interner")]
+mod interned_token;
+
+/// Contains the events recorded while tracing the lexers tried.
+#[cfg(feature = "trace")]
+mod trace_event;
+
 /// Contains helper functions
 pub mod helpers;
 
@@ -86,16 +195,64 @@ pub mod secondary_lexers;
 /// Contains the lexers which create token usable for lexing only.
 pub mod primary_lexers;
 
-pub use raw_token::RawToken;
-pub use token_info::TokenInfo;
-pub use secondary_lexers::{lex, any};
+pub use raw_token::{RawToken, RawTokenKind};
+pub use token_info::{TokenInfo, SpannedTokenInfo};
+pub use lexer_config::{BlankLinePolicy, LexerConfig};
+pub use lexer_context::{LexerContext, SpannedToken};
+pub use source_map::SourceMap;
+pub use lexer_plugin::LexerPlugin;
+pub use readable_boundary::{ReadableBoundary, ReadableBoundaryCollection};
+pub use readable_token_queue::ReadableTokenQueue;
+pub use either::Either;
+pub use seekable_abstract_source::SeekableAbstractSource;
+pub use measurable_abstract_source::MeasurableAbstractSource;
+pub use scope_error::{ScopeError, ScopeErrorKind};
+pub use structure_error::{StructureError, StructureErrorKind};
+pub use unexpected_token_error::UnexpectedTokenError;
+pub use lex_error::LexError;
+pub use configured_lex_error::ConfiguredLexError;
+pub use source_too_large_error::SourceTooLargeError;
+pub use excess_tabs_error::ExcessTabsError;
+#[cfg(feature = "arbitrary")]
+pub use arbitrary_raw_token::generate_source_for_token;
+#[cfg(feature = "trace")]
+pub use trace_event::{InvalidReason, TraceEvent, TraceResult};
+#[cfg(feature = "interner")]
+pub use string_interner::StringInterner;
+#[cfg(feature = "interner")]
+pub use interned_token::{InternedIds, InternedToken};
+pub use secondary_lexers::{
+	lex,
+	any,
+	any_hinted,
+	any_spanned,
+	any_with_config,
+	try_any,
+	try_any_with_config
+};
 
-use primary_lexers::{
-	complex,
+pub use primary_lexers::{
 	simplex,
+	complex,
 	attacher,
 	line_comment,
 	block_comment,
 	line_othertongue,
 	block_othertongue
 };
+
+use primary_lexers::{
+	attacher_strict,
+	attacher_semicolon,
+	attacher_trimmed,
+	attacher_with_comment,
+	attacher_double_colon,
+	attacher_arrow,
+	attacher_namespaced,
+	dotted_attacher,
+	list_attacher,
+	block_comment_bounded,
+	block_comment_nested_with_width,
+	block_othertongue_bounded,
+	tagged_block_othertongue_bounded
+};
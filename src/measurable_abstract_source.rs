@@ -0,0 +1,114 @@
+use crate::abstracts::AbstractSource;
+
+/// Represents a source which can tell the number of its bytes.
+///
+/// The abstract sources can only tell if they are empty at an index. Some helpers need the length
+/// of the whole source which may be known without scanning it.
+///
+/// ## Notes
+/// The default implementation searches for the first index where the source is empty by doubling
+/// then halving the distance so it takes logarithmic time. Sources that know their length should
+/// override it.
+pub trait MeasurableAbstractSource: AbstractSource {
+	/// Returns the number of bytes in the source.
+	fn byte_len(&self) -> usize {
+		find_byte_len(self)
+	}
+}
+
+impl MeasurableAbstractSource for &[u8] {
+	fn byte_len(&self) -> usize {
+		self.len()
+	}
+}
+
+/// Returns the number of bytes in any abstract source like the default of
+/// [`MeasurableAbstractSource::byte_len()`].
+///
+/// The lexers only require the abstract sources so they use it to measure sources which may not
+/// implement the trait.
+///
+/// [`MeasurableAbstractSource::byte_len()`]: ./trait.MeasurableAbstractSource.html#method.byte_len
+pub(crate) fn find_byte_len<T>(src: &T) -> usize
+where
+	T: AbstractSource + ?Sized {
+	if src.is_empty_at(0) { 0 } else { find_byte_len_after(src, 0) }
+}
+
+/// Returns the number of bytes in the source which is known to have a byte at the inner index.
+pub(crate) fn find_byte_len_after<T>(src: &T, known_inner_index: usize) -> usize
+where
+	T: AbstractSource + ?Sized {
+	let mut inner_index = known_inner_index;
+	let mut step = 1;
+	while !src.is_empty_at(inner_index.saturating_add(step)) {
+		inner_index = inner_index.saturating_add(step);
+		step = step.saturating_mul(2);
+	}
+
+	let mut outer_index = inner_index.saturating_add(step);
+	while outer_index - inner_index > 1 {
+		let middle_index = inner_index + (outer_index - inner_index) / 2;
+		if src.is_empty_at(middle_index) {
+			outer_index = middle_index;
+		} else {
+			inner_index = middle_index;
+		}
+	}
+
+	outer_index
+}
+
+#[cfg(test)]
+mod t {
+	use crate::abstracts::AbstractSource;
+	use crate::helpers::{find_line_ending, StrSource};
+	use crate::native::Vec;
+	use super::{MeasurableAbstractSource, find_byte_len, find_byte_len_after};
+
+	struct ScannedSource<'a>(&'a [u8]);
+
+	impl<'a> AbstractSource for ScannedSource<'a> {
+		fn is_empty_at(&self, offset: usize) -> bool {
+			self.0.is_empty_at(offset)
+		}
+
+		fn forward_slice(self, offset: usize) -> Self {
+			Self(self.0.forward_slice(offset))
+		}
+
+		fn slice(self, start: usize, end: usize) -> Self {
+			Self(self.0.slice(start, end))
+		}
+	}
+
+	impl<'a> MeasurableAbstractSource for ScannedSource<'a> {}
+
+	#[test]
+	fn can_measure_sources_without_new_lines() {
+		for source in [&b""[..], &b"a"[..], &b"a\tb|"[..], &b"hello world"[..]] {
+			assert_eq!(source.byte_len(), find_line_ending(&source, 0));
+			assert_eq!(ScannedSource(source).byte_len(), source.byte_len());
+		}
+	}
+
+	#[test]
+	fn can_find_byte_len_after_inner_index() {
+		for size in 1..40 {
+			let source = vec![b'a'; size];
+			for known_inner_index in 0..size {
+				assert_eq!(find_byte_len_after(&&source[..], known_inner_index), size);
+			}
+
+			assert_eq!(find_byte_len(&&source[..]), size);
+		}
+
+		assert_eq!(find_byte_len(&&b""[..]), 0);
+	}
+
+	#[test]
+	fn can_measure_other_sources() {
+		assert_eq!(StrSource::new("a\nb").byte_len(), 3);
+		assert_eq!(ScannedSource(&b"a\nb"[..]).byte_len(), 3);
+	}
+}
@@ -1,28 +1,88 @@
-/// Contains `line_comment()` lexer.
+/// Contains `line_comment()`, `line_comment_full()`, `line_comment_with_prefix()`, and
+/// `line_comment_with_prefix2()` lexers.
 mod line_comment;
 
-/// Contains `block_comment()` lexer.
+/// Contains `block_comment()`, `block_comment_bounded()`, `block_comment_nested()`,
+/// `block_comment_nested_with_width()`, `block_comment_paired()`,
+/// `block_comment_paired_with_width()`, `block_comment_with_header()`, and `meta_block_comment()`
+/// lexers.
 mod block_comment;
 
-/// Contains `simplex()` lexer.
+/// Contains `simplex()`, `simplex_escaped()`, `simplex_min_len()`, and `simplex_with_terminator()`
+/// lexers.
 mod simplex;
 
 /// Contains `complex()` lexer and `determine_ending()`.
 mod complex;
 
-// /// Contains `attacher()` lexer.
+/// Contains `attacher()`, `attacher_strict()`, `attacher_semicolon()`, `attacher_trimmed()`,
+/// `attacher_with_block()`, and `attacher_with_comment()` lexers.
 mod attacher;
 
+/// Contains `quoted_attacher()` lexer.
+mod quoted_attacher;
+
+/// Contains `attacher_double_colon()` lexer.
+mod attacher_double_colon;
+
+/// Contains `attacher_arrow()` lexer.
+mod attacher_arrow;
+
+/// Contains `attacher_namespaced()` and `dotted_attacher()` lexers.
+mod attacher_namespaced;
+
+/// Contains `list_attacher()` lexer.
+mod list_attacher;
+
 /// Contains `line_othertongue()` lexer.
 mod line_othertongue;
 
-/// Contains `block_othertongue()` lexer.
+/// Contains `blank_line()` lexer.
+mod blank_line;
+
+/// Contains `block_othertongue()`, `block_othertongue_bounded()`, `block_othertongue_n()`,
+/// `block_othertongue_stripped()`, `tagged_block_othertongue()`, and
+/// `tagged_block_othertongue_bounded()` lexers.
 mod block_othertongue;
 
 pub use complex::complex;
-pub use simplex::simplex;
-pub use attacher::attacher;
-pub use line_comment::line_comment;
-pub use block_comment::block_comment;
+pub use simplex::{simplex, simplex_escaped, simplex_min_len, simplex_with_terminator};
+pub use attacher::{
+	attacher,
+	attacher_strict,
+	attacher_semicolon,
+	attacher_trimmed,
+	attacher_with_block,
+	attacher_with_comment
+};
+pub use quoted_attacher::quoted_attacher;
+pub use attacher_double_colon::attacher_double_colon;
+pub use attacher_arrow::attacher_arrow;
+pub use attacher_namespaced::{attacher_namespaced, dotted_attacher};
+pub use list_attacher::list_attacher;
+pub use line_comment::{
+	line_comment,
+	line_comment_full,
+	line_comment_with_prefix,
+	line_comment_with_prefix2
+};
+pub use block_comment::{
+	block_comment,
+	block_comment_bounded,
+	block_comment_nested,
+	block_comment_nested_with_width,
+	block_comment_paired,
+	block_comment_paired_with_width,
+	block_comment_with_header,
+	meta_block_comment
+};
 pub use line_othertongue::line_othertongue;
-pub use block_othertongue::block_othertongue;
+pub use blank_line::blank_line;
+pub use block_othertongue::{
+	block_othertongue,
+	block_othertongue_bounded,
+	block_othertongue_n,
+	block_othertongue_stripped,
+	tagged_block_othertongue,
+	tagged_block_othertongue_bounded
+};
@@ -1,7 +1,25 @@
-use crate::abstracts::{AbstractSource, ComparableAbstractSource, AbstractBoundary};
+use crate::abstracts::{
+	AbstractSource,
+	AbstractBoundary,
+	ComparableAbstractSource,
+	AbstractBoundaryCollection
+};
 use crate::delimeter::Delimeter;
+use crate::helpers::find_line_ending;
+use crate::primary_lexers::block_othertongue;
 use crate::raw_token::{RawToken, RawTokenInfo};
-use crate::special_characters::{COLON, NEW_LINE, SPACE, TAB};
+use crate::special_characters::{
+	TAB,
+	COLON,
+	EQUAL,
+	SPACE,
+	NEW_LINE,
+	SEMICOLON,
+	POUND_SIGN,
+	TRIPLE_EQUAL,
+	VERTICAL_LINE,
+	CARRIAGE_RETURN
+};
 
 /// Returns the info of recognized attacher and the last index that has been checked from the
 /// source.
@@ -13,7 +31,17 @@ use crate::special_characters::{COLON, NEW_LINE, SPACE, TAB};
 ///
 /// ## Notes
 /// If there is no valid raw token found, it will return invalid raw token along with the last index
-/// checked.
+/// checked. If the colon is followed by a new line or the end of the source, it will return an
+/// attacher without content raw token instead. It is also returned if there are only tabs and
+/// spaces between the colon and the new line.
+///
+/// The spaces between the label and the colon are not part of the label, like in `key :\tvalue`.
+/// The content ends before a tab, a new line, or the end of the source. Therefore, the spaces at
+/// the end of the content are part of it. Use [`attacher_trimmed()`] to exclude them.
+///
+/// The last index is the index of the tab or new line that ends the content, or the length of the
+/// source if there is none. The new line is not consumed but the carriage return before it is.
+/// The attachers without content only consume up to their colon.
 ///
 /// ## Examples
 /// ```
@@ -33,14 +61,319 @@ use crate::special_characters::{COLON, NEW_LINE, SPACE, TAB};
 /// assert_eq!(raw_token, RawToken::Attacher(0..5, 7..12));
 /// assert_eq!(last_index, 12);
 ///
+/// let without_content = b"hello:\nworld";
+/// let (raw_token, last_index) = attacher
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&without_content[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::AttacherNoContent(0..5));
+/// assert_eq!(last_index, 6);
+///
 /// let simplex = b"hello world";
 /// let (raw_token, last_index) = attacher
 /// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&simplex[..], 0, 0);
 /// assert_eq!(raw_token, RawToken::Invalid);
 /// assert_eq!(last_index, 11);
 /// ```
-pub fn attacher<T, U, V>(src: T, slice_offset: usize, mut search_offset: usize)
+///
+/// [`attacher_trimmed()`]: ./fn.attacher_trimmed.html
+pub fn attacher<T, U, V>(src: T, slice_offset: usize, search_offset: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	lex_attacher(src, slice_offset, search_offset, COLON, false, false)
+}
+
+/// Returns the info of recognized attacher whose content has no trailing spaces and the last index
+/// that has been checked from the source.
+///
+/// It works like [`attacher()`] but the spaces at the end of the content are excluded from its
+/// boundary. They are still counted in the last index. Trailing tabs are never part of the content
+/// since a tab ends the content.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::{attacher, attacher_trimmed};
+/// use chearmyp_lexer::RawToken;
+///
+/// let trailing_spaces = b"hello:\tworld  \n";
+/// let (raw_token, last_index) = attacher_trimmed
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&trailing_spaces[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..5, 7..12));
+/// assert_eq!(last_index, 14);
+///
+/// let (raw_token, last_index) = attacher
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&trailing_spaces[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..5, 7..14));
+/// assert_eq!(last_index, 14);
+/// ```
+///
+/// [`attacher()`]: ./fn.attacher.html
+pub fn attacher_trimmed<T, U, V>(src: T, slice_offset: usize, search_offset: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	lex_attacher(src, slice_offset, search_offset, COLON, true, false)
+}
+
+/// Returns the info of recognized attacher whose label is separated by a semicolon and the last
+/// index that has been checked from the source.
+///
+/// It works like [`attacher()`] but the label ends before a semicolon followed by a tab or space,
+/// like `color; red` which resembles the properties of CSS.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::attacher_semicolon;
+/// use chearmyp_lexer::RawToken;
+///
+/// let separated_by_semicolon = b"color; red";
+/// let (raw_token, last_index) = attacher_semicolon
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&separated_by_semicolon[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..5, 7..10));
+/// assert_eq!(last_index, 10);
+///
+/// let separated_by_colon = b"color: red";
+/// let (raw_token, last_index) = attacher_semicolon
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&separated_by_colon[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 10);
+/// ```
+///
+/// [`attacher()`]: ./fn.attacher.html
+pub fn attacher_semicolon<T, U, V>(src: T, slice_offset: usize, search_offset: usize)
 -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	lex_attacher(src, slice_offset, search_offset, SEMICOLON, false, false)
+}
+
+/// Returns the info of recognized attacher whose content excludes the inline comment and the last
+/// index that has been checked from the source.
+///
+/// It works like [`attacher()`] but the content ends before a space followed by a pound sign, like
+/// in `key:\tvalue # comment`. The inline comment is skipped until the line ending so the last
+/// index is at the line ending. The fourth argument decides if the spaces at the end of the content
+/// are excluded like in [`attacher_trimmed()`].
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::attacher_with_comment;
+/// use chearmyp_lexer::RawToken;
+///
+/// let with_comment = b"key:\tvalue  # comment\n";
+/// let (raw_token, last_index) = attacher_with_comment
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&with_comment[..], 0, 0, false);
+/// assert_eq!(raw_token, RawToken::Attacher(0..3, 5..11));
+/// assert_eq!(last_index, 21);
+///
+/// let (raw_token, last_index) = attacher_with_comment
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&with_comment[..], 0, 0, true);
+/// assert_eq!(raw_token, RawToken::Attacher(0..3, 5..10));
+/// assert_eq!(last_index, 21);
+///
+/// let with_pound_sign = b"key:\tvalue#1";
+/// let (raw_token, last_index) = attacher_with_comment
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&with_pound_sign[..], 0, 0, false);
+/// assert_eq!(raw_token, RawToken::Attacher(0..3, 5..12));
+/// assert_eq!(last_index, 12);
+/// ```
+///
+/// [`attacher()`]: ./fn.attacher.html
+/// [`attacher_trimmed()`]: ./fn.attacher_trimmed.html
+pub fn attacher_with_comment<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	is_content_trimmed: bool
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	lex_attacher(src, slice_offset, search_offset, COLON, is_content_trimmed, true)
+}
+
+/// Returns the info of recognized attacher whose label does not start with a special character
+/// and the last index that has been checked from the source.
+///
+/// It works like [`attacher()`] but it rejects labels that start with a pound sign, an equal sign,
+/// or a vertical line since those characters make other lexers recognize the line when the source
+/// is lexed again.
+///
+/// ## Notes
+/// If the label starts with one of those characters, it will return an invalid label raw token
+/// variant with the label along with the slice offset. Other sources are returned like in
+/// [`attacher()`].
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::attacher_strict;
+/// use chearmyp_lexer::RawToken;
+///
+/// let usual_label = b"hello:\tworld";
+/// let (raw_token, last_index) = attacher_strict
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&usual_label[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..5, 7..12));
+/// assert_eq!(last_index, 12);
+///
+/// let ambiguous_label = b"|hello:\tworld";
+/// let (raw_token, last_index) = attacher_strict
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&ambiguous_label[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::InvalidLabel(0..6));
+/// assert_eq!(last_index, 0);
+/// ```
+///
+/// [`attacher()`]: ./fn.attacher.html
+pub fn attacher_strict<T, U, V>(src: T, slice_offset: usize, search_offset: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	let is_label_ambiguous = src.is_same_needle_at(slice_offset, POUND_SIGN)
+		|| src.is_same_needle_at(slice_offset, EQUAL)
+		|| src.is_same_needle_at(slice_offset, VERTICAL_LINE);
+
+	match attacher(src, slice_offset, search_offset) {
+		(RawToken::Attacher(label, _), _) | (RawToken::AttacherNoContent(label), _)
+		if is_label_ambiguous => {
+			(RawToken::InvalidLabel(label), slice_offset)
+		},
+		info => info
+	}
+}
+
+/// Returns the info of recognized attacher whose content may be a block othertongue and the last
+/// index that has been checked from the source.
+///
+/// It works like [`attacher()`] but it needs the number of tabs must the terminating equal signs be
+/// indented as the fourth argument (known as the tab count). If the content starts with 3 equal
+/// signs, the block othertongue will be lexed from there and its lines will be the content, like in
+/// `label:\t===`.
+///
+/// ## Notes
+/// If the block othertongue has not been closed properly, its raw token variant will be returned
+/// instead.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::attacher_with_block;
+/// use chearmyp_lexer::RawToken;
+///
+/// let with_block = b"\thello:\t===\n\tworld\n\t===\n";
+/// let (raw_token, last_index) = attacher_with_block
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&with_block[..], 1, 1, 1);
+/// assert_eq!(raw_token, RawToken::InlineBlockAttacher(1..6, vec![12..18]));
+/// assert_eq!(last_index, 24);
+///
+/// let usual = b"hello:\tworld";
+/// let (raw_token, last_index) = attacher_with_block
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&usual[..], 0, 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..5, 7..12));
+/// assert_eq!(last_index, 12);
+/// ```
+///
+/// [`attacher()`]: ./fn.attacher.html
+pub fn attacher_with_block<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	tab_count: usize
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let (label_end, content_start) = match lex_label(&src, slice_offset, search_offset, COLON) {
+		Ok(label_info) => label_info,
+		Err(info) => return info
+	};
+	let label = U::new(slice_offset, label_end);
+
+	if !src.is_same_needle_at(content_start, TRIPLE_EQUAL) {
+		return lex_content(src, label, content_start, false, false);
+	}
+
+	match block_othertongue(src, content_start, tab_count) {
+		(RawToken::BlockOthertongue(lines), offset) => {
+			(RawToken::InlineBlockAttacher(label, lines), offset)
+		},
+		info => info
+	}
+}
+
+fn lex_attacher<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	separator: &'static str,
+	is_content_trimmed: bool,
+	is_comment_excluded: bool
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	lex_attacher_with_label_end(
+		src,
+		slice_offset,
+		search_offset,
+		separator,
+		is_content_trimmed,
+		is_comment_excluded
+	).0
+}
+
+/// Returns the info of recognized attacher like [`attacher()`] and the end of its label without the
+/// spaces before the colon. The label end is the slice offset if there is no attacher.
+///
+/// [`attacher()`]: ./fn.attacher.html
+pub(crate) fn attacher_with_label_end<T, U, V>(src: T, slice_offset: usize, search_offset: usize)
+-> (RawTokenInfo<U, V>, usize)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	lex_attacher_with_label_end(src, slice_offset, search_offset, COLON, false, false)
+}
+
+fn lex_attacher_with_label_end<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	separator: &'static str,
+	is_content_trimmed: bool,
+	is_comment_excluded: bool
+) -> (RawTokenInfo<U, V>, usize)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	match lex_label(&src, slice_offset, search_offset, separator) {
+		Ok((label_end, content_start)) => {
+			let info = lex_content(
+				src,
+				U::new(slice_offset, label_end),
+				content_start,
+				is_content_trimmed,
+				is_comment_excluded
+			);
+			(info, label_end)
+		},
+		Err(info) => (info, slice_offset)
+	}
+}
+
+/// Returns the end of the label and where the content starts, or the info of the raw token if there
+/// is no content.
+fn lex_label<T, U, V>(
+	src: &T,
+	slice_offset: usize,
+	mut search_offset: usize,
+	separator: &'static str
+) -> Result<(usize, usize), RawTokenInfo<U, V>>
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str>,
 	U: AbstractBoundary<usize> {
@@ -48,53 +381,99 @@ where
 	let label_end;
 
 	loop {
-		let separator = determine_separator(&src, search_offset);
-		match separator {
+		match determine_separator(src, search_offset, separator) {
 			Delimeter::Incorrect => search_offset += 1,
 			Delimeter::Pad => {
-				label_end = search_offset;
+				label_end = find_label_end(src, label_start, search_offset);
 				search_offset += 1;
 				break;
 			},
-			_ => return (RawToken::Invalid, search_offset)
+			Delimeter::Invalid if src.is_same_needle_at(search_offset, separator) => {
+				let label = U::new(label_start, find_label_end(src, label_start, search_offset));
+				return Err((RawToken::AttacherNoContent(label), search_offset + 1))
+			},
+			_ => return Err((RawToken::Invalid, search_offset))
 		}
 	}
 
-	let label = U::new(label_start, label_end);
-
 	loop {
 		if src.is_same_needle_at(search_offset, TAB) || src.is_same_needle_at(search_offset, SPACE) {
 			search_offset += 1;
 		} else if src.is_empty_at(search_offset) {
-			return (RawToken::Invalid, search_offset)
+			return Err((RawToken::Invalid, search_offset))
+		} else if src.is_same_needle_at(search_offset, NEW_LINE)
+		|| src.is_same_needle_at(search_offset, CARRIAGE_RETURN)
+		&& src.is_same_needle_at(search_offset + 1, NEW_LINE) {
+			let label = U::new(label_start, label_end);
+			return Err((RawToken::AttacherNoContent(label), search_offset))
 		} else {
 			break;
 		}
 	}
 
-	let content_start = search_offset;
+	Ok((label_end, search_offset))
+}
+
+/// Returns the end of the label without the spaces before the separator. The spaces inside the
+/// label are kept.
+fn find_label_end<T>(src: &T, label_start: usize, separator_offset: usize) -> usize
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let mut label_end = separator_offset;
+	while label_end > label_start && src.is_same_needle_at(label_end - 1, SPACE) {
+		label_end -= 1;
+	}
+
+	label_end
+}
+
+fn lex_content<T, U, V>(
+	src: T,
+	label: U,
+	content_start: usize,
+	is_content_trimmed: bool,
+	is_comment_excluded: bool
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	let mut search_offset = content_start;
 	let content_end;
 
 	loop {
-		let ending = determine_ending(&src, search_offset);
+		let ending = if is_comment_excluded {
+			determine_content_ending_with_comment(&src, search_offset)
+		} else {
+			determine_ending(&src, search_offset)
+		};
 		match ending {
 			Delimeter::Incorrect => search_offset += 1,
 			Delimeter::Pad | Delimeter::Limit => {
 				content_end = search_offset;
+				if is_comment_excluded && src.is_same_needle_at(search_offset, SPACE) {
+					search_offset = find_line_ending(&src, search_offset);
+				}
+				// The carriage return of CRLF line endings is not part of the content.
+				if src.is_same_needle_at(search_offset, CARRIAGE_RETURN) { search_offset += 1; }
 				break;
 			},
 			Delimeter::Invalid => return (RawToken::Invalid, search_offset)
 		}
 	}
 
+	let mut content_end = content_end;
+	while is_content_trimmed && src.is_same_needle_at(content_end - 1, SPACE) {
+		content_end -= 1;
+	}
+
 	let content = U::new(content_start, content_end);
 	(RawToken::Attacher(label, content), search_offset)
 }
 
-fn determine_separator<T>(src: &T, offset: usize) -> Delimeter
+fn determine_separator<T>(src: &T, offset: usize, separator: &'static str) -> Delimeter
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> {
-	if src.is_same_needle_at(offset, COLON) {
+	if src.is_same_needle_at(offset, separator) {
 		let next_offset = offset + 1;
 		if src.is_same_needle_at(next_offset, TAB) || src.is_same_needle_at(next_offset, SPACE) {
 			Delimeter::Pad
@@ -115,7 +494,9 @@ where
 fn determine_ending<T>(src: &T, offset: usize) -> Delimeter
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> {
-	if src.is_same_needle_at(offset, NEW_LINE) || src.is_same_needle_at(offset, TAB) {
+	let is_crlf = src.is_same_needle_at(offset, CARRIAGE_RETURN)
+		&& src.is_same_needle_at(offset + 1, NEW_LINE);
+	if src.is_same_needle_at(offset, NEW_LINE) || src.is_same_needle_at(offset, TAB) || is_crlf {
 		Delimeter::Pad
 	} else if src.is_empty_at(offset) {
 		Delimeter::Limit
@@ -124,10 +505,28 @@ where
 	}
 }
 
+fn determine_content_ending_with_comment<T>(src: &T, offset: usize) -> Delimeter
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	if src.is_same_needle_at(offset, SPACE) && src.is_same_needle_at(offset + 1, POUND_SIGN) {
+		Delimeter::Pad
+	} else {
+		determine_ending(src, offset)
+	}
+}
+
 #[cfg(test)]
 mod t {
 	use crate::native::{Range, Vec};
-	use super::{RawToken, attacher};
+	use super::{
+		RawToken,
+		attacher,
+		attacher_strict,
+		attacher_semicolon,
+		attacher_trimmed,
+		attacher_with_block,
+		attacher_with_comment
+	};
 
 	macro_rules! test_attacher {
 		(
@@ -135,7 +534,15 @@ mod t {
 			$expected_token:expr,
 			$expected_consumption:literal
 		) => {
-			let (raw_token, consumed_size) = attacher
+			test_attacher!(attacher, $sample, $expected_token, $expected_consumption);
+		};
+		(
+			$lexer:ident,
+			$sample:literal,
+			$expected_token:expr,
+			$expected_consumption:literal
+		) => {
+			let (raw_token, consumed_size) = $lexer
 				::<&[u8], Range<usize>, Vec<Range<usize>>>(&&$sample[..], 0, 0);
 			assert_eq!(raw_token, $expected_token);
 			assert_eq!(consumed_size, $expected_consumption);
@@ -156,6 +563,12 @@ mod t {
 		test_attacher!(b"h:	i	j:	k", Attacher!(0..1, 3..4), 4);
 	}
 
+	#[test]
+	fn can_lex_with_crlf_line_ending() {
+		test_attacher!(b"a:\tb\r\n", Attacher!(0..1, 3..4), 5);
+		test_attacher!(b"c:\td\re\r\n", Attacher!(0..1, 3..6), 7);
+	}
+
 	#[test]
 	fn cannot_lex() {
 		test_attacher!(b"lm", RawToken::Invalid, 2);
@@ -163,8 +576,119 @@ mod t {
 		test_attacher!(b"o:	", RawToken::Invalid, 3);
 	}
 
+	#[test]
+	fn can_lex_without_content() {
+		test_attacher!(b"r:", RawToken::AttacherNoContent(0..1), 2);
+		test_attacher!(b"st:\nu", RawToken::AttacherNoContent(0..2), 3);
+		test_attacher!(b"v:w:", RawToken::AttacherNoContent(0..3), 4);
+	}
+
+	#[test]
+	fn can_lex_with_padding_only_as_without_content() {
+		test_attacher!(b"key:\t\n", RawToken::AttacherNoContent(0..3), 5);
+		test_attacher!(b"x: \t\r\ny", RawToken::AttacherNoContent(0..1), 4);
+	}
+
 	#[test]
 	fn can_lex_separated_by_colon_then_space() {
 		test_attacher!(b"p: q", Attacher!(0..1, 3..4), 4);
 	}
+
+	#[test]
+	fn can_lex_with_spaces_before_colon() {
+		test_attacher!(b"key :\tvalue", Attacher!(0..3, 6..11), 11);
+		test_attacher!(b"a b  : c", Attacher!(0..3, 7..8), 8);
+		test_attacher!(b"d :\n", RawToken::AttacherNoContent(0..1), 3);
+	}
+
+	#[test]
+	fn can_lex_with_trimmed_content() {
+		let (raw_token, consumed_size) = attacher_trimmed
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"x:\ty z  \t"[..], 0, 0);
+		assert_eq!(raw_token, Attacher!(0..1, 3..6));
+		assert_eq!(consumed_size, 8);
+	}
+
+	#[test]
+	fn can_lex_with_untrimmed_content() {
+		test_attacher!(b"x:\ty z  \t", Attacher!(0..1, 3..8), 8);
+	}
+
+	#[test]
+	fn can_lex_separated_by_semicolon() {
+		test_attacher!(attacher_semicolon, b"a;	b", Attacher!(0..1, 3..4), 4);
+		test_attacher!(attacher_semicolon, b"cd;		e", Attacher!(0..2, 5..6), 6);
+		test_attacher!(attacher_semicolon, b"f;		g\n", Attacher!(0..1, 4..5), 5);
+		test_attacher!(attacher_semicolon, b"h;	i	j;	k", Attacher!(0..1, 3..4), 4);
+		test_attacher!(attacher_semicolon, b"p; q", Attacher!(0..1, 3..4), 4);
+	}
+
+	#[test]
+	fn cannot_lex_separated_by_semicolon() {
+		test_attacher!(attacher_semicolon, b"lm", RawToken::Invalid, 2);
+		test_attacher!(attacher_semicolon, b"n|", RawToken::Invalid, 2);
+		test_attacher!(attacher_semicolon, b"o;	", RawToken::Invalid, 3);
+		test_attacher!(attacher_semicolon, b"p:	q", RawToken::Invalid, 2);
+	}
+
+	#[test]
+	fn can_lex_separated_by_semicolon_without_content() {
+		test_attacher!(attacher_semicolon, b"r;", RawToken::AttacherNoContent(0..1), 2);
+		test_attacher!(attacher_semicolon, b"st;\nu", RawToken::AttacherNoContent(0..2), 3);
+		test_attacher!(attacher_semicolon, b"v;w;", RawToken::AttacherNoContent(0..3), 4);
+	}
+
+	#[test]
+	fn can_lex_strictly() {
+		test_attacher!(attacher_strict, b"a:	b", Attacher!(0..1, 3..4), 4);
+		test_attacher!(attacher_strict, b"c#:	d", Attacher!(0..2, 4..5), 5);
+		test_attacher!(attacher_strict, b"e:", RawToken::AttacherNoContent(0..1), 2);
+	}
+
+	#[test]
+	fn cannot_lex_strictly() {
+		test_attacher!(attacher_strict, b"#f:	g", RawToken::InvalidLabel(0..2), 0);
+		test_attacher!(attacher_strict, b"=h:	i", RawToken::InvalidLabel(0..2), 0);
+		test_attacher!(attacher_strict, b"|j:", RawToken::InvalidLabel(0..2), 0);
+	}
+
+	#[test]
+	fn can_lex_with_block() {
+		let source = b"a:\t===\nb\nc\n===\nd";
+		let (raw_token, last_index) = attacher_with_block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, 0);
+		assert_eq!(raw_token, RawToken::InlineBlockAttacher(0..1, vec![7..8, 9..10]));
+		assert_eq!(last_index, 15);
+
+		let source = b"e:\t==";
+		let (raw_token, last_index) = attacher_with_block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, 0);
+		assert_eq!(raw_token, Attacher!(0..1, 3..5));
+		assert_eq!(last_index, 5);
+	}
+
+	#[test]
+	fn cannot_lex_with_unclosed_block() {
+		let source = b"a:\t===\nb\n\t===";
+		let (raw_token, last_index) = attacher_with_block
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, 0);
+		assert_eq!(raw_token, RawToken::UnclosedBlockOthertongue(vec![7..8, 9..13]));
+		assert_eq!(last_index, 14);
+	}
+
+	#[test]
+	fn can_lex_without_inline_comment() {
+		let samples = [
+			(&b"a:\tb # c"[..], Attacher!(0..1, 3..4), 8),
+			(&b"d:\te f #\r\ng"[..], Attacher!(0..1, 3..6), 9),
+			(&b"h:\ti#j\tk"[..], Attacher!(0..1, 3..6), 6)
+		];
+
+		for (sample, expected_token, expected_consumption) in samples {
+			let (raw_token, consumed_size) = attacher_with_comment
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(sample, 0, 0, false);
+			assert_eq!(raw_token, expected_token);
+			assert_eq!(consumed_size, expected_consumption);
+		}
+	}
 }
@@ -0,0 +1,148 @@
+use crate::abstracts::{AbstractSource, ComparableAbstractSource, AbstractBoundary};
+use crate::delimeter::Delimeter;
+use crate::raw_token::{RawToken, RawTokenInfo};
+use crate::special_characters::{NEW_LINE, SPACED_ARROW, TAB};
+
+/// Returns the info of recognized attacher separated by an arrow and the last index that has been
+/// checked from the source.
+///
+/// It works like [`attacher_double_colon()`] but the label and content are separated by a space,
+/// a dash, a greater-than sign, and another space, like `label -> value` which resembles the
+/// notation of maps. The content ends before a tab, a new line, or the end of the source.
+///
+/// ## Notes
+/// If there is no valid raw token found, it will return invalid raw token along with the last index
+/// checked.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::attacher_arrow;
+/// use chearmyp_lexer::RawToken;
+///
+/// let terminated = b"hello -> world\n";
+/// let (raw_token, last_index) = attacher_arrow
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&terminated[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..5, 9..14));
+/// assert_eq!(last_index, 14);
+///
+/// let usual_attacher = b"hello:\tworld";
+/// let (raw_token, last_index) = attacher_arrow
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&usual_attacher[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 6);
+/// ```
+///
+/// [`attacher_double_colon()`]: ./fn.attacher_double_colon.html
+pub fn attacher_arrow<T, U, V>(src: T, slice_offset: usize, mut search_offset: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	let label_start = slice_offset;
+	let label_end;
+
+	loop {
+		let separator = determine_separator_arrow(&src, search_offset);
+		match separator {
+			Delimeter::Incorrect => search_offset += 1,
+			Delimeter::Pad => {
+				label_end = search_offset;
+				search_offset += SPACED_ARROW.len();
+				break;
+			},
+			_ => return (RawToken::Invalid, search_offset)
+		}
+	}
+
+	let label = U::new(label_start, label_end);
+	let content_start = search_offset;
+	let content_end;
+
+	loop {
+		let ending = determine_ending(&src, search_offset);
+		match ending {
+			Delimeter::Incorrect => search_offset += 1,
+			Delimeter::Pad | Delimeter::Limit => {
+				content_end = search_offset;
+				break;
+			},
+			Delimeter::Invalid => return (RawToken::Invalid, search_offset)
+		}
+	}
+
+	if content_start == content_end {
+		return (RawToken::Invalid, search_offset);
+	}
+
+	let content = U::new(content_start, content_end);
+	(RawToken::Attacher(label, content), search_offset)
+}
+
+fn determine_separator_arrow<T>(src: &T, offset: usize) -> Delimeter
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	if src.is_same_needle_at(offset, SPACED_ARROW) {
+		Delimeter::Pad
+	} else if src.is_same_needle_at(offset, NEW_LINE) || src.is_same_needle_at(offset, TAB) {
+		Delimeter::Invalid
+	} else if src.is_empty_at(offset) {
+		Delimeter::Limit
+	} else {
+		Delimeter::Incorrect
+	}
+}
+
+fn determine_ending<T>(src: &T, offset: usize) -> Delimeter
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	if src.is_same_needle_at(offset, NEW_LINE) || src.is_same_needle_at(offset, TAB) {
+		Delimeter::Pad
+	} else if src.is_empty_at(offset) {
+		Delimeter::Limit
+	} else {
+		Delimeter::Incorrect
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use super::{RawToken, attacher_arrow};
+
+	macro_rules! test_attacher_arrow {
+		(
+			$sample:literal,
+			$expected_token:expr,
+			$expected_consumption:literal
+		) => {
+			let (raw_token, consumed_size) = attacher_arrow
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&&$sample[..], 0, 0);
+			assert_eq!(raw_token, $expected_token);
+			assert_eq!(consumed_size, $expected_consumption);
+		};
+	}
+
+	macro_rules! Attacher {
+		($label:expr, $content:expr) => {
+			RawToken::Attacher($label, $content)
+		};
+	}
+
+	#[test]
+	fn can_lex() {
+		test_attacher_arrow!(b"a -> b", Attacher!(0..1, 5..6), 6);
+		test_attacher_arrow!(b"c d -> e f", Attacher!(0..3, 7..10), 10);
+		test_attacher_arrow!(b"g -> h\n", Attacher!(0..1, 5..6), 6);
+		test_attacher_arrow!(b"i->j -> k\tl -> m", Attacher!(0..4, 8..9), 9);
+	}
+
+	#[test]
+	fn cannot_lex() {
+		test_attacher_arrow!(b"no", RawToken::Invalid, 2);
+		test_attacher_arrow!(b"p->q", RawToken::Invalid, 4);
+		test_attacher_arrow!(b"r -> ", RawToken::Invalid, 5);
+		test_attacher_arrow!(b"s -> \nt", RawToken::Invalid, 5);
+		test_attacher_arrow!(b"u:\tv -> w", RawToken::Invalid, 2);
+	}
+}
@@ -0,0 +1,146 @@
+use crate::abstracts::{AbstractSource, ComparableAbstractSource, AbstractBoundary};
+use crate::delimeter::Delimeter;
+use crate::raw_token::{RawToken, RawTokenInfo};
+use crate::special_characters::{DOUBLE_COLON, NEW_LINE, TAB};
+
+/// Returns the info of recognized attacher separated by double colon and the last index that has
+/// been checked from the source.
+///
+/// It needs an array of bytes as the first argument (known as source), where to start slicing
+/// (known as slice offset) as the second argument, and where to start looking for the double colon
+/// as the third argument (known as the search offset). The content starts right after the double
+/// colon and ends before a tab, a new line, or the end of the source.
+///
+/// ## Notes
+/// If there is no valid raw token found, it will return invalid raw token along with the last index
+/// checked.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::attacher_double_colon;
+/// use chearmyp_lexer::RawToken;
+///
+/// let terminated = b"hello::world\n";
+/// let (raw_token, last_index) = attacher_double_colon
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&terminated[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..5, 7..12));
+/// assert_eq!(last_index, 12);
+///
+/// let usual_attacher = b"hello:\tworld";
+/// let (raw_token, last_index) = attacher_double_colon
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&usual_attacher[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 6);
+/// ```
+pub fn attacher_double_colon<T, U, V>(src: T, slice_offset: usize, mut search_offset: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	let label_start = slice_offset;
+	let label_end;
+
+	loop {
+		let separator = determine_separator(&src, search_offset);
+		match separator {
+			Delimeter::Incorrect => search_offset += 1,
+			Delimeter::Pad => {
+				label_end = search_offset;
+				search_offset += 2;
+				break;
+			},
+			_ => return (RawToken::Invalid, search_offset)
+		}
+	}
+
+	let label = U::new(label_start, label_end);
+	let content_start = search_offset;
+	let content_end;
+
+	loop {
+		let ending = determine_ending(&src, search_offset);
+		match ending {
+			Delimeter::Incorrect => search_offset += 1,
+			Delimeter::Pad | Delimeter::Limit => {
+				content_end = search_offset;
+				break;
+			},
+			Delimeter::Invalid => return (RawToken::Invalid, search_offset)
+		}
+	}
+
+	if content_start == content_end {
+		return (RawToken::Invalid, search_offset);
+	}
+
+	let content = U::new(content_start, content_end);
+	(RawToken::Attacher(label, content), search_offset)
+}
+
+fn determine_separator<T>(src: &T, offset: usize) -> Delimeter
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	if src.is_same_needle_at(offset, DOUBLE_COLON) {
+		Delimeter::Pad
+	} else if src.is_same_needle_at(offset, NEW_LINE) || src.is_same_needle_at(offset, TAB) {
+		Delimeter::Invalid
+	} else if src.is_empty_at(offset) {
+		Delimeter::Limit
+	} else {
+		Delimeter::Incorrect
+	}
+}
+
+fn determine_ending<T>(src: &T, offset: usize) -> Delimeter
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	if src.is_same_needle_at(offset, NEW_LINE) || src.is_same_needle_at(offset, TAB) {
+		Delimeter::Pad
+	} else if src.is_empty_at(offset) {
+		Delimeter::Limit
+	} else {
+		Delimeter::Incorrect
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use super::{RawToken, attacher_double_colon};
+
+	macro_rules! test_attacher_double_colon {
+		(
+			$sample:literal,
+			$expected_token:expr,
+			$expected_consumption:literal
+		) => {
+			let (raw_token, consumed_size) = attacher_double_colon
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&&$sample[..], 0, 0);
+			assert_eq!(raw_token, $expected_token);
+			assert_eq!(consumed_size, $expected_consumption);
+		};
+	}
+
+	macro_rules! Attacher {
+		($label:expr, $content:expr) => {
+			RawToken::Attacher($label, $content)
+		};
+	}
+
+	#[test]
+	fn can_lex() {
+		test_attacher_double_colon!(b"a::b", Attacher!(0..1, 3..4), 4);
+		test_attacher_double_colon!(b"cd::e f", Attacher!(0..2, 4..7), 7);
+		test_attacher_double_colon!(b"g::h\n", Attacher!(0..1, 3..4), 4);
+		test_attacher_double_colon!(b"i::j\tk::l", Attacher!(0..1, 3..4), 4);
+	}
+
+	#[test]
+	fn cannot_lex() {
+		test_attacher_double_colon!(b"mn", RawToken::Invalid, 2);
+		test_attacher_double_colon!(b"o:\tp", RawToken::Invalid, 2);
+		test_attacher_double_colon!(b"q::", RawToken::Invalid, 3);
+		test_attacher_double_colon!(b"r::\ns", RawToken::Invalid, 3);
+	}
+}
@@ -0,0 +1,209 @@
+use crate::abstracts::{
+	AbstractSource,
+	AbstractBoundary,
+	ComparableAbstractSource,
+	AbstractBoundaryCollection
+};
+use crate::ascii_needle::ascii_needle;
+use crate::primary_lexers::attacher::attacher_with_label_end;
+use crate::raw_token::{RawToken, RawTokenInfo};
+use crate::special_characters::PERIOD;
+
+/// Returns the info of recognized attacher with a label separated into namespace segments and the
+/// last index that has been checked from the source.
+///
+/// It needs an array of bytes as the first argument (known as source), where to start slicing
+/// (known as slice offset) as the second argument, where to start looking for the terminator as the
+/// third argument (known as the search offset), and the byte which separates the segments of the
+/// label as the fourth argument (known as the namespace separator).
+///
+/// ## Notes
+/// The source will be lexed by [`attacher()`] lexer first. Other raw tokens than the attacher will
+/// be returned as is. If one of the segments is empty, it will return invalid raw token along with
+/// the index of the namespace separator that ends it. A namespace separator that is not an ASCII
+/// character never separates the label.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::attacher_namespaced;
+/// use chearmyp_lexer::RawToken;
+///
+/// let namespaced = b"database.host:\tlocalhost";
+/// let (raw_token, last_index) = attacher_namespaced
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&namespaced[..], 0, 0, b'.');
+/// assert_eq!(raw_token, RawToken::NamespacedAttacher(vec![0..8, 9..13], 15..24));
+/// assert_eq!(last_index, 24);
+///
+/// let empty_segment = b"database..host:\tlocalhost";
+/// let (raw_token, last_index) = attacher_namespaced
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&empty_segment[..], 0, 0, b'.');
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 9);
+/// ```
+///
+/// [`attacher()`]: ./fn.attacher.html
+pub fn attacher_namespaced<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	namespace_sep: u8
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let (content, last_seen_index, label_end) = match attacher_with_label_end(
+		src.clone(),
+		slice_offset,
+		search_offset
+	) {
+		((RawToken::Attacher(_, content), last_seen_index), label_end) => {
+			(content, last_seen_index, label_end)
+		},
+		(info, _) => return info
+	};
+
+	let separator = ascii_needle(namespace_sep);
+	let mut segments: Option<V> = None;
+	let mut segment_start = slice_offset;
+
+	for offset in slice_offset..=label_end {
+		let is_segment_end = offset == label_end
+			|| separator.is_some_and(|separator| src.is_same_needle_at(offset, separator));
+		if !is_segment_end {
+			continue;
+		}
+
+		if offset == segment_start {
+			return (RawToken::Invalid, offset);
+		}
+
+		match segments.as_mut() {
+			Some(segments) => segments.add(U::new(segment_start, offset)),
+			None => segments = Some(V::new(segment_start, offset))
+		}
+		segment_start = offset + 1;
+	}
+
+	match segments {
+		Some(segments) => (RawToken::NamespacedAttacher(segments, content), last_seen_index),
+		None => (RawToken::Invalid, label_end)
+	}
+}
+
+/// Returns the info of recognized dotted attacher with a label separated by periods and the last
+/// index that has been checked from the source.
+///
+/// It works like [`attacher_namespaced()`] with a period as the namespace separator but the
+/// segments and the content are returned in a dotted attacher raw token. Therefore, hierarchical
+/// keys like `database.pool.size` have a segment for each level. Enable it in the secondary lexers
+/// by setting `dotted_labels` of the lexer configuration.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::dotted_attacher;
+/// use chearmyp_lexer::RawToken;
+///
+/// let dotted = b"database.pool.size:\t10";
+/// let (raw_token, last_index) = dotted_attacher
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&dotted[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::DottedAttacher {
+/// 	segments: vec![0..8, 9..13, 14..18],
+/// 	content: 20..22
+/// });
+/// assert_eq!(last_index, 22);
+/// ```
+///
+/// [`attacher_namespaced()`]: ./fn.attacher_namespaced.html
+pub fn dotted_attacher<T, U, V>(src: T, slice_offset: usize, search_offset: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	match attacher_namespaced(src, slice_offset, search_offset, PERIOD.as_bytes()[0]) {
+		(RawToken::NamespacedAttacher(segments, content), last_seen_index) => {
+			(RawToken::DottedAttacher { segments, content }, last_seen_index)
+		},
+		info => info
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use super::{RawToken, attacher_namespaced, dotted_attacher};
+
+	macro_rules! test_attacher_namespaced {
+		(
+			$sample:literal separated by $separator:literal,
+			$expected_token:expr,
+			$expected_consumption:literal
+		) => {
+			let (raw_token, consumed_size) = attacher_namespaced
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&&$sample[..], 0, 0, $separator);
+			assert_eq!(raw_token, $expected_token);
+			assert_eq!(consumed_size, $expected_consumption);
+		};
+	}
+
+	macro_rules! NamespacedAttacher {
+		([$($segment:expr),+], $content:expr) => {
+			RawToken::NamespacedAttacher(vec![$($segment),+], $content)
+		};
+	}
+
+	#[test]
+	fn can_lex() {
+		test_attacher_namespaced!(
+			b"a.b:\tc" separated by b'.',
+			NamespacedAttacher!([0..1, 2..3], 5..6),
+			6
+		);
+		test_attacher_namespaced!(
+			b"de/f/g: h\n" separated by b'/',
+			NamespacedAttacher!([0..2, 3..4, 5..6], 8..9),
+			9
+		);
+	}
+
+	#[test]
+	fn can_lex_with_spaces_before_colon() {
+		test_attacher_namespaced!(
+			b"a.b :\tc" separated by b'.',
+			NamespacedAttacher!([0..1, 2..3], 6..7),
+			7
+		);
+	}
+
+	#[test]
+	fn cannot_lex() {
+		test_attacher_namespaced!(b"k..l:\tm" separated by b'.', RawToken::Invalid, 2);
+		test_attacher_namespaced!(b".n:\to" separated by b'.', RawToken::Invalid, 0);
+		test_attacher_namespaced!(b"p.:\tq" separated by b'.', RawToken::Invalid, 2);
+		test_attacher_namespaced!(b"r.s" separated by b'.', RawToken::Invalid, 3);
+	}
+
+	#[test]
+	fn can_lex_dotted_attacher() {
+		let (raw_token, consumed_size) = dotted_attacher
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"v.w/x:\ty"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::DottedAttacher {
+			segments: vec![0..1, 2..5],
+			content: 7..8
+		});
+		assert_eq!(consumed_size, 8);
+
+		let (raw_token, consumed_size) = dotted_attacher
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"z..a:\tb"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::Invalid);
+		assert_eq!(consumed_size, 2);
+	}
+
+	#[test]
+	fn can_lex_other_attachers() {
+		test_attacher_namespaced!(b"t.u:" separated by b'.', RawToken::AttacherNoContent(0..3), 4);
+	}
+}
@@ -0,0 +1,86 @@
+use crate::abstracts::{AbstractSource, AbstractBoundary, ComparableAbstractSource};
+use crate::helpers::find_line_ending_and_next;
+use crate::raw_token::{RawToken, RawTokenInfo};
+use crate::special_characters::{CARRIAGE_RETURN, SPACE, TAB};
+
+/// Returns the info of recognized blank line and its last index occupied in the source.
+///
+/// It needs an array of bytes as the first argument (known as source) and where the line starts as
+/// the second argument (known as the offset). A blank line only has tabs, spaces, and carriage
+/// returns before its line ending. The raw token and the last index end before the line ending,
+/// including the carriage return of a CRLF line ending.
+///
+/// ## Notes
+/// If there is no character at the offset from the source, it will return an empty raw token
+/// variant. If the line has other characters, it will return an invalid raw token variant with the
+/// offset.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::blank_line;
+/// use chearmyp_lexer::RawToken;
+///
+/// let blank = b"\t  \nhello";
+/// let (raw_token, last_index) = blank_line
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&blank[..], 0);
+/// assert_eq!(raw_token, RawToken::BlankLine(0..3));
+/// assert_eq!(last_index, 3);
+///
+/// let non_blank = b"\thello";
+/// let (raw_token, last_index) = blank_line
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&non_blank[..], 0);
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 0);
+/// ```
+pub fn blank_line<T, U, V>(src: T, offset: usize) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	if src.is_empty_at(offset) {
+		return (RawToken::Empty, offset);
+	}
+
+	let (line_end, _) = find_line_ending_and_next(&src, offset);
+	let is_blank = (offset..line_end).all(|index| {
+		src.is_same_needle_at(index, TAB)
+		|| src.is_same_needle_at(index, SPACE)
+		|| src.is_same_needle_at(index, CARRIAGE_RETURN)
+	});
+
+	if is_blank {
+		(RawToken::BlankLine(U::new(offset, line_end)), line_end)
+	} else {
+		(RawToken::Invalid, offset)
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use super::{RawToken, blank_line};
+
+	macro_rules! test_blank_line {
+		($sample:literal, $expected_token:expr, $expected_consumption:literal) => {
+			let (raw_token, consumed_size) = blank_line
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&&$sample[..], 0);
+			assert_eq!(raw_token, $expected_token);
+			assert_eq!(consumed_size, $expected_consumption);
+		};
+	}
+
+	#[test]
+	fn can_lex() {
+		test_blank_line!(b"\t\t", RawToken::BlankLine(0..2), 2);
+		test_blank_line!(b"  \na", RawToken::BlankLine(0..2), 2);
+		test_blank_line!(b" \t\r\n", RawToken::BlankLine(0..2), 2);
+		test_blank_line!(b"\r \n", RawToken::BlankLine(0..2), 2);
+	}
+
+	#[test]
+	fn cannot_lex() {
+		test_blank_line!(b"", RawToken::Empty, 0);
+		test_blank_line!(b"\ta", RawToken::Invalid, 0);
+		test_blank_line!(b" b\n", RawToken::Invalid, 0);
+	}
+}
@@ -4,9 +4,18 @@ use crate::abstracts::{
 	ComparableAbstractSource,
 	AbstractBoundaryCollection
 };
-use crate::helpers::block;
-use crate::special_characters::POUND_SIGN;
+use crate::helpers::{
+	add_line,
+	block_nested,
+	block_with_header,
+	find_line_ending,
+	has_n_matching_bytes,
+	measure_indentation,
+	trim_carriage_return
+};
+use crate::special_characters::{CARRIAGE_RETURN, NEW_LINE, POUND_SIGN};
 use crate::raw_token::{RawToken, RawTokenInfo};
+use crate::readable_boundary::ReadableBoundary;
 
 /// Returns the info of recognized block comment and its probably last seen index in the source.
 ///
@@ -18,6 +27,21 @@ use crate::raw_token::{RawToken, RawTokenInfo};
 /// If the source has no 3 pound signs found at the offset, it will return an invalid raw token
 /// variant with the offset.
 ///
+/// If the opening pound signs are not followed by a new line, the lexer will look for the same
+/// number of closing pound signs in the same line first (like `### content ###`). The content
+/// between them will be the only line of the block comment. Otherwise, the rest of the opening
+/// line is discarded. Use [`meta_block_comment()`] to keep it.
+///
+/// If the source ends before the closing pound signs, it will return an unclosed block comment raw
+/// token variant with the lines collected.
+///
+/// The closing pound signs must be indented with tabs. Use [`block_comment_bounded()`] if they may
+/// be indented with spaces.
+///
+/// If the block comment is closed, the last index is after the new line that ends the closing line,
+/// or the length of the source if there is none. If it is unclosed, the last index is one more
+/// than the length of the source.
+///
 /// ## Examples
 /// ```
 /// use std::ops::Range;
@@ -30,29 +54,506 @@ use crate::raw_token::{RawToken, RawTokenInfo};
 /// assert_eq!(raw_token, RawToken::BlockComment(vec![4..16]));
 /// assert_eq!(last_index, 20);
 ///
+/// let single_line = b"### hello world ###";
+/// let (raw_token, last_index) = block_comment
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&single_line[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::BlockComment(vec![3..16]));
+/// assert_eq!(last_index, 19);
+///
 /// let non_comment = b"hello world";
 /// let (raw_token, last_index) = block_comment
 /// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&non_comment[..], 0, 0);
 /// assert_eq!(raw_token, RawToken::Invalid);
 /// assert_eq!(last_index, 0);
 /// ```
+///
+/// [`meta_block_comment()`]: ./fn.meta_block_comment.html
+/// [`block_comment_bounded()`]: ./fn.block_comment_bounded.html
 pub fn block_comment<T, U, V>(src: T, offset: usize, tab_count: usize) -> RawTokenInfo<U, V>
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
 	U: AbstractBoundary<usize>,
 	V: AbstractBoundaryCollection<usize, U> {
-	let block = block(src, offset, tab_count, POUND_SIGN);
-	if let (RawToken::Block(lines), offset) = block {
-		(RawToken::BlockComment(lines), offset)
+	block_comment_bounded(src, offset, tab_count, 0, None, 0)
+}
+
+/// Returns the info of recognized block comment whose number of lines is within the minimum and the
+/// maximum and its probably last seen index in the source.
+///
+/// It works like [`block_comment()`] but it needs the minimum number of lines as the fourth
+/// argument and the maximum number of lines as the fifth argument. There is no minimum if it is 0
+/// and there is no maximum if it is `None`. The last argument is the number of spaces that make one
+/// level of indentation of the closing pound signs, like the indent width of `LexerConfig`. Only
+/// tabs indent them if it is 0.
+///
+/// ## Notes
+/// If the block comment has more lines than the maximum, it will return a block too long raw token
+/// variant with the lines collected so far and the index where the next line starts. If the closed
+/// block comment has fewer lines than the minimum, it will return a block too short raw token
+/// variant with the lines collected. Single-line block comments have one line.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::block_comment_bounded;
+/// use chearmyp_lexer::RawToken;
+///
+/// let long_comment = b"###\nhello\nworld\n###";
+/// let (raw_token, last_index) = block_comment_bounded
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&long_comment[..], 0, 0, 0, Some(1), 0);
+/// assert_eq!(raw_token, RawToken::BlockTooLong { lines_collected: vec![4..9], limit: 1 });
+/// assert_eq!(last_index, 10);
+///
+/// let empty_comment = b"###\n###";
+/// let (raw_token, last_index) = block_comment_bounded
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&empty_comment[..], 0, 0, 1, None, 0);
+/// assert_eq!(raw_token, RawToken::BlockTooShort { lines: vec![7..7], required: 1 });
+/// assert_eq!(last_index, 7);
+///
+/// let spaced_comment = b"  ###\n  hello\n  ###";
+/// let (raw_token, last_index) = block_comment_bounded
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&spaced_comment[..], 2, 1, 0, None, 2);
+/// assert_eq!(raw_token, RawToken::BlockComment(vec![6..13]));
+/// assert_eq!(last_index, 19);
+/// ```
+///
+/// [`block_comment()`]: ./fn.block_comment.html
+pub fn block_comment_bounded<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	min_lines: usize,
+	max_lines: Option<usize>,
+	indent_width: usize
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	match lex_meta_block_comment(src, offset, tab_count, min_lines, max_lines, indent_width) {
+		(RawToken::MetaBlockComment { lines, .. }, offset) => {
+			(RawToken::BlockComment(lines), offset)
+		},
+		info => info
+	}
+}
+
+/// Returns the info of recognized block comment with the content of its opening line and its
+/// probably last seen index in the source.
+///
+/// It works like [`block_comment()`] but the content after the opening pound signs until the line
+/// ending is kept as the meta of the block comment, like `@param name` in
+/// `### @param name\ndescription\n###`.
+///
+/// ## Notes
+/// If the opening line has nothing after the pound signs, the meta will be an empty boundary. The
+/// single-line block comments (like `### content ###`) have an empty meta too. The unclosed block
+/// comments do not keep their meta.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::meta_block_comment;
+/// use chearmyp_lexer::RawToken;
+///
+/// let with_meta = b"### @param name\ndescription\n###";
+/// let (raw_token, last_index) = meta_block_comment
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&with_meta[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::MetaBlockComment { meta: 3..15, lines: vec![16..27] });
+/// assert_eq!(last_index, 31);
+///
+/// let without_meta = b"###\ndescription\n###";
+/// let (raw_token, last_index) = meta_block_comment
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&without_meta[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::MetaBlockComment { meta: 3..3, lines: vec![4..15] });
+/// assert_eq!(last_index, 19);
+/// ```
+///
+/// [`block_comment()`]: ./fn.block_comment.html
+pub fn meta_block_comment<T, U, V>(src: T, offset: usize, tab_count: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	lex_meta_block_comment(src, offset, tab_count, 0, None, 0)
+}
+
+/// Returns the info of recognized block comment with the text on its opening line as the header
+/// and its probably last seen index in the source.
+///
+/// It works like [`meta_block_comment()`] but the content after the opening pound signs until the
+/// line ending is kept as the header of the block comment, like `type:config` in
+/// `### type:config\ncontent\n###`.
+///
+/// ## Notes
+/// If the header is empty, it will return a block comment raw token variant instead. The
+/// single-line block comments (like `### content ###`) have no header.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::block_comment_with_header;
+/// use chearmyp_lexer::RawToken;
+///
+/// let with_header = b"### type:config\ncontent\n###";
+/// let (raw_token, last_index) = block_comment_with_header
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&with_header[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::BlockCommentWithHeader { header: 3..15, lines: vec![16..23] });
+/// assert_eq!(last_index, 27);
+///
+/// let without_header = b"###\ncontent\n###";
+/// let (raw_token, last_index) = block_comment_with_header
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&without_header[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::BlockComment(vec![4..11]));
+/// assert_eq!(last_index, 15);
+/// ```
+///
+/// [`meta_block_comment()`]: ./fn.meta_block_comment.html
+pub fn block_comment_with_header<T, U, V>(src: T, offset: usize, tab_count: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize> + ReadableBoundary,
+	V: AbstractBoundaryCollection<usize, U> {
+	match meta_block_comment::<T, U, V>(src, offset, tab_count) {
+		(RawToken::MetaBlockComment { meta, lines }, offset) => {
+			let raw_token = if meta.start() == meta.end() {
+				RawToken::BlockComment(lines)
+			} else {
+				RawToken::BlockCommentWithHeader { header: meta, lines }
+			};
+			(raw_token, offset)
+		},
+		info => info
+	}
+}
+
+/// Returns the info of recognized block comment which may contain other block comments and its
+/// probably last seen index in the source.
+///
+/// It works like [`block_comment()`] but the pound signs inside the block comment may open and
+/// close nested block comments. The block comment closes once its closing pound signs are found
+/// while there are no open nested block comments.
+///
+/// ## Notes
+/// The pound signs open a nested block comment if they are indented deeper than the innermost open
+/// block comment, if their number is different, if they are followed by other characters, or if
+/// they are right after an opening line. Otherwise, they close the innermost open block comment.
+/// Therefore, `###\n###\ncontent\n###\n###` is one block comment with a nested block comment. The
+/// lines of the nested block comments are part of the returned lines.
+///
+/// If the source ends while a block comment is still open, the pound signs are not treated as
+/// nested anymore and the block comment is lexed like [`block_comment()`] instead. Unclosed,
+/// mismatched, and malformed block comments are returned in that case. Like in [`block_comment()`],
+/// an empty block comment has one empty line at its last index.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::block_comment_nested;
+/// use chearmyp_lexer::RawToken;
+///
+/// let nested = b"###\n\t###\n\thello\n\t###\n###";
+/// let (raw_token, last_index) = block_comment_nested
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&nested[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::BlockComment(vec![4..8, 9..15, 16..20]));
+/// assert_eq!(last_index, 24);
+///
+/// let unclosed = b"###\nhello\n\t###";
+/// let (raw_token, last_index) = block_comment_nested
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&unclosed[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::UnclosedBlockComment(vec![4..9, 10..14]));
+/// assert_eq!(last_index, 15);
+/// ```
+///
+/// [`block_comment()`]: ./fn.block_comment.html
+pub fn block_comment_nested<T, U, V>(src: T, offset: usize, tab_count: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	block_comment_nested_with_width(src, offset, tab_count, 0)
+}
+
+/// Returns the info of recognized block comment which may contain other block comments and may be
+/// indented with spaces, and its probably last seen index in the source.
+///
+/// It works like [`block_comment_nested()`] but it needs the number of spaces that make one level
+/// of indentation as the fourth argument, like the indent width of `LexerConfig`. The levels of the
+/// closing and the nested pound signs are counted in that unit. Only tabs indent them if it is 0.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::block_comment_nested_with_width;
+/// use chearmyp_lexer::RawToken;
+///
+/// let nested = b"###\n  ###\n  hello\n  ###\n###";
+/// let (raw_token, last_index) = block_comment_nested_with_width
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&nested[..], 0, 0, 2);
+/// assert_eq!(raw_token, RawToken::BlockComment(vec![4..9, 10..17, 18..23]));
+/// assert_eq!(last_index, 27);
+/// ```
+///
+/// [`block_comment_nested()`]: ./fn.block_comment_nested.html
+pub fn block_comment_nested_with_width<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	indent_width: usize
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	if let Some(info) = single_line_block_comment(&src, offset) {
+		return match info {
+			(RawToken::MetaBlockComment { lines, .. }, offset) => {
+				(RawToken::BlockComment(lines), offset)
+			},
+			info => info
+		};
+	}
+
+	match block_nested(src.clone(), offset, tab_count, POUND_SIGN, indent_width) {
+		(RawToken::Block { lines, .. }, offset) => (RawToken::BlockComment(lines), offset),
+		(RawToken::EmptyBlock, offset) => (RawToken::BlockComment(V::new(offset, offset)), offset),
+		(RawToken::UnclosedBlock(lines), offset) => (RawToken::UnclosedBlockComment(lines), offset),
+		info => info
+	}
+}
+
+/// Returns the info of recognized block comment whose opening and closing delimiters are different
+/// and its probably last seen index in the source.
+///
+/// It works like [`block_comment()`] but it needs the opening delimiter as the fourth argument and
+/// the closing delimiter as the fifth argument instead of 3 pound signs, like `/*` and `*/`.
+///
+/// ## Notes
+/// If the closing delimiter is found in the opening line (like `/* content */`), the content
+/// between the delimiters will be the only line of the block comment. Otherwise, the rest of the
+/// opening line is discarded.
+///
+/// If the source ends before the closing delimiter, it will return an unclosed block comment raw
+/// token variant with the lines collected.
+///
+/// The closing delimiter must be indented with tabs. Use [`block_comment_paired_with_width()`] if
+/// it may be indented with spaces.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::block_comment_paired;
+/// use chearmyp_lexer::RawToken;
+///
+/// let terminated = b"\t/*\n\thello world\n\t*/";
+/// let (raw_token, last_index) = block_comment_paired
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&terminated[..], 1, 1, "/*", "*/");
+/// assert_eq!(raw_token, RawToken::BlockComment(vec![4..16]));
+/// assert_eq!(last_index, 20);
+///
+/// let single_line = b"/* hello world */";
+/// let (raw_token, last_index) = block_comment_paired
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&single_line[..], 0, 0, "/*", "*/");
+/// assert_eq!(raw_token, RawToken::BlockComment(vec![2..15]));
+/// assert_eq!(last_index, 17);
+/// ```
+///
+/// [`block_comment()`]: ./fn.block_comment.html
+/// [`block_comment_paired_with_width()`]: ./fn.block_comment_paired_with_width.html
+pub fn block_comment_paired<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	open: &'static str,
+	close: &'static str
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	block_comment_paired_with_width(src, offset, tab_count, open, close, 0)
+}
+
+/// Returns the info of recognized block comment whose opening and closing delimiters are different
+/// and may be indented with spaces, and its probably last seen index in the source.
+///
+/// It works like [`block_comment_paired()`] but it needs the number of spaces that make one level
+/// of indentation as the sixth argument, like the indent width of `LexerConfig`. The level of the
+/// closing delimiter is counted in that unit. Only tabs indent it if it is 0.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::block_comment_paired_with_width;
+/// use chearmyp_lexer::RawToken;
+///
+/// let indented = b"  /*\r\n  hello\r\n  */";
+/// let (raw_token, last_index) = block_comment_paired_with_width
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&indented[..], 2, 1, "/*", "*/", 2);
+/// assert_eq!(raw_token, RawToken::BlockComment(vec![6..13]));
+/// assert_eq!(last_index, 19);
+/// ```
+///
+/// [`block_comment_paired()`]: ./fn.block_comment_paired.html
+pub fn block_comment_paired_with_width<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	open: &'static str,
+	close: &'static str,
+	indent_width: usize
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	if !src.is_same_needle_at(offset, open) {
+		let raw_token = if src.is_empty_at(offset) { RawToken::Empty } else { RawToken::Invalid };
+		return (raw_token, offset);
+	}
+
+	let content_start = offset + open.len();
+	let opening_line_end = find_line_ending(&src, content_start);
+	let closing_index = (content_start..opening_line_end)
+		.find(|&index| src.is_same_needle_at(index, close));
+	if let Some(content_end) = closing_index {
+		let mut last_seen_index = content_end + close.len();
+		if src.is_same_needle_at(last_seen_index, CARRIAGE_RETURN)
+		&& src.is_same_needle_at(last_seen_index + 1, NEW_LINE) {
+			last_seen_index += 1;
+		}
+		if src.is_same_needle_at(last_seen_index, NEW_LINE) { last_seen_index += 1; }
+		return (RawToken::BlockComment(V::new(content_start, content_end)), last_seen_index);
+	}
+
+	let mut lines = None;
+	let mut offset = opening_line_end;
+	offset += if src.is_same_needle_at(offset, NEW_LINE) { 1 } else { 0 };
+
+	let is_closed = loop {
+		let start = offset;
+		let end = find_line_ending(&src, start);
+		if start == end && src.is_empty_at(end) { break false; }
+		let line = src.clone().slice(start, end);
+		let (leading_tab_count, indentation_size)
+			= measure_indentation(&line, usize::MAX, indent_width);
+
+		offset = end;
+
+		if leading_tab_count == tab_count && line.is_same_needle_at(indentation_size, close) {
+			if src.is_same_needle_at(offset, NEW_LINE) { offset += 1; }
+			break true;
+		}
+
+		offset += 1;
+		// The carriage return of CRLF line endings is not part of the content.
+		lines = add_line(lines, start, trim_carriage_return(&src, start, end));
+	};
+
+	let lines = lines.unwrap_or_else(|| V::new(offset, offset));
+	let raw_token = if is_closed {
+		RawToken::BlockComment(lines)
 	} else {
-		block
+		RawToken::UnclosedBlockComment(lines)
+	};
+	(raw_token, offset)
+}
+
+fn lex_meta_block_comment<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	min_lines: usize,
+	max_lines: Option<usize>,
+	indent_width: usize
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	if let Some(info) = single_line_block_comment(&src, offset) {
+		return info;
+	}
+
+	let info = block_with_header(
+		src,
+		offset,
+		tab_count,
+		POUND_SIGN,
+		min_lines,
+		max_lines,
+		indent_width
+	);
+	match info {
+		((RawToken::Block { lines, .. }, offset), meta) => {
+			(RawToken::MetaBlockComment { meta, lines }, offset)
+		},
+		((RawToken::EmptyBlock, offset), meta) => {
+			(RawToken::MetaBlockComment { meta, lines: V::new(offset, offset) }, offset)
+		},
+		((RawToken::UnclosedBlock(lines), offset), _) => {
+			(RawToken::UnclosedBlockComment(lines), offset)
+		},
+		(info, _) => info
 	}
 }
 
+fn single_line_block_comment<T, U, V>(src: &T, offset: usize) -> Option<RawTokenInfo<U, V>>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	if !has_n_matching_bytes(src, offset, 3, POUND_SIGN) { return None; }
+
+	let delimiter_size = count_pound_signs(src, offset);
+	let content_start = offset + delimiter_size;
+	let mut content_end = content_start;
+
+	loop {
+		if src.is_same_needle_at(content_end, NEW_LINE) || src.is_empty_at(content_end) {
+			return None;
+		}
+
+		// Runs of pound signs with a different size are part of the content.
+		match count_pound_signs(src, content_end) {
+			0 => content_end += 1,
+			pound_sign_count if pound_sign_count == delimiter_size => break,
+			pound_sign_count => content_end += pound_sign_count
+		}
+	}
+
+	let mut last_seen_index = content_end + delimiter_size;
+	if src.is_same_needle_at(last_seen_index, NEW_LINE) { last_seen_index += 1; }
+
+	let meta = U::new(content_start, content_start);
+	let lines = V::new(content_start, content_end);
+	Some((RawToken::MetaBlockComment { meta, lines }, last_seen_index))
+}
+
+fn count_pound_signs<T>(src: &T, offset: usize) -> usize
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let mut count = 0;
+	while src.is_same_needle_at(offset + count, POUND_SIGN) { count += 1; }
+	count
+}
+
 #[cfg(test)]
 mod t {
 	use crate::native::{Range, Vec};
-	use super::{RawToken, block_comment};
+	use super::{
+		RawToken,
+		block_comment,
+		block_comment_bounded,
+		block_comment_nested,
+		block_comment_paired,
+		block_comment_paired_with_width,
+		block_comment_with_header,
+		meta_block_comment
+	};
 
 	macro_rules! BlockComment {
 		($($raw_token:expr),*) => {
@@ -91,6 +592,46 @@ mod t {
 			can_lex_comment_with_empty_line_and_indented_line
 			with sample b"###\n\t\thello world!\n\nhi universe\n\t###" and tab count 1
 			expecting [4..18, 19..19, 20..31] with consumed size of 36 bytes.
+
+			can_lex_comment_with_longer_delimiters
+			with sample b"####\n###\n####" and tab count 0
+			expecting [5..8] with consumed size of 13 bytes.
+
+			can_lex_single_line_comment
+			with sample b"### hello world ###" and tab count 0
+			expecting [3..16] with consumed size of 19 bytes.
+
+			can_lex_single_line_comment_with_new_line
+			with sample b"###hi###\nhello" and tab count 0
+			expecting [3..5] with consumed size of 9 bytes.
+
+			can_lex_single_line_comment_with_longer_delimiters
+			with sample b"####hi####" and tab count 0
+			expecting [4..6] with consumed size of 10 bytes.
+
+			can_lex_single_line_comment_with_other_pound_signs
+			with sample b"#### a ### b ####" and tab count 0
+			expecting [4..13] with consumed size of 17 bytes.
+
+			can_lex_empty_comment_with_discarded_meta
+			with sample b"### hi\n###" and tab count 0
+			expecting [10..10] with consumed size of 10 bytes.
+
+			can_lex_comment_with_discarded_meta
+			with sample b"### hi\nhello\n###" and tab count 0
+			expecting [7..12] with consumed size of 16 bytes.
+
+			can_lex_comment_without_text_of_opening_line
+			with sample b"###abc\nx\n###" and tab count 0
+			expecting [7..8] with consumed size of 12 bytes.
+
+			can_lex_comment_with_crlf_line_endings
+			with sample b"###\r\nhello\r\n###" and tab count 0
+			expecting [5..10] with consumed size of 15 bytes.
+
+			can_lex_comment_with_crlf_line_endings_and_discarded_meta
+			with sample b"### hi\r\nhello\r\n###" and tab count 0
+			expecting [8..13] with consumed size of 18 bytes.
 		]
 
 		invalid cases: [
@@ -99,4 +640,261 @@ mod t {
 			cannot_lex_double_pound_sign with sample b"##" expecting Invalid.
 		]
 	}
+
+	#[test]
+	fn cannot_lex_unclosed_comment() {
+		let (raw_token, last_index) = block_comment
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"###\nhello\nworld"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::UnclosedBlockComment(vec![4..9, 10..15]));
+		assert_eq!(last_index, 16);
+	}
+
+	#[test]
+	fn cannot_lex_single_line_comment_with_longer_closing_pound_signs() {
+		let (raw_token, last_index) = block_comment
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"###hi####\nb\nc"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::UnclosedBlockComment(vec![10..11, 12..13]));
+		assert_eq!(last_index, 14);
+	}
+
+	#[test]
+	fn can_lex_comment_after_other_content() {
+		let source = b"abcdefghijklmnopqrs\n###\nhello world\n###";
+		let (raw_token, last_index) = block_comment
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 20, 0);
+		assert_eq!(raw_token, BlockComment!(24..35));
+		assert_eq!(last_index, 39);
+	}
+
+	#[test]
+	fn can_lex_comment_with_header() {
+		let (raw_token, last_index) = block_comment_with_header
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"### e f\ng\nh\n###"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::BlockCommentWithHeader {
+			header: 3..7,
+			lines: vec![8..9, 10..11]
+		});
+		assert_eq!(last_index, 15);
+	}
+
+	#[test]
+	fn can_lex_comment_without_header() {
+		let (raw_token, last_index) = block_comment_with_header
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"###\ni\nj\n###"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::BlockComment(vec![4..5, 6..7]));
+		assert_eq!(last_index, 11);
+
+		let (raw_token, last_index) = block_comment_with_header
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"### k l ###"[..], 0, 0);
+		assert_eq!(raw_token, BlockComment!(3..8));
+		assert_eq!(last_index, 11);
+	}
+
+	#[test]
+	fn cannot_lex_comment_beyond_line_limit() {
+		let source = b"### a\nb\nc\nd\n###";
+		let (raw_token, last_index) = block_comment_bounded
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, 0, Some(2), 0);
+		let lines_collected = vec![6..7, 8..9];
+		assert_eq!(raw_token, RawToken::BlockTooLong { lines_collected, limit: 2 });
+		assert_eq!(last_index, 10);
+	}
+
+	#[test]
+	fn can_lex_comment_within_line_limit() {
+		let (raw_token, last_index) = block_comment_bounded
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"###\nb\nc\n###"[..], 0, 0, 0, Some(2), 0);
+		assert_eq!(raw_token, RawToken::BlockComment(vec![4..5, 6..7]));
+		assert_eq!(last_index, 11);
+	}
+
+	#[test]
+	fn can_lex_comment_with_paired_delimiters() {
+		let (raw_token, last_index) = block_comment_paired
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"/* a\nb\nc\n*/\nd"[..], 0, 0, "/*", "*/");
+		assert_eq!(raw_token, RawToken::BlockComment(vec![5..6, 7..8]));
+		assert_eq!(last_index, 12);
+
+		let (raw_token, last_index) = block_comment_paired
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"/**/"[..], 0, 0, "/*", "*/");
+		assert_eq!(raw_token, BlockComment!(2..2));
+		assert_eq!(last_index, 4);
+	}
+
+	#[test]
+	fn can_lex_comment_with_paired_delimiters_and_crlf() {
+		let source = b"/*\r\na\r\n\r\n*/\r\nb";
+		let (raw_token, last_index) = block_comment_paired
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "/*", "*/");
+		assert_eq!(raw_token, RawToken::BlockComment(vec![4..5, 7..7]));
+		assert_eq!(last_index, 13);
+
+		let (raw_token, last_index) = block_comment_paired
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"/* c */\r\nd"[..], 0, 0, "/*", "*/");
+		assert_eq!(raw_token, RawToken::BlockComment(vec![2..5]));
+		assert_eq!(last_index, 9);
+	}
+
+	#[test]
+	fn can_lex_comment_with_paired_delimiters_indented_with_spaces() {
+		let source = b"/*\n  e\n  */\n*/";
+		let (raw_token, last_index) = block_comment_paired_with_width
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 0, 0, "/*", "*/", 2);
+		assert_eq!(raw_token, RawToken::BlockComment(vec![3..6, 7..11]));
+		assert_eq!(last_index, 14);
+
+		let source = b"\t/*\n  f\n\t  */";
+		let (raw_token, last_index) = block_comment_paired_with_width
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 1, 1, "/*", "*/", 2);
+		assert_eq!(raw_token, RawToken::UnclosedBlockComment(vec![4..7, 8..13]));
+		assert_eq!(last_index, 14);
+	}
+
+	#[test]
+	fn cannot_lex_comment_without_paired_delimiters() {
+		let (raw_token, last_index) = block_comment_paired
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"/\n*/"[..], 0, 0, "/*", "*/");
+		assert_eq!(raw_token, RawToken::Invalid);
+		assert_eq!(last_index, 0);
+
+		let (raw_token, last_index) = block_comment_paired
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"/*\na\n\t*/"[..], 0, 0, "/*", "*/");
+		assert_eq!(raw_token, RawToken::UnclosedBlockComment(vec![3..4, 5..8]));
+		assert_eq!(last_index, 9);
+	}
+
+	mod nested {
+		use crate::native::{Range, Vec};
+		use super::{RawToken, block_comment_nested};
+
+		macro_rules! BlockComment {
+			($($raw_token:expr),*) => {
+				create_block!(BlockComment $($raw_token),*)
+			};
+		}
+
+		test_block_cases!{
+			lexer: block_comment_nested
+			raw_token creator: BlockComment
+
+			valid cases: [
+				can_lex_empty_comment with sample b"###\n###" and tab count 0
+				expecting [7..7] with consumed size of 7 bytes.
+
+				can_lex_empty_comment_before_other_line
+				with sample b"###\n###\nx" and tab count 0
+				expecting [8..8] with consumed size of 8 bytes.
+
+				can_lex_comment_without_nested_comment
+				with sample b"###\n\thi\n\t###" and tab count 1
+				expecting [4..7] with consumed size of 12 bytes.
+
+				can_lex_comment_with_nested_comment
+				with sample b"###\n\t###\n\thi\n\t###\n###\n" and tab count 0
+				expecting [4..8, 9..12, 13..17] with consumed size of 22 bytes.
+
+				can_lex_comment_with_deeply_nested_comments
+				with sample b"###\n\t###\n\t\t###\n\t\t###\n\t###\n###" and tab count 0
+				expecting [4..8, 9..14, 15..20, 21..25] with consumed size of 29 bytes.
+
+				can_lex_comment_with_nested_comment_closed_at_tab_count
+				with sample b"###\n\t###\n###\n###" and tab count 0
+				expecting [4..8, 9..12] with consumed size of 16 bytes.
+
+				can_lex_comment_with_nested_comment_at_same_level
+				with sample b"###\n###\na\n###\n###\nb" and tab count 0
+				expecting [4..7, 8..9, 10..13] with consumed size of 18 bytes.
+
+				can_lex_comment_with_nested_comment_after_content
+				with sample b"###\na\n### b\nc\n###\n###" and tab count 0
+				expecting [4..5, 6..11, 12..13, 14..17] with consumed size of 21 bytes.
+
+				can_lex_wide_comment_with_nested_comment
+				with sample b"####\n###\na\n###\n####" and tab count 0
+				expecting [5..8, 9..10, 11..14] with consumed size of 19 bytes.
+
+				can_lex_comment_as_unnested_if_nested_comment_is_unclosed
+				with sample b"###\na\n\t###\nb\n###" and tab count 0
+				expecting [4..5, 6..10, 11..12] with consumed size of 16 bytes.
+			]
+
+			invalid cases: [
+				cannot_lex_empty_string with sample b"" expecting Empty.
+				cannot_lex_single_pound_sign with sample b"#" expecting Invalid.
+				cannot_lex_double_pound_sign with sample b"##" expecting Invalid.
+			]
+		}
+
+		#[test]
+		fn cannot_lex_unclosed_comment() {
+			let (raw_token, last_index) = block_comment_nested
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"###\n\t###\na"[..], 0, 0);
+			assert_eq!(raw_token, RawToken::UnclosedBlockComment(vec![4..8, 9..10]));
+			assert_eq!(last_index, 11);
+		}
+
+		#[test]
+		fn cannot_lex_comment_with_malformed_or_mismatched_closing_line() {
+			let (raw_token, last_index) = block_comment_nested
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"###\na\nb\n### c"[..], 0, 0);
+			assert_eq!(raw_token, RawToken::MalformedBlock(vec![4..5, 6..7]));
+			assert_eq!(last_index, 13);
+
+			let (raw_token, last_index) = block_comment_nested
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"\t###\n\ta\n\tb\n###"[..], 1, 1);
+			assert_eq!(raw_token, RawToken::MismatchedBlockClose {
+				actual_depth: 0,
+				expected_depth: 1,
+				lines: vec![5..7, 8..10]
+			});
+			assert_eq!(last_index, 14);
+		}
+	}
+
+	mod meta {
+		use crate::native::{Range, Vec};
+		use super::{RawToken, meta_block_comment};
+
+		macro_rules! test_meta_block_comment {
+			(
+				$sample:literal with tab count $tab_count:literal,
+				$expected_token:expr,
+				$expected_consumption:literal
+			) => {
+				let (raw_token, consumed_size) = meta_block_comment
+					::<&[u8], Range<usize>, Vec<Range<usize>>>(&&$sample[..], 0, $tab_count);
+				assert_eq!(raw_token, $expected_token);
+				assert_eq!(consumed_size, $expected_consumption);
+			};
+		}
+
+		#[test]
+		fn can_lex_with_meta() {
+			test_meta_block_comment!(
+				b"### @param a\nb\nc\n###" with tab count 0,
+				RawToken::MetaBlockComment { meta: 3..12, lines: vec![13..14, 15..16] },
+				20
+			);
+			test_meta_block_comment!(
+				b"###@return\n\td\n\te\n\t###" with tab count 1,
+				RawToken::MetaBlockComment { meta: 3..10, lines: vec![11..13, 14..16] },
+				21
+			);
+		}
+
+		#[test]
+		fn can_lex_without_meta() {
+			test_meta_block_comment!(
+				b"###\nf\ng\n###" with tab count 0,
+				RawToken::MetaBlockComment { meta: 3..3, lines: vec![4..5, 6..7] },
+				11
+			);
+		}
+
+		#[test]
+		fn cannot_lex() {
+			test_meta_block_comment!(b"" with tab count 0, RawToken::Empty, 0);
+			test_meta_block_comment!(b"##" with tab count 0, RawToken::Invalid, 0);
+		}
+	}
 }
@@ -4,7 +4,7 @@ use crate::abstracts::{
 	ComparableAbstractSource,
 	AbstractBoundaryCollection
 };
-use crate::helpers::block;
+use crate::helpers::{block, block_n, block_deindented, block_with_header};
 use crate::special_characters::EQUAL;
 use crate::raw_token::{RawToken, RawTokenInfo};
 
@@ -16,7 +16,15 @@ use crate::raw_token::{RawToken, RawTokenInfo};
 ///
 /// ## Notes
 /// If the source has no 3 equal signs found at the offset, it will return an invalid raw token
-/// variant with the offset.
+/// variant with the offset. If the source ends before the closing equal signs, it will return an
+/// unclosed block othertongue raw token variant with the lines collected.
+///
+/// The closing equal signs must be indented with tabs. Use [`block_othertongue_bounded()`] if they
+/// may be indented with spaces.
+///
+/// If the block othertongue is closed, the last index is after the new line that ends the closing
+/// line, or the length of the source if there is none. If it is unclosed, the last index is one
+/// more than the length of the source.
 ///
 /// ## Examples
 /// ```
@@ -36,23 +44,253 @@ use crate::raw_token::{RawToken, RawTokenInfo};
 /// assert_eq!(raw_token, RawToken::Invalid);
 /// assert_eq!(last_index, 0);
 /// ```
+///
+/// [`block_othertongue_bounded()`]: ./fn.block_othertongue_bounded.html
 pub fn block_othertongue<T, U, V>(src: T, offset: usize, tab_count: usize) -> RawTokenInfo<U, V>
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
 	U: AbstractBoundary<usize>,
 	V: AbstractBoundaryCollection<usize, U> {
-	let block = block(src, offset, tab_count, EQUAL);
-	if let (RawToken::Block(lines), offset) = block {
-		(RawToken::BlockOthertongue(lines), offset)
-	} else {
-		block
+	block_othertongue_bounded(src, offset, tab_count, 0, None, 0)
+}
+
+/// Returns the info of recognized block othertongue whose number of lines is within the minimum
+/// and the maximum and its probably last seen index in the source.
+///
+/// It works like [`block_othertongue()`] but it needs the minimum number of lines as the fourth
+/// argument and the maximum number of lines as the fifth argument. There is no minimum if it is 0
+/// and there is no maximum if it is `None`. The last argument is the number of spaces that make one
+/// level of indentation of the closing equal signs, like the indent width of `LexerConfig`. Only
+/// tabs indent them if it is 0.
+///
+/// ## Notes
+/// If the block othertongue has more lines than the maximum, it will return a block too long raw
+/// token variant with the lines collected so far and the index where the next line starts. If the
+/// closed block othertongue has fewer lines than the minimum, it will return a block too short raw
+/// token variant with the lines collected.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::block_othertongue_bounded;
+/// use chearmyp_lexer::RawToken;
+///
+/// let long_othertongue = b"===\nhello\nworld\n===";
+/// let (raw_token, last_index) = block_othertongue_bounded
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&long_othertongue[..], 0, 0, 0, Some(1), 0);
+/// assert_eq!(raw_token, RawToken::BlockTooLong { lines_collected: vec![4..9], limit: 1 });
+/// assert_eq!(last_index, 10);
+///
+/// let empty_othertongue = b"===\n===";
+/// let (raw_token, last_index) = block_othertongue_bounded
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&empty_othertongue[..], 0, 0, 1, None, 0);
+/// assert_eq!(raw_token, RawToken::BlockTooShort { lines: vec![7..7], required: 1 });
+/// assert_eq!(last_index, 7);
+///
+/// let spaced_othertongue = b"  ===\n  hello\n  ===";
+/// let (raw_token, last_index) = block_othertongue_bounded
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&spaced_othertongue[..], 2, 1, 0, None, 2);
+/// assert_eq!(raw_token, RawToken::BlockOthertongue(vec![6..13]));
+/// assert_eq!(last_index, 19);
+/// ```
+///
+/// [`block_othertongue()`]: ./fn.block_othertongue.html
+pub fn block_othertongue_bounded<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	min_lines: usize,
+	max_lines: Option<usize>,
+	indent_width: usize
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let info = block(src, offset, tab_count, EQUAL, min_lines, max_lines, indent_width);
+	into_block_othertongue(info)
+}
+
+/// Returns the info of recognized block othertongue whose delimiters have an exact number of equal
+/// signs and its probably last seen index in the source.
+///
+/// It works like [`block_othertongue()`] but it needs the number of equal signs as the fourth
+/// argument. Both the opening and the closing lines must have that number of equal signs. It is
+/// useful to avoid `===` which is also the setext heading underline of Markdown.
+///
+/// ## Notes
+/// If the opening line has a different number of equal signs, it will return an invalid raw token
+/// variant with the offset.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::block_othertongue_n;
+/// use chearmyp_lexer::RawToken;
+///
+/// let four_signs = b"====\nHeading\n===\n====";
+/// let (raw_token, last_index) = block_othertongue_n
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&four_signs[..], 0, 0, 4);
+/// assert_eq!(raw_token, RawToken::BlockOthertongue(vec![5..12, 13..16]));
+/// assert_eq!(last_index, 21);
+///
+/// let three_signs = b"===\nhello\n===";
+/// let (raw_token, last_index) = block_othertongue_n
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&three_signs[..], 0, 0, 4);
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 0);
+/// ```
+///
+/// [`block_othertongue()`]: ./fn.block_othertongue.html
+pub fn block_othertongue_n<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	delimiter_count: usize
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	into_block_othertongue(block_n(src, offset, tab_count, EQUAL, delimiter_count))
+}
+
+/// Returns the info of recognized block othertongue whose lines do not include the indentation and
+/// its probably last seen index in the source.
+///
+/// It works like [`block_othertongue()`] but the start of each line is moved after the tabs
+/// required by the tab count. Lines with fewer tabs than the tab count keep their actual start.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::block_othertongue_stripped;
+/// use chearmyp_lexer::RawToken;
+///
+/// let indented = b"\t===\n\tfn main() {}\n\t===";
+/// let (raw_token, last_index) = block_othertongue_stripped
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&indented[..], 1, 1);
+/// assert_eq!(raw_token, RawToken::BlockOthertongue(vec![6..18]));
+/// assert_eq!(last_index, 23);
+/// ```
+///
+/// [`block_othertongue()`]: ./fn.block_othertongue.html
+pub fn block_othertongue_stripped<T, U, V>(src: T, offset: usize, tab_count: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	into_block_othertongue(block_deindented(src, offset, tab_count, EQUAL))
+}
+
+/// Returns the info of recognized block othertongue with the text on its opening line as the tag
+/// and its probably last seen index in the source.
+///
+/// It works like [`block_othertongue()`] but the content after the opening equal signs until the
+/// line ending is kept as the tag of the block othertongue, like `rust` in
+/// `===rust\nfn main() {}\n===`. Renderers may use the tag to choose a syntax highlighter.
+///
+/// ## Notes
+/// The tag is an empty boundary if the opening equal signs are followed by a new line. The block
+/// othertongue is still returned as a tagged block othertongue raw token variant.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::tagged_block_othertongue;
+/// use chearmyp_lexer::RawToken;
+///
+/// let tagged = b"===rust\nfn main() {}\n===";
+/// let (raw_token, last_index) = tagged_block_othertongue
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&tagged[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::TaggedBlockOthertongue { tag: 3..7, lines: vec![8..20] });
+/// assert_eq!(last_index, 24);
+///
+/// let untagged = b"===\nhello\n===";
+/// let (raw_token, last_index) = tagged_block_othertongue
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&untagged[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::TaggedBlockOthertongue { tag: 3..3, lines: vec![4..9] });
+/// assert_eq!(last_index, 13);
+/// ```
+///
+/// [`block_othertongue()`]: ./fn.block_othertongue.html
+pub fn tagged_block_othertongue<T, U, V>(src: T, offset: usize, tab_count: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	tagged_block_othertongue_bounded(src, offset, tab_count, 0, None, 0)
+}
+
+/// Returns the info of recognized tagged block othertongue whose number of lines is within the
+/// minimum and the maximum and its probably last seen index in the source.
+///
+/// It works like [`tagged_block_othertongue()`] but it needs the minimum and the maximum numbers of
+/// lines as the fourth and fifth arguments, and the indent width as the last argument like
+/// [`block_othertongue_bounded()`].
+///
+/// [`tagged_block_othertongue()`]: ./fn.tagged_block_othertongue.html
+/// [`block_othertongue_bounded()`]: ./fn.block_othertongue_bounded.html
+pub fn tagged_block_othertongue_bounded<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	min_lines: usize,
+	max_lines: Option<usize>,
+	indent_width: usize
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let ((raw_token, offset), tag) = block_with_header(
+		src,
+		offset,
+		tab_count,
+		EQUAL,
+		min_lines,
+		max_lines,
+		indent_width
+	);
+	match raw_token {
+		RawToken::Block { lines, .. } => (RawToken::TaggedBlockOthertongue { tag, lines }, offset),
+		RawToken::EmptyBlock => {
+			let lines = V::new(offset, offset);
+			(RawToken::TaggedBlockOthertongue { tag, lines }, offset)
+		},
+		raw_token => into_block_othertongue((raw_token, offset))
+	}
+}
+
+fn into_block_othertongue<U, V>(info: RawTokenInfo<U, V>) -> RawTokenInfo<U, V>
+where
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	match info {
+		(RawToken::Block { lines, .. }, offset) => (RawToken::BlockOthertongue(lines), offset),
+		(RawToken::EmptyBlock, offset) => {
+			(RawToken::BlockOthertongue(V::new(offset, offset)), offset)
+		},
+		(RawToken::UnclosedBlock(lines), offset) => {
+			(RawToken::UnclosedBlockOthertongue(lines), offset)
+		},
+		block => block
 	}
 }
 
 #[cfg(test)]
 mod t {
 	use crate::native::{Range, Vec};
-	use super::{RawToken, block_othertongue};
+	use super::{
+		RawToken,
+		block_othertongue,
+		block_othertongue_bounded,
+		block_othertongue_n,
+		block_othertongue_stripped,
+		tagged_block_othertongue
+	};
 
 	macro_rules! BlockOthertongue {
 		($($raw_token:expr),*) => {
@@ -73,6 +311,14 @@ mod t {
 			with sample b"===\na\n===" and tab count 0
 			expecting [4..5] with consumed size of 9 bytes.
 
+			can_lex_othertongue_with_longer_delimiters
+			with sample b"=====\n===\n=====" and tab count 0
+			expecting [6..9] with consumed size of 15 bytes.
+
+			can_lex_othertongue_with_equal_signs
+			with sample b"===\na = b\n==\n====\n===" and tab count 0
+			expecting [4..9, 10..12, 13..17] with consumed size of 21 bytes.
+
 			can_lex_othertongue_with_indented_and_single_line
 			with sample b"===\n\tbc\n\t===" and tab count 1
 			expecting [4..7] with consumed size of 12 bytes.
@@ -100,4 +346,60 @@ mod t {
 			cannot_lex_on_double_character_line with sample b"==" expecting Invalid.
 		]
 	}
+
+	#[test]
+	fn cannot_lex_unclosed_othertongue() {
+		let (raw_token, last_index) = block_othertongue
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"===\nhello\nworld"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::UnclosedBlockOthertongue(vec![4..9, 10..15]));
+		assert_eq!(last_index, 16);
+	}
+
+	#[test]
+	fn can_lex_othertongue_with_exact_delimiters() {
+		let (raw_token, last_index) = block_othertongue_n
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"\t====\n\ta\n\tb\n\t====\n"[..], 1, 1, 4);
+		assert_eq!(raw_token, RawToken::BlockOthertongue(vec![6..8, 9..11]));
+		assert_eq!(last_index, 18);
+
+		let (raw_token, last_index) = block_othertongue_n
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"=====\nb\n====="[..], 0, 0, 4);
+		assert_eq!(raw_token, RawToken::Invalid);
+		assert_eq!(last_index, 0);
+	}
+
+	#[test]
+	fn cannot_lex_othertongue_beyond_line_limit() {
+		let (raw_token, last_index) = block_othertongue_bounded
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"===\na\nb\nc\n==="[..], 0, 0, 0, Some(2), 0);
+		let lines_collected = vec![4..5, 6..7];
+		assert_eq!(raw_token, RawToken::BlockTooLong { lines_collected, limit: 2 });
+		assert_eq!(last_index, 8);
+	}
+
+	#[test]
+	fn can_lex_stripped_othertongue() {
+		let source = b"\t\t===\n\t\ta\n\t\t\tb\n\tc\n\t\t===";
+		let (raw_token, last_index) = block_othertongue_stripped
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&source[..], 2, 2);
+		assert_eq!(raw_token, RawToken::BlockOthertongue(vec![8..9, 12..14, 15..17]));
+		assert_eq!(last_index, 23);
+	}
+
+	#[test]
+	fn can_lex_tagged_othertongue_with_indented_lines() {
+		let (raw_token, last_index) = tagged_block_othertongue
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"\t=== md\r\n\ti\n\tj\n\t==="[..], 1, 1);
+		let lines = vec![9..11, 12..14];
+		assert_eq!(raw_token, RawToken::TaggedBlockOthertongue { tag: 4..7, lines });
+		assert_eq!(last_index, 19);
+	}
+
+	#[test]
+	fn cannot_lex_unclosed_tagged_othertongue() {
+		let (raw_token, last_index) = tagged_block_othertongue
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"===js\nj\nk"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::UnclosedBlockOthertongue(vec![6..7, 8..9]));
+		assert_eq!(last_index, 10);
+	}
 }
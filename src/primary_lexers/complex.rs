@@ -1,7 +1,8 @@
 use crate::abstracts::{AbstractSource, ComparableAbstractSource, AbstractBoundary};
 use crate::delimeter::Delimeter;
+use crate::primary_lexers::line_othertongue::determine_othertongue_prefix;
 use crate::raw_token::{RawToken, RawTokenInfo};
-use crate::special_characters::{NEW_LINE, TAB};
+use crate::special_characters::{NEW_LINE, SPACE, TAB};
 
 /// Returns the info of recognized complex and the last index that has been checked from the source.
 ///
@@ -14,6 +15,12 @@ use crate::special_characters::{NEW_LINE, TAB};
 /// This lexer does not differentiate simplexes and attachers. Use [`simplex()`] and [`attacher()`]
 /// lexers first.
 ///
+/// The complex ends before a space if it is followed by an inlined othertongue (like `a = b`).
+///
+/// The last index is the index of the tab, the new line, or the space before an inlined
+/// othertongue that ends the complex, or the length of the source if there is none. They are not
+/// consumed.
+///
 /// ## Examples
 /// ```
 /// use std::ops::Range;
@@ -38,6 +45,12 @@ use crate::special_characters::{NEW_LINE, TAB};
 /// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&simplex[..], 0, 0);
 /// assert_eq!(raw_token, RawToken::Complex(0..12));
 /// assert_eq!(last_index, 12);
+///
+/// let inlined_othertongue = b"hello = world";
+/// let (raw_token, last_index) = complex
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&inlined_othertongue[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Complex(0..5));
+/// assert_eq!(last_index, 5);
 /// ```
 ///
 /// [`simplex()`]: ./fn.simplex.html
@@ -67,11 +80,17 @@ where
 fn determine_ending<T>(src: &T, offset: usize) -> Delimeter
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> {
-	if src.is_same_needle_at(offset, NEW_LINE) || src.is_same_needle_at(offset, TAB) {
+	let is_before_othertongue = src.is_same_needle_at(offset, SPACE)
+		&& matches!(determine_othertongue_prefix(src, offset + 1), Delimeter::Pad);
+	if src.is_same_needle_at(offset, NEW_LINE)
+	|| src.is_same_needle_at(offset, TAB)
+	|| is_before_othertongue {
 		Delimeter::Pad
 	} else if src.is_empty_at(offset) {
 		Delimeter::Limit
 	} else {
+		// Vertical lines are part of the complex. Only a vertical line at the end of the line marks
+		// a simplex, which is already recognized by `simplex()` before this lexer is tried.
 		Delimeter::Incorrect
 	}
 }
@@ -101,6 +120,13 @@ mod t {
 		test_complex!(b"a", Complex!(0..1), 1);
 		test_complex!(b"bc	", Complex!(0..2), 2);
 		test_complex!(b"d\n", Complex!(0..1), 1);
-		test_complex!(b"e = f\n", Complex!(0..5), 5);
+		test_complex!(b"e = f\n", Complex!(0..1), 1);
+		test_complex!(b"g =h", Complex!(0..4), 4);
+	}
+
+	#[test]
+	fn can_lex_with_vertical_line() {
+		test_complex!(b"a|b", Complex!(0..3), 3);
+		test_complex!(b"c|\n", Complex!(0..2), 2);
 	}
 }
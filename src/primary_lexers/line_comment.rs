@@ -1,4 +1,5 @@
 use crate::abstracts::{AbstractSource, AbstractBoundary, ComparableAbstractSource};
+use crate::ascii_needle::ascii_needle;
 use crate::helpers::find_line_ending;
 use crate::special_characters::POUND_SIGN;
 use crate::raw_token::{RawToken, RawTokenInfo};
@@ -14,6 +15,9 @@ use crate::raw_token::{RawToken, RawTokenInfo};
 /// variant. If the source has no pound sign found at the offset, it will return an invalid raw
 /// token variant with the offset.
 ///
+/// The last index is the index of the new line after the comment, or the length of the source if
+/// there is none. The new line is not consumed but the tabs before it are part of the comment.
+///
 /// ## Examples
 /// ```
 /// use std::ops::Range;
@@ -38,12 +42,113 @@ use crate::raw_token::{RawToken, RawTokenInfo};
 /// assert_eq!(raw_token, RawToken::Invalid);
 /// assert_eq!(last_index, 0);
 /// ```
-pub fn line_comment<T, U, V>(src: T, mut i: usize) -> RawTokenInfo<U, V>
+pub fn line_comment<T, U, V>(src: T, i: usize) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	line_comment_with_prefix(src, i, POUND_SIGN.as_bytes()[0])
+}
+
+/// Returns the info of recognized line comment including its pound sign and its last index
+/// occupied in the source.
+///
+/// It works like [`line_comment()`] but the range of the raw token starts at the pound sign.
+/// Therefore, the original line can be reconstructed from the range. The content of the comment
+/// starts one byte after the start of the range.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::line_comment_full;
+/// use chearmyp_lexer::RawToken;
+///
+/// let comment = b"\t# hello world\n";
+/// let (raw_token, last_index) = line_comment_full
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&comment[..], 1);
+/// assert_eq!(raw_token, RawToken::LineComment(1..14));
+/// assert_eq!(last_index, 14);
+/// ```
+///
+/// [`line_comment()`]: ./fn.line_comment.html
+pub fn line_comment_full<T, U, V>(src: T, i: usize) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	match line_comment::<T, U, V>(src, i) {
+		(RawToken::LineComment(_), end) => (RawToken::LineComment(U::new(i, end)), end),
+		info => info
+	}
+}
+
+/// Returns the info of recognized line comment that starts with the prefix and its last index
+/// occupied in the source.
+///
+/// It works like [`line_comment()`] but it needs the byte to look for at the offset as the third
+/// argument (known as the prefix) instead of the pound sign.
+///
+/// ## Notes
+/// If the prefix is not an ASCII character, it will return an invalid raw token variant with the
+/// offset. Use [`line_comment_with_prefix2()`] for prefixes with more than one character.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::line_comment_with_prefix;
+/// use chearmyp_lexer::RawToken;
+///
+/// let comment = b"; hello world";
+/// let (raw_token, last_index) = line_comment_with_prefix
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&comment[..], 0, b';');
+/// assert_eq!(raw_token, RawToken::LineComment(1..13));
+/// assert_eq!(last_index, 13);
+/// ```
+///
+/// [`line_comment()`]: ./fn.line_comment.html
+/// [`line_comment_with_prefix2()`]: ./fn.line_comment_with_prefix2.html
+pub fn line_comment_with_prefix<T, U, V>(src: T, i: usize, prefix: u8) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	match ascii_needle(prefix) {
+		Some(prefix) => line_comment_with_prefix2(src, i, prefix),
+		None if src.is_empty_at(i) => (RawToken::Empty, i),
+		None => (RawToken::Invalid, i)
+	}
+}
+
+/// Returns the info of recognized line comment that starts with the prefix of any length and its
+/// last index occupied in the source.
+///
+/// It works like [`line_comment_with_prefix()`] but the prefix may have more than one character,
+/// like `//` or `--`.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::line_comment_with_prefix2;
+/// use chearmyp_lexer::RawToken;
+///
+/// let comment = b"// hello world\n";
+/// let (raw_token, last_index) = line_comment_with_prefix2
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&comment[..], 0, "//");
+/// assert_eq!(raw_token, RawToken::LineComment(2..14));
+/// assert_eq!(last_index, 14);
+///
+/// let non_comment = b"/ hello world";
+/// let (raw_token, last_index) = line_comment_with_prefix2
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&non_comment[..], 0, "//");
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 0);
+/// ```
+///
+/// [`line_comment_with_prefix()`]: ./fn.line_comment_with_prefix.html
+pub fn line_comment_with_prefix2<T, U, V>(src: T, mut i: usize, prefix: &'static str)
+-> RawTokenInfo<U, V>
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str>,
 	U: AbstractBoundary<usize> {
-	if src.is_same_needle_at(i, POUND_SIGN) {
-		i += 1;
+	if src.is_same_needle_at(i, prefix) {
+		i += prefix.len();
 		let end = find_line_ending(&src, i);
 		(RawToken::LineComment(U::new(i, end)), end)
 	} else if src.is_empty_at(i) {
@@ -56,7 +161,13 @@ where
 #[cfg(test)]
 mod t {
 	use crate::native::{Range, Vec};
-	use super::{RawToken, line_comment};
+	use super::{
+		RawToken,
+		line_comment,
+		line_comment_full,
+		line_comment_with_prefix,
+		line_comment_with_prefix2
+	};
 
 	macro_rules! test_line_comment {
 		($sample:literal 0 $variant:ident) => {
@@ -80,11 +191,53 @@ mod t {
 		test_line_comment!(b"#" 1 1..1);
 		test_line_comment!(b"# hello" 7 1..7);
 		test_line_comment!(b"# hi\n" 4 1..4);
+		test_line_comment!(b"# text" 6 1..6);
+		test_line_comment!(b"# text\n" 6 1..6);
 	}
 
 	#[test]
 	fn cannot_lex() {
 		test_line_comment!(b"" 0 Empty);
 		test_line_comment!(b"\n" 0 Invalid);
+		test_line_comment!(b"a" 0 Invalid);
+	}
+
+	#[test]
+	fn can_lex_with_pound_sign() {
+		let (raw_token, last_index) = line_comment_full
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(b"# hi\n", 0);
+		assert_eq!(raw_token, RawToken::LineComment(0..4));
+		assert_eq!(last_index, 4);
+
+		let (raw_token, last_index) = line_comment_full
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(b"hi", 0);
+		assert_eq!(raw_token, RawToken::Invalid);
+		assert_eq!(last_index, 0);
+	}
+
+	#[test]
+	fn can_lex_with_prefix() {
+		let (raw_token, last_index) = line_comment_with_prefix
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(b"% hi\n", 0, b'%');
+		assert_eq!(raw_token, RawToken::LineComment(1..4));
+		assert_eq!(last_index, 4);
+
+		let (raw_token, last_index) = line_comment_with_prefix2
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(b"-- hi", 0, "--");
+		assert_eq!(raw_token, RawToken::LineComment(2..5));
+		assert_eq!(last_index, 5);
+	}
+
+	#[test]
+	fn cannot_lex_with_other_prefix() {
+		let (raw_token, last_index) = line_comment_with_prefix
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(b"# hi", 0, b'%');
+		assert_eq!(raw_token, RawToken::Invalid);
+		assert_eq!(last_index, 0);
+
+		let (raw_token, last_index) = line_comment_with_prefix
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(b"\xff hi", 0, 0xff);
+		assert_eq!(raw_token, RawToken::Invalid);
+		assert_eq!(last_index, 0);
 	}
 }
@@ -14,6 +14,10 @@ use crate::special_characters::EQUAL_THEN_SPACE;
 /// If there is no valid raw token found, it will return invalid raw token along with the probably
 /// last index checked.
 ///
+/// The last index is the index of the new line after the othertongue, or the length of the source
+/// if there is none. The new line is not consumed but the tabs before it are part of the
+/// othertongue.
+///
 /// ## Examples
 /// ```
 /// use std::ops::Range;
@@ -87,6 +91,12 @@ mod t {
 		test_line_othertongue!(b"= a" 3 2..3);
 	}
 
+	#[test]
+	fn can_lex_with_equal_signs() {
+		test_line_othertongue!(b"= a=b" 5 2..5);
+		test_line_othertongue!(b"= c = d\n" 7 2..7);
+	}
+
 	#[test]
 	fn cannot_lex() {
 		test_line_othertongue!(b" = bc" 0 Invalid);
@@ -0,0 +1,163 @@
+use crate::abstracts::{
+	AbstractSource,
+	AbstractBoundary,
+	ComparableAbstractSource,
+	AbstractBoundaryCollection
+};
+use crate::ascii_needle::ascii_needle;
+use crate::primary_lexers::attacher;
+use crate::raw_token::{RawToken, RawTokenInfo};
+use crate::special_characters::{COLON, SPACE, TAB};
+
+/// Returns the info of recognized attacher with a content separated into items and the last index
+/// that has been checked from the source.
+///
+/// It needs an array of bytes as the first argument (known as source), where to start slicing
+/// (known as slice offset) as the second argument, where to start looking for the terminator as the
+/// third argument (known as the search offset), and the byte which separates the items of the
+/// content as the fourth argument (known as the item separator).
+///
+/// ## Notes
+/// The source will be lexed by [`attacher()`] lexer first. Other raw tokens than the attacher will
+/// be returned as is. The spaces around each item are not part of it. If one of the items is
+/// empty, it will return invalid raw token along with the index where the empty item ends. An item
+/// separator that is not an ASCII character never separates the content.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::list_attacher;
+/// use chearmyp_lexer::RawToken;
+///
+/// let list = b"ports:\t8080, 8443, 9090";
+/// let (raw_token, last_index) = list_attacher
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&list[..], 0, 0, b',');
+/// assert_eq!(raw_token, RawToken::ListAttacher(0..5, vec![7..11, 13..17, 19..23]));
+/// assert_eq!(last_index, 23);
+///
+/// let empty_item = b"ports:\t8080, , 9090";
+/// let (raw_token, last_index) = list_attacher
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&empty_item[..], 0, 0, b',');
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 13);
+/// ```
+///
+/// [`attacher()`]: ./fn.attacher.html
+pub fn list_attacher<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	item_sep: u8
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	let (label, last_seen_index) = match attacher(src.clone(), slice_offset, search_offset) {
+		(RawToken::Attacher(label, _), last_seen_index) => (label, last_seen_index),
+		info => return info
+	};
+
+	let content_start = find_content_start(&src, search_offset.max(slice_offset));
+	let separator = ascii_needle(item_sep);
+	let mut items: Option<V> = None;
+	let mut item_start = content_start;
+
+	for offset in content_start..=last_seen_index {
+		let is_item_end = offset == last_seen_index
+			|| separator.is_some_and(|separator| src.is_same_needle_at(offset, separator));
+		if !is_item_end {
+			continue;
+		}
+
+		let mut start = item_start;
+		let mut end = offset;
+		while start < end && src.is_same_needle_at(start, SPACE) { start += 1; }
+		while start < end && src.is_same_needle_at(end - 1, SPACE) { end -= 1; }
+
+		if start == end {
+			return (RawToken::Invalid, offset);
+		}
+
+		match items.as_mut() {
+			Some(items) => items.add(U::new(start, end)),
+			None => items = Some(V::new(start, end))
+		}
+		item_start = offset + 1;
+	}
+
+	match items {
+		Some(items) => (RawToken::ListAttacher(label, items), last_seen_index),
+		None => (RawToken::Invalid, last_seen_index)
+	}
+}
+
+fn find_content_start<T>(src: &T, mut offset: usize) -> usize
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	loop {
+		let pad_offset = offset + 1;
+		if src.is_same_needle_at(offset, COLON)
+		&& (src.is_same_needle_at(pad_offset, TAB) || src.is_same_needle_at(pad_offset, SPACE)) {
+			break;
+		} else {
+			offset += 1;
+		}
+	}
+
+	offset += 1;
+	while src.is_same_needle_at(offset, TAB) || src.is_same_needle_at(offset, SPACE) {
+		offset += 1;
+	}
+
+	offset
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use super::{RawToken, list_attacher};
+
+	macro_rules! test_list_attacher {
+		(
+			$sample:literal separated by $separator:literal,
+			$expected_token:expr,
+			$expected_consumption:literal
+		) => {
+			let (raw_token, consumed_size) = list_attacher
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&&$sample[..], 0, 0, $separator);
+			assert_eq!(raw_token, $expected_token);
+			assert_eq!(consumed_size, $expected_consumption);
+		};
+	}
+
+	macro_rules! ListAttacher {
+		($label:expr, [$($item:expr),+]) => {
+			RawToken::ListAttacher($label, vec![$($item),+])
+		};
+	}
+
+	#[test]
+	fn can_lex() {
+		test_list_attacher!(b"a:\tb,c" separated by b',', ListAttacher!(0..1, [3..4, 5..6]), 6);
+		test_list_attacher!(
+			b"de: f ; g;h\n" separated by b';',
+			ListAttacher!(0..2, [4..5, 8..9, 10..11]),
+			11
+		);
+		test_list_attacher!(b"i:\tj" separated by b',', ListAttacher!(0..1, [3..4]), 4);
+	}
+
+	#[test]
+	fn cannot_lex() {
+		test_list_attacher!(b"k:\tl,,m" separated by b',', RawToken::Invalid, 5);
+		test_list_attacher!(b"n:\t,o" separated by b',', RawToken::Invalid, 3);
+		test_list_attacher!(b"p:\tq, " separated by b',', RawToken::Invalid, 6);
+		test_list_attacher!(b"r,s" separated by b',', RawToken::Invalid, 3);
+	}
+
+	#[test]
+	fn can_lex_other_attachers() {
+		test_list_attacher!(b"t:" separated by b',', RawToken::AttacherNoContent(0..1), 2);
+	}
+}
@@ -0,0 +1,117 @@
+use crate::abstracts::{AbstractSource, ComparableAbstractSource, AbstractBoundary};
+use crate::primary_lexers::attacher;
+use crate::raw_token::{RawToken, RawTokenInfo};
+use crate::special_characters::{COLON, NEW_LINE, QUOTATION_MARK, SPACE, TAB};
+
+/// Returns the info of recognized attacher with quoted label and the last index that has been
+/// checked from the source.
+///
+/// It needs an array of bytes as the first argument (known as source), where to start slicing
+/// (known as slice offset) as the second argument, and where to start looking for the closing
+/// quotation mark as the third argument (known as the search offset). The label of the returned
+/// attacher does not include the quotation marks so it may contain spaces.
+///
+/// ## Notes
+/// If there is no quotation mark at the slice offset, the source will be lexed by [`attacher()`]
+/// lexer instead. If the label has no closing quotation mark in the same line or it is not followed
+/// by a colon then a tab or space, it will return invalid raw token along with the last index
+/// checked.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::quoted_attacher;
+/// use chearmyp_lexer::RawToken;
+///
+/// let quoted = b"\"full name\":\tjohn doe";
+/// let (raw_token, last_index) = quoted_attacher
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&quoted[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(1..10, 13..21));
+/// assert_eq!(last_index, 21);
+///
+/// let unquoted = b"name:\tjohn doe";
+/// let (raw_token, last_index) = quoted_attacher
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&unquoted[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Attacher(0..4, 6..14));
+/// assert_eq!(last_index, 14);
+/// ```
+///
+/// [`attacher()`]: ./fn.attacher.html
+pub fn quoted_attacher<T, U, V>(src: T, slice_offset: usize, search_offset: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	if !src.is_same_needle_at(slice_offset, QUOTATION_MARK) {
+		return attacher(src, slice_offset, search_offset);
+	}
+
+	let label_start = slice_offset + 1;
+	let mut label_end = if search_offset > label_start { search_offset } else { label_start };
+
+	loop {
+		if src.is_same_needle_at(label_end, QUOTATION_MARK) {
+			break;
+		} else if src.is_same_needle_at(label_end, NEW_LINE) || src.is_empty_at(label_end) {
+			return (RawToken::Invalid, label_end);
+		} else {
+			label_end += 1;
+		}
+	}
+
+	let separator_offset = label_end + 1;
+	let pad_offset = separator_offset + 1;
+	let has_separator = src.is_same_needle_at(separator_offset, COLON)
+		&& (src.is_same_needle_at(pad_offset, TAB) || src.is_same_needle_at(pad_offset, SPACE));
+
+	if !has_separator {
+		return (RawToken::Invalid, separator_offset);
+	}
+
+	match attacher(src, slice_offset, separator_offset) {
+		(RawToken::Attacher(_, content), last_seen_index) => {
+			let label = U::new(label_start, label_end);
+			(RawToken::Attacher(label, content), last_seen_index)
+		},
+		info => info
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use super::{RawToken, quoted_attacher};
+
+	macro_rules! test_quoted_attacher {
+		(
+			$sample:literal,
+			$expected_token:expr,
+			$expected_consumption:literal
+		) => {
+			let (raw_token, consumed_size) = quoted_attacher
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&&$sample[..], 0, 0);
+			assert_eq!(raw_token, $expected_token);
+			assert_eq!(consumed_size, $expected_consumption);
+		};
+	}
+
+	#[test]
+	fn can_lex() {
+		test_quoted_attacher!(b"\"a b\":\tc", RawToken::Attacher(1..4, 7..8), 8);
+		test_quoted_attacher!(b"\"d\": e f\n", RawToken::Attacher(1..2, 5..8), 8);
+		test_quoted_attacher!(b"\"g:\th\":\ti", RawToken::Attacher(1..5, 8..9), 9);
+	}
+
+	#[test]
+	fn can_lex_unquoted_label() {
+		test_quoted_attacher!(b"j:\tk", RawToken::Attacher(0..1, 3..4), 4);
+	}
+
+	#[test]
+	fn cannot_lex() {
+		test_quoted_attacher!(b"\"l m", RawToken::Invalid, 4);
+		test_quoted_attacher!(b"\"n\no\":\tp", RawToken::Invalid, 2);
+		test_quoted_attacher!(b"\"q\"r:\ts", RawToken::Invalid, 3);
+		test_quoted_attacher!(b"\"t\":", RawToken::Invalid, 3);
+	}
+}
@@ -1,7 +1,7 @@
 use crate::abstracts::{AbstractSource, ComparableAbstractSource, AbstractBoundary};
 use crate::delimeter::Delimeter;
 use crate::raw_token::{RawToken, RawTokenInfo};
-use crate::special_characters::{NEW_LINE, TAB, VERTICAL_LINE};
+use crate::special_characters::{BACKSLASH, NEW_LINE, TAB, VERTICAL_LINE};
 
 /// Returns the info of recognized simplex and the last index that has been checked from the source.
 ///
@@ -14,6 +14,11 @@ use crate::special_characters::{NEW_LINE, TAB, VERTICAL_LINE};
 /// source. Also, it does not differentiate attachers because there may be a case where the content
 /// of an attacher ends in vertical line. Use [`attacher()`] lexer first.
 ///
+/// A vertical line preceded by a backslash is escaped so it is part of the concept, like in
+/// `pipe\|`. The concept keeps the backslash. Use [`unescape_simplex()`] to strip it.
+///
+/// The last index is after the vertical line. The tab or new line after it is not consumed.
+///
 /// ## Examples
 /// ```
 /// use std::ops::Range;
@@ -31,38 +36,207 @@ use crate::special_characters::{NEW_LINE, TAB, VERTICAL_LINE};
 /// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&non_simplex[..], 0, 0);
 /// assert_eq!(raw_token, RawToken::Invalid);
 /// assert_eq!(last_index, 11);
+///
+/// let escaped = b"pipe\\||";
+/// let (raw_token, last_index) = simplex
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&escaped[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Simplex(0..6));
+/// assert_eq!(last_index, 7);
 /// ```
 ///
 /// [`attacher()`]: ./fn.attacher.html
-pub fn simplex<T, U, V>(src: T, slice_offset: usize, mut search_offset: usize)
+/// [`unescape_simplex()`]: ../helpers/fn.unescape_simplex.html
+pub fn simplex<T, U, V>(src: T, slice_offset: usize, search_offset: usize)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	simplex_with_terminator(src, slice_offset, search_offset, VERTICAL_LINE)
+}
+
+/// Returns the info of recognized simplex whose backslashes escape the next character and the last
+/// index that has been checked from the source.
+///
+/// It works like [`simplex()`] but a backslash may escape another backslash too. Therefore, the
+/// vertical line in `path\\|` ends the concept since the backslash before it has been escaped.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::simplex_escaped;
+/// use chearmyp_lexer::RawToken;
+///
+/// let escaped = b"pipe\\||";
+/// let (raw_token, last_index) = simplex_escaped
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&escaped[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Simplex(0..6));
+/// assert_eq!(last_index, 7);
+///
+/// let escaped_backslash = b"path\\\\|";
+/// let (raw_token, last_index) = simplex_escaped
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&escaped_backslash[..], 0, 0);
+/// assert_eq!(raw_token, RawToken::Simplex(0..6));
+/// assert_eq!(last_index, 7);
+/// ```
+///
+/// [`simplex()`]: ./fn.simplex.html
+pub fn simplex_escaped<T, U, V>(src: T, slice_offset: usize, search_offset: usize)
 -> RawTokenInfo<U, V>
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str>,
 	U: AbstractBoundary<usize> {
+	let options = SimplexOptions {
+		terminator: VERTICAL_LINE,
+		min_len: 0,
+		pairs_backslashes: true
+	};
+	lex_simplex(src, slice_offset, search_offset, options)
+}
+
+/// Returns the info of recognized simplex that ends with the terminator and the last index that has
+/// been checked from the source.
+///
+/// It works like [`simplex()`] but it needs the characters that end the concept as the fourth
+/// argument (known as the terminator) instead of the vertical line. The terminator may have more
+/// than one character, like `||` which allows a single vertical line inside the concept.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::simplex_with_terminator;
+/// use chearmyp_lexer::RawToken;
+///
+/// let terminated = b"hello|world||";
+/// let (raw_token, last_index) = simplex_with_terminator
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&terminated[..], 0, 0, "||");
+/// assert_eq!(raw_token, RawToken::Simplex(0..11));
+/// assert_eq!(last_index, 13);
+///
+/// let non_simplex = b"hello world|";
+/// let (raw_token, last_index) = simplex_with_terminator
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&non_simplex[..], 0, 0, "||");
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 12);
+/// ```
+///
+/// [`simplex()`]: ./fn.simplex.html
+pub fn simplex_with_terminator<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	terminator: &'static str
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	let options = SimplexOptions { terminator, min_len: 0, pairs_backslashes: false };
+	lex_simplex(src, slice_offset, search_offset, options)
+}
+
+/// Returns the info of recognized simplex whose concept has at least the minimum length and the
+/// last index that has been checked from the source.
+///
+/// It works like [`simplex()`] but it needs the minimum number of bytes that the concept should
+/// have as the fourth argument (known as the minimum length).
+///
+/// ## Notes
+/// If the concept is shorter than the minimum length, it will return invalid raw token along with
+/// the index of the vertical line that ends the concept.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::primary_lexers::simplex_min_len;
+/// use chearmyp_lexer::RawToken;
+///
+/// let long_enough = b"hi|";
+/// let (raw_token, last_index) = simplex_min_len
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&long_enough[..], 0, 0, 2);
+/// assert_eq!(raw_token, RawToken::Simplex(0..2));
+/// assert_eq!(last_index, 3);
+///
+/// let too_short = b"h|";
+/// let (raw_token, last_index) = simplex_min_len
+/// 	::<&[u8], Range<usize>, Vec<Range<usize>>>(&too_short[..], 0, 0, 2);
+/// assert_eq!(raw_token, RawToken::Invalid);
+/// assert_eq!(last_index, 1);
+/// ```
+///
+/// [`simplex()`]: ./fn.simplex.html
+pub fn simplex_min_len<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	min_len: usize
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	let options = SimplexOptions { terminator: VERTICAL_LINE, min_len, pairs_backslashes: false };
+	lex_simplex(src, slice_offset, search_offset, options)
+}
+
+struct SimplexOptions {
+	terminator: &'static str,
+	min_len: usize,
+	pairs_backslashes: bool
+}
+
+fn lex_simplex<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	mut search_offset: usize,
+	options: SimplexOptions
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str>,
+	U: AbstractBoundary<usize> {
+	let SimplexOptions { terminator, min_len, pairs_backslashes } = options;
 	let start = slice_offset;
 	let end;
 
 	loop {
-		let ending = determine_ending(&src, search_offset);
+		let ending = determine_ending(&src, start, search_offset, terminator, pairs_backslashes);
 		match ending {
 			Delimeter::Incorrect => search_offset += 1,
 			Delimeter::Invalid => { return (RawToken::Invalid, search_offset); },
 			Delimeter::Pad | Delimeter::Limit => {
 				end = search_offset;
-				search_offset += 1;
+				search_offset += terminator.len();
 				break;
 			}
 		}
 	}
 
+	if end - start < min_len {
+		return (RawToken::Invalid, end);
+	}
+
 	(RawToken::Simplex(U::new(start, end)), search_offset)
 }
 
-fn determine_ending<T>(src: &T, offset: usize) -> Delimeter
+fn is_escaped<T>(src: &T, start: usize, offset: usize, pairs_backslashes: bool) -> bool
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> {
-	if src.is_same_needle_at(offset, VERTICAL_LINE) {
-		let next_offset = offset + 1;
+	let backslash_count = (start..offset).rev()
+		.take_while(|&index| src.is_same_needle_at(index, BACKSLASH))
+		.take(if pairs_backslashes { usize::MAX } else { 1 })
+		.count();
+	backslash_count % 2 == 1
+}
+
+fn determine_ending<T>(
+	src: &T,
+	start: usize,
+	offset: usize,
+	terminator: &'static str,
+	pairs_backslashes: bool
+) -> Delimeter
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	if src.is_same_needle_at(offset, terminator)
+	&& !is_escaped(src, start, offset, pairs_backslashes) {
+		let next_offset = offset + terminator.len();
 		if src.is_same_needle_at(next_offset, NEW_LINE) || src.is_same_needle_at(next_offset, TAB) {
 			Delimeter::Pad
 		} else if src.is_empty_at(next_offset) {
@@ -82,7 +256,7 @@ where
 #[cfg(test)]
 mod t {
 	use crate::native::{Range, Vec};
-	use super::{RawToken, simplex};
+	use super::{RawToken, simplex, simplex_escaped, simplex_min_len, simplex_with_terminator};
 
 	macro_rules! test_simplex {
 		(
@@ -104,6 +278,20 @@ mod t {
 		test_simplex!(b"kl|", RawToken::Simplex(0..2), 3);
 	}
 
+	#[test]
+	fn can_lex_before_tab_delimiter() {
+		test_simplex!(b"a|\tb", RawToken::Simplex(0..1), 2);
+		test_simplex!(b"cd|\t\te|", RawToken::Simplex(0..2), 3);
+	}
+
+	#[test]
+	fn can_lex_with_each_ending_after_vertical_line() {
+		test_simplex!(b"a|", RawToken::Simplex(0..1), 2);
+		test_simplex!(b"a|\t", RawToken::Simplex(0..1), 2);
+		test_simplex!(b"a|\n", RawToken::Simplex(0..1), 2);
+		test_simplex!(b"a|b", RawToken::Invalid, 3);
+	}
+
 	#[test]
 	fn cannot_lex() {
 		test_simplex!(b"g\n", RawToken::Invalid, 1);
@@ -111,4 +299,65 @@ mod t {
 		test_simplex!(b"mn", RawToken::Invalid, 2);
 		test_simplex!(b"o: pq", RawToken::Invalid, 5);
 	}
+
+	macro_rules! test_simplex_min_len {
+		(
+			$sample:literal at least $min_len:literal,
+			$expected_token:expr,
+			$expected_consumption:literal
+		) => {
+			let (raw_token, consumed_size) = simplex_min_len
+				::<&[u8], Range<usize>, Vec<Range<usize>>>(&$sample[..], 0, 0, $min_len);
+			assert_eq!(raw_token, $expected_token);
+			assert_eq!(consumed_size, $expected_consumption);
+		};
+	}
+
+	#[test]
+	fn can_lex_with_minimum_length() {
+		test_simplex_min_len!(b"r|" at least 1, RawToken::Simplex(0..1), 2);
+		test_simplex_min_len!(b"st|\n" at least 2, RawToken::Simplex(0..2), 3);
+	}
+
+	#[test]
+	fn cannot_lex_shorter_than_minimum_length() {
+		test_simplex_min_len!(b"|" at least 1, RawToken::Invalid, 0);
+		test_simplex_min_len!(b"u|\t" at least 2, RawToken::Invalid, 1);
+	}
+
+	#[test]
+	fn can_lex_escaped_vertical_line() {
+		test_simplex!(b"a\\||", RawToken::Simplex(0..3), 4);
+		test_simplex!(b"b\\|\t", RawToken::Invalid, 3);
+		test_simplex!(b"c\\\\|", RawToken::Invalid, 4);
+	}
+
+	#[test]
+	fn can_lex_escaped_backslash() {
+		let (raw_token, consumed_size) = simplex_escaped
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"d\\\\|\te"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::Simplex(0..3));
+		assert_eq!(consumed_size, 4);
+
+		let (raw_token, consumed_size) = simplex_escaped
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"f\\\\\\|\n"[..], 0, 0);
+		assert_eq!(raw_token, RawToken::Invalid);
+		assert_eq!(consumed_size, 5);
+	}
+
+	#[test]
+	fn can_lex_with_terminator() {
+		let (raw_token, consumed_size) = simplex_with_terminator
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"v|w||\tx"[..], 0, 0, "||");
+		assert_eq!(raw_token, RawToken::Simplex(0..3));
+		assert_eq!(consumed_size, 5);
+	}
+
+	#[test]
+	fn cannot_lex_with_other_terminator() {
+		let (raw_token, consumed_size) = simplex_with_terminator
+			::<&[u8], Range<usize>, Vec<Range<usize>>>(&b"y|\tz"[..], 0, 0, "||");
+		assert_eq!(raw_token, RawToken::Invalid);
+		assert_eq!(consumed_size, 2);
+	}
 }
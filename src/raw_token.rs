@@ -1,19 +1,186 @@
+use crate::native::Vec;
+
 /// Contains the raw tokens used for lexing only.
 #[derive(Debug, PartialEq)]
 pub enum RawToken<T, U> {
 	Empty,
 	Invalid,
 	ScopeLevel(usize),
-	Block(U),
+	BlankLine(T),
+	Block {
+		lines: U,
+		tab_level: usize
+	},
+	EmptyBlock,
+	BlockParagraphs(Vec<U>),
+	UnclosedBlock(U),
 	LineComment(T),
 	BlockComment(U),
+	UnclosedBlockComment(U),
+	MetaBlockComment {
+		meta: T,
+		lines: U
+	},
+	BlockCommentWithHeader {
+		header: T,
+		lines: U
+	},
 	Simplex(T),
 	Complex(T),
 	Attacher(T, T),
+	AttacherNoContent(T),
+	InvalidLabel(T),
+	NamespacedAttacher(U, T),
+	DottedAttacher {
+		segments: U,
+		content: T
+	},
+	ListAttacher(T, U),
+	InlineBlockAttacher(T, U),
 	LineOthertongue(T),
-	BlockOthertongue(U)
+	BlockOthertongue(U),
+	TaggedBlockOthertongue {
+		tag: T,
+		lines: U
+	},
+	UnclosedBlockOthertongue(U),
+	MismatchedBlockClose {
+		actual_depth: usize,
+		expected_depth: usize,
+		lines: U
+	},
+	BlockTooLong {
+		lines_collected: U,
+		limit: usize
+	},
+	BlockTooShort {
+		lines: U,
+		required: usize
+	},
+	MalformedBlock(U)
+}
+
+impl<T, U> RawToken<T, U> {
+	/// Returns the kind of the raw token.
+	///
+	/// ## Examples
+	/// ```
+	/// use std::ops::Range;
+	/// use chearmyp_lexer::{RawToken, RawTokenKind};
+	///
+	/// let raw_token = RawToken::<Range<usize>, Vec<Range<usize>>>::Simplex(0..5);
+	/// assert_eq!(raw_token.kind(), RawTokenKind::Simplex);
+	/// ```
+	#[inline]
+	pub fn kind(&self) -> RawTokenKind {
+		match self {
+			Self::Empty => RawTokenKind::Empty,
+			Self::Invalid => RawTokenKind::Invalid,
+			Self::ScopeLevel(_) => RawTokenKind::ScopeLevel,
+			Self::BlankLine(_) => RawTokenKind::BlankLine,
+			Self::Block { .. } => RawTokenKind::Block,
+			Self::EmptyBlock => RawTokenKind::EmptyBlock,
+			Self::BlockParagraphs(_) => RawTokenKind::BlockParagraphs,
+			Self::UnclosedBlock(_) => RawTokenKind::UnclosedBlock,
+			Self::LineComment(_) => RawTokenKind::LineComment,
+			Self::BlockComment(_) => RawTokenKind::BlockComment,
+			Self::UnclosedBlockComment(_) => RawTokenKind::UnclosedBlockComment,
+			Self::MetaBlockComment { .. } => RawTokenKind::MetaBlockComment,
+			Self::BlockCommentWithHeader { .. } => RawTokenKind::BlockCommentWithHeader,
+			Self::Simplex(_) => RawTokenKind::Simplex,
+			Self::Complex(_) => RawTokenKind::Complex,
+			Self::Attacher(_, _) => RawTokenKind::Attacher,
+			Self::AttacherNoContent(_) => RawTokenKind::AttacherNoContent,
+			Self::InvalidLabel(_) => RawTokenKind::InvalidLabel,
+			Self::NamespacedAttacher(_, _) => RawTokenKind::NamespacedAttacher,
+			Self::DottedAttacher { .. } => RawTokenKind::DottedAttacher,
+			Self::ListAttacher(_, _) => RawTokenKind::ListAttacher,
+			Self::InlineBlockAttacher(_, _) => RawTokenKind::InlineBlockAttacher,
+			Self::LineOthertongue(_) => RawTokenKind::LineOthertongue,
+			Self::BlockOthertongue(_) => RawTokenKind::BlockOthertongue,
+			Self::TaggedBlockOthertongue { .. } => RawTokenKind::TaggedBlockOthertongue,
+			Self::UnclosedBlockOthertongue(_) => RawTokenKind::UnclosedBlockOthertongue,
+			Self::MismatchedBlockClose { .. } => RawTokenKind::MismatchedBlockClose,
+			Self::BlockTooLong { .. } => RawTokenKind::BlockTooLong,
+			Self::BlockTooShort { .. } => RawTokenKind::BlockTooShort,
+			Self::MalformedBlock(_) => RawTokenKind::MalformedBlock
+		}
+	}
+}
+
+/// Contains the kinds of raw tokens.
+///
+/// It mirrors the kinds of tokens plus the kinds used for lexing only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RawTokenKind {
+	Empty,
+	Invalid,
+	ScopeLevel,
+	BlankLine,
+	Block,
+	EmptyBlock,
+	BlockParagraphs,
+	UnclosedBlock,
+	LineComment,
+	BlockComment,
+	UnclosedBlockComment,
+	MetaBlockComment,
+	BlockCommentWithHeader,
+	Simplex,
+	Complex,
+	Attacher,
+	AttacherNoContent,
+	InvalidLabel,
+	NamespacedAttacher,
+	DottedAttacher,
+	ListAttacher,
+	InlineBlockAttacher,
+	LineOthertongue,
+	BlockOthertongue,
+	TaggedBlockOthertongue,
+	UnclosedBlockOthertongue,
+	MismatchedBlockClose,
+	BlockTooLong,
+	BlockTooShort,
+	MalformedBlock
+}
+
+impl<T, U> From<&RawToken<T, U>> for RawTokenKind {
+	#[inline]
+	fn from(raw_token: &RawToken<T, U>) -> Self {
+		raw_token.kind()
+	}
 }
 
 /// Contains the extracted raw token and its last index occupied in the source.
 /// This raw token is used as return value for most lexers.
 pub type RawTokenInfo<U, V> = (RawToken<U, V>, usize);
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use super::{RawToken, RawTokenKind};
+
+	type SampleRawToken = RawToken<Range<usize>, Vec<Range<usize>>>;
+
+	#[test]
+	fn can_get_kind_of_lexing_only_raw_token() {
+		assert_eq!(SampleRawToken::Empty.kind(), RawTokenKind::Empty);
+		assert_eq!(SampleRawToken::Invalid.kind(), RawTokenKind::Invalid);
+		assert_eq!(SampleRawToken::EmptyBlock.kind(), RawTokenKind::EmptyBlock);
+		let raw_token = SampleRawToken::Block { lines: vec![0..1, 2..3], tab_level: 0 };
+		assert_eq!(raw_token.kind(), RawTokenKind::Block);
+	}
+
+	#[test]
+	fn can_get_kind_of_raw_token() {
+		assert_eq!(SampleRawToken::ScopeLevel(1).kind(), RawTokenKind::ScopeLevel);
+		assert_eq!(SampleRawToken::Attacher(0..1, 2..3).kind(), RawTokenKind::Attacher);
+	}
+
+	#[test]
+	fn can_convert_raw_token_into_kind() {
+		let raw_token = SampleRawToken::LineComment(1..2);
+		assert_eq!(RawTokenKind::from(&raw_token), RawTokenKind::LineComment);
+	}
+}
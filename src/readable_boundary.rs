@@ -0,0 +1,35 @@
+use crate::native::{Range, Vec};
+
+/// Represents a boundary whose ends can be read back after it has been created.
+///
+/// The abstract boundaries can only be constructed. Some helpers need to know where a boundary
+/// starts and ends to work on the source again.
+pub trait ReadableBoundary {
+	/// Returns the index where the boundary starts.
+	fn start(&self) -> usize;
+
+	/// Returns the index where the boundary ends (exclusive).
+	fn end(&self) -> usize;
+}
+
+/// Represents a collection of boundaries that can be read back in the order they were added.
+pub trait ReadableBoundaryCollection<U> {
+	/// Returns the boundaries in the collection.
+	fn boundaries(&self) -> &[U];
+}
+
+impl ReadableBoundary for Range<usize> {
+	fn start(&self) -> usize {
+		self.start
+	}
+
+	fn end(&self) -> usize {
+		self.end
+	}
+}
+
+impl<U> ReadableBoundaryCollection<U> for Vec<U> {
+	fn boundaries(&self) -> &[U] {
+		&self[..]
+	}
+}
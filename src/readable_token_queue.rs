@@ -0,0 +1,55 @@
+use crate::native::{Vec, VecDeque};
+
+/// Represents a token queue whose tokens can be read back in the order they were pushed.
+///
+/// The abstract token queues can only receive tokens. Some helpers need to read the tokens again
+/// after lexing.
+pub trait ReadableTokenQueue<W> {
+	/// Returns the number of tokens in the queue.
+	fn token_count(&self) -> usize;
+
+	/// Returns the token at the index if it exists.
+	fn token_at(&self, index: usize) -> Option<&W>;
+
+	/// Removes the first token in the queue and returns it if it exists.
+	fn pop_front_token(&mut self) -> Option<W>;
+
+	/// Removes the last token in the queue and returns it if it exists.
+	fn pop_back_token(&mut self) -> Option<W>;
+}
+
+impl<W> ReadableTokenQueue<W> for VecDeque<W> {
+	fn token_count(&self) -> usize {
+		self.len()
+	}
+
+	fn token_at(&self, index: usize) -> Option<&W> {
+		self.get(index)
+	}
+
+	fn pop_front_token(&mut self) -> Option<W> {
+		self.pop_front()
+	}
+
+	fn pop_back_token(&mut self) -> Option<W> {
+		self.pop_back()
+	}
+}
+
+impl<W> ReadableTokenQueue<W> for Vec<W> {
+	fn token_count(&self) -> usize {
+		self.len()
+	}
+
+	fn token_at(&self, index: usize) -> Option<&W> {
+		self.get(index)
+	}
+
+	fn pop_front_token(&mut self) -> Option<W> {
+		if self.is_empty() { None } else { Some(self.remove(0)) }
+	}
+
+	fn pop_back_token(&mut self) -> Option<W> {
+		self.pop()
+	}
+}
@@ -0,0 +1,25 @@
+/// Contains the kinds of invalid scope level transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeErrorKind {
+	/// The scope level increased by more than one level.
+	LevelJumpTooLarge,
+
+	/// The scope level is deeper than the maximum allowed.
+	LevelTooDeep
+}
+
+/// Contains the info of the first invalid scope level transition found in a token queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScopeError {
+	/// The kind of invalid transition.
+	pub kind: ScopeErrorKind,
+
+	/// The index of the scope level token in the token queue.
+	pub token_index: usize,
+
+	/// The scope level before the transition.
+	pub from_level: usize,
+
+	/// The scope level after the transition.
+	pub to_level: usize
+}
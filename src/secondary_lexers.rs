@@ -1,8 +1,116 @@
-/// Contains `any()` lexer.
+/// Contains `any()`, `any_with_config()`, `any_with_handler()`, `any_with_plugins()`, their
+/// fallible versions, and `any_with_list_items()`.
 mod any;
 
 /// Contains the general lexer.
 mod lex;
 
-pub use lex::lex;
-pub use any::any;
+/// Contains `token_to_source_fragment()`.
+mod token_to_source_fragment;
+
+/// Contains `validate_scope_levels()`.
+mod validate_scope_levels;
+
+/// Contains `TokenCursor`.
+mod token_cursor;
+
+/// Contains `drain_to_vec()` and `into_vec_deque()`.
+mod drain_to_vec;
+
+/// Contains `count_tokens()`.
+mod count_tokens;
+
+/// Contains `ReversibleTokenIter`.
+mod reversible_token_iter;
+
+/// Contains `tokens_on_line()`.
+mod tokens_on_line;
+
+/// Contains `concat_token_queues()`.
+mod concat_token_queues;
+
+/// Contains `partition_tokens()` and `TokenPartition`.
+mod partition_tokens;
+
+/// Contains `TokenSink`, `QueueSink`, and `WritingSink`.
+mod token_sink;
+
+/// Contains `token_windows()`.
+mod token_windows;
+
+/// Contains `split_by_scope()`.
+mod split_by_scope;
+
+/// Contains `prescan()` and `PrescanStats`.
+mod prescan;
+
+/// Contains `build_token_fingerprint_map()` and `TokenFingerprintMap`.
+mod token_fingerprint_map;
+
+/// Contains `interleave_token_queues()`.
+mod interleave_token_queues;
+
+/// Contains `verify_structure()`.
+mod verify_structure;
+
+/// Contains `lex_interned()`.
+#[cfg(feature = "interner")]
+mod lex_interned;
+
+pub use lex::{
+	lex,
+	lex_at_depth,
+	lex_bounded,
+	lex_chunked,
+	lex_each,
+	lex_no_comments,
+	lex_recover,
+	lex_sink,
+	lex_spanned,
+	lex_vec,
+	lex_with_config,
+	lex_with_context,
+	lex_with_fingerprint,
+	lex_with_limit,
+	lex_with_lines,
+	lex_with_max_depth,
+	lex_with_progress,
+	lex_with_source_map,
+	lex_with_token_kinds
+};
+#[cfg(feature = "async")]
+pub use lex::lex_chunked_async;
+pub use any::{
+	any,
+	any_hinted,
+	any_or_end,
+	any_spanned,
+	any_with_config,
+	any_with_handler,
+	any_with_list_items,
+	any_with_plugins,
+	try_any,
+	try_any_with_config
+};
+#[cfg(feature = "trace")]
+pub use any::any_traced;
+pub use token_to_source_fragment::token_to_source_fragment;
+pub use validate_scope_levels::validate_scope_levels;
+pub use token_cursor::TokenCursor;
+pub use drain_to_vec::{drain_to_vec, into_vec_deque};
+pub use count_tokens::count_tokens;
+pub use reversible_token_iter::ReversibleTokenIter;
+pub use tokens_on_line::tokens_on_line;
+pub use concat_token_queues::concat_token_queues;
+pub use partition_tokens::{partition_tokens, TokenPartition};
+pub use token_sink::{QueueSink, TokenSink};
+pub use token_windows::token_windows;
+pub use split_by_scope::split_by_scope;
+pub use prescan::{prescan, PrescanStats};
+pub use token_fingerprint_map::{build_token_fingerprint_map, TokenFingerprintMap};
+pub use interleave_token_queues::interleave_token_queues;
+pub use verify_structure::verify_structure;
+#[cfg(not(feature = "no_std"))]
+pub use token_sink::WritingSink;
+#[cfg(feature = "interner")]
+pub use lex_interned::lex_interned;
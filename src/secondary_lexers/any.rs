@@ -5,20 +5,49 @@ use crate::abstracts::{
 	ComparableAbstractSource,
 	AbstractBoundaryCollection
 };
-use crate::helpers::count_tabs;
-use crate::raw_token::RawToken;
-use crate::token_info::TokenInfo;
-use crate::special_characters::{EQUAL, POUND_SIGN};
+use crate::helpers::{block_end, count_tabs, count_tabs_with_width, find_indentation_end};
+use crate::lexer_config::LexerConfig;
+use crate::lexer_plugin::LexerPlugin;
+use crate::raw_token::{RawToken, RawTokenInfo};
+#[cfg(feature = "trace")]
+use crate::native::Vec;
+#[cfg(feature = "trace")]
+use crate::trace_event::{InvalidReason, TraceEvent, TraceResult};
+use crate::token::TokenKind;
+use crate::token_info::{TokenInfo, SpannedTokenInfo};
+use crate::unexpected_token_error::UnexpectedTokenError;
+use crate::special_characters::{
+	EQUAL,
+	NEW_LINE,
+	POUND_SIGN,
+	EQUAL_THEN_SPACE,
+	SPACE_THEN_EQUAL,
+	TRIPLE_POUND_SIGN
+};
 use crate::{
 	simplex,
 	complex,
 	attacher,
+	attacher_strict,
+	attacher_semicolon,
+	attacher_trimmed,
+	attacher_with_comment,
+	attacher_double_colon,
+	attacher_arrow,
+	attacher_namespaced,
+	dotted_attacher,
+	list_attacher,
 	line_comment,
-	block_comment,
+	block_comment_bounded,
+	block_comment_nested_with_width,
 	line_othertongue,
-	block_othertongue
+	block_othertongue_bounded,
+	tagged_block_othertongue_bounded
 };
 
+pub const UNEXPECTED_TOKEN_MESSAGE: &str = "There is an unexpected raw token in lexing found \
+	in the source. This is possibly due to developer error.";
+
 /// Returns the info of first recognized token and its probably last seen index in the source.
 ///
 /// It needs an array of bytes as the first argument (known as source), where to start looking for
@@ -27,7 +56,14 @@ use crate::{
 /// offset has already been checked (if this is true, it would check for scope level).
 ///
 /// ## Notes
-/// May panic if the last possible lexer has returned an unexpected token.
+/// May panic if the last possible lexer has returned an unexpected token. Use [`try_any()`] to
+/// handle it instead. There is no token at the end of the source. Use [`any_or_end()`] to check it
+/// first.
+///
+/// If a block comment or block othertongue has a closing delimiter with wrong indentation or with
+/// trailing content, the lines collected before the closing delimiter will be returned as the block
+/// token. Use [`try_any()`] to reject them instead. Block tokens without closing delimiters are
+/// returned with the lines collected until the end of the source.
 ///
 /// ## Examples
 /// ```
@@ -43,8 +79,132 @@ use crate::{
 /// assert_eq!(token, Token::new_complex(0..5));
 /// assert_eq!(last_index, 5);
 /// ```
+///
+/// [`try_any()`]: ./fn.try_any.html
+/// [`any_or_end()`]: ./fn.any_or_end.html
 pub fn any<T, U, V, W>(src: T, offset: usize, tab_count: usize, is_in_new_line: bool)
 -> TokenInfo<W>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	any_with_config(src, offset, tab_count, is_in_new_line, &LexerConfig::default())
+}
+
+/// Returns the info of first recognized token and its probably last seen index in the source or
+/// `None` if the offset is at the end of the source.
+///
+/// It works like [`any()`] but it checks if there is a character at the offset first. Therefore, it
+/// can be called repeatedly until the source has been exhausted.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::secondary_lexers::any_or_end;
+/// use chearmyp_token::Token;
+///
+/// type Info = Option<(Token<Range<usize>, Vec<Range<usize>>>, usize)>;
+///
+/// let info: Info = any_or_end(&b"hello"[..], 0, 0, false);
+/// assert_eq!(info, Some((Token::new_complex(0..5), 5)));
+///
+/// let info: Info = any_or_end(&b"hello"[..], 5, 0, false);
+/// assert_eq!(info, None);
+/// ```
+///
+/// [`any()`]: ./fn.any.html
+pub fn any_or_end<T, U, V, W>(src: T, offset: usize, tab_count: usize, is_in_new_line: bool)
+-> Option<TokenInfo<W>>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	if src.is_empty_at(offset) {
+		None
+	} else {
+		Some(any(src, offset, tab_count, is_in_new_line))
+	}
+}
+
+/// Returns the first recognized token and the byte range from the offset up to its probably last
+/// seen index in the source.
+///
+/// It works like [`any()`] but the returned range also contains the offset where the token was
+/// started to be lexed. The range of a token found in a new line includes the tabs before it.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::any_spanned;
+/// use chearmyp_token::Token;
+///
+/// let (token, span): (
+///   Token<Range<usize>, Vec<Range<usize>>>,
+///   Range<usize>
+/// ) = any_spanned(&b"hello\nworld|"[..], 6, 0, false);
+/// assert_eq!(token, Token::new_simplex(6..11));
+/// assert_eq!(span, 6..12);
+/// ```
+///
+/// [`any()`]: ./fn.any.html
+pub fn any_spanned<T, U, V, W>(src: T, offset: usize, tab_count: usize, is_in_new_line: bool)
+-> SpannedTokenInfo<W>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	let token_start = offset;
+	let (token, last_seen_index) = any(src, offset, tab_count, is_in_new_line);
+	(token, token_start..last_seen_index)
+}
+
+/// Returns the info of first recognized token and its probably last seen index in the source by
+/// trying the lexer of the hinted token kind first.
+///
+/// It works like [`any()`] but it needs the kind of token that is likely to be found as the fifth
+/// argument (known as the hint). If the hinted lexer cannot recognize the token, the source will be
+/// lexed in the usual order.
+///
+/// ## Notes
+/// The hint does not change the recognized token. It is only followed if no other lexer in the
+/// usual order would recognize the token first. Currently, only the attacher, line comment, and
+/// line othertongue kinds can be followed. Other kinds are lexed in the usual order.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::{AbstractToken, TokenKind};
+/// use chearmyp_lexer::any_hinted;
+/// use chearmyp_token::Token;
+///
+/// let (token, last_index): (
+///   Token<Range<usize>, Vec<Range<usize>>>,
+///   usize
+/// ) = any_hinted(&b"\thello:\tworld"[..], 0, 1, true, TokenKind::Attacher);
+/// assert_eq!(token, Token::new_attacher(1..6, 8..13));
+/// assert_eq!(last_index, 13);
+///
+/// let (token, last_index): (
+///   Token<Range<usize>, Vec<Range<usize>>>,
+///   usize
+/// ) = any_hinted(&b"hello"[..], 0, 0, false, TokenKind::Attacher);
+/// assert_eq!(token, Token::new_complex(0..5));
+/// assert_eq!(last_index, 5);
+/// ```
+///
+/// [`any()`]: ./fn.any.html
+pub fn any_hinted<T, U, V, W>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	is_in_new_line: bool,
+	hint: TokenKind
+) -> TokenInfo<W>
 where
 	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
 	U: AbstractBoundary<usize>,
@@ -53,7 +213,7 @@ where
 	let mut tabbed_offset = offset;
 
 	if is_in_new_line {
-		let new_tab_count = count_tabs(src.clone().forward_slice(offset), tab_count);
+		let (new_tab_count, _) = count_tabs(src.clone().forward_slice(offset), tab_count);
 		if new_tab_count != tab_count {
 			return (W::new_scope_level(new_tab_count), offset + new_tab_count);
 		} else {
@@ -61,145 +221,984 @@ where
 		}
 	}
 
-	let mut offset = tabbed_offset;
-	let mut raw_token;
-
-	macro_rules! lex {
-		(
-			$parser:ident$(($($other_argument:tt),+))?
-			$(unless $raw_token:ident($($content:tt),+) turns into $new_token:ident => $block:block)?
-			$(
-				which expects
-					$expected_raw_token:ident($($expected_content:tt),+)
-					turning into $expected_new_token:ident
-			)?
-		) => {
-			let info = $parser(src.clone(), offset, $($($other_argument,)*)?);
-			raw_token = info.0;
-			offset = info.1;
-			$(
-				if let RawToken::$raw_token($($content,)+) = raw_token {
-					let token = W::$new_token($($content,)+);
-					(token, offset)
-				} else $block
-			)?
-			$(
-				if let RawToken::$expected_raw_token($($expected_content,)+) = raw_token {
-					let token = W::$expected_new_token($($expected_content,)+);
-					(token, offset)
-				} else {
-					let effect = "There is an unexpected raw token in lexing found in the source.";
-					let cause = "This is possibly due to developer error.";
-					panic!("{} {}", effect, cause);
-				}
-			)?
-		};
-	}
+	let is_reserved = src.is_same_needle_at(tabbed_offset, POUND_SIGN)
+		|| src.is_same_needle_at(tabbed_offset, EQUAL)
+		|| src.is_same_needle_at(tabbed_offset, SPACE_THEN_EQUAL);
 
-	if src.is_same_needle_at(offset, POUND_SIGN) {
-		lex!{
-			block_comment(tab_count)
-			unless BlockComment(comment) turns into new_block_comment => {
-				lex!{ line_comment which expects LineComment(comment) turning into new_line_comment }
+	let hinted_info = match hint {
+		TokenKind::Attacher if !is_reserved => {
+			match attacher::<T, U, V>(src.clone(), tabbed_offset, tabbed_offset) {
+				(RawToken::Attacher(label, content), offset) => {
+					Some((W::new_attacher(label, content), offset))
+				},
+				_ => None
 			}
-		}
-	} else if src.is_same_needle_at(offset, EQUAL) {
-		lex!{
-			block_othertongue(tab_count)
-			unless BlockOthertongue(othertongue) turns into new_block_othertongue => {
-				lex!{
-					line_othertongue
-					unless LineOthertongue(othertongue) turns into new_line_othertongue => {
-						lex!{
-							attacher(offset)
-							unless Attacher(label, content) turns into new_attacher => {
-								let search_offset = if offset > tabbed_offset {
-									offset - 1
-								} else {
-									tabbed_offset
-								};
-								let slice_start_offset = tabbed_offset;
-								offset = slice_start_offset;
-
-								lex!{
-									simplex(search_offset)
-									unless Simplex(concept) turns into new_simplex => {
-										let search_offset = offset;
-										let slice_start_offset = tabbed_offset;
-										offset = slice_start_offset;
-										lex!{
-											complex(search_offset)
-											which expects Complex(concept)
-											turning into new_complex
-										}
-									}
-								}
-							}
-						}
-					}
-				}
+		},
+		TokenKind::LineComment if src.is_same_needle_at(tabbed_offset, POUND_SIGN)
+		&& !src.is_same_needle_at(tabbed_offset, TRIPLE_POUND_SIGN) => {
+			match line_comment::<T, U, V>(src.clone(), tabbed_offset) {
+				(RawToken::LineComment(comment), offset) => {
+					Some((W::new_line_comment(comment), offset))
+				},
+				_ => None
 			}
-		}
-	} else {
-		lex!{
-			attacher(offset)
-			unless Attacher(label, content) turns into new_attacher => {
-				let search_offset = if offset > tabbed_offset {
-					offset - 1
-				} else {
-					tabbed_offset
-				};
-				let slice_start_offset = tabbed_offset;
-				offset = slice_start_offset;
+		},
+		TokenKind::LineOthertongue => {
+			let othertongue_offset = if src.is_same_needle_at(tabbed_offset, SPACE_THEN_EQUAL) {
+				tabbed_offset + 1
+			} else {
+				tabbed_offset
+			};
 
-				lex!{
-					simplex(search_offset)
-					unless Simplex(concept) turns into new_simplex => {
-						let search_offset = offset;
-						let slice_start_offset = tabbed_offset;
-						offset = slice_start_offset;
-						lex!{
-							complex(search_offset)
-							which expects Complex(concept)
-							turning into new_complex
-						}
-					}
+			if src.is_same_needle_at(othertongue_offset, EQUAL_THEN_SPACE) {
+				match line_othertongue::<T, U, V>(src.clone(), othertongue_offset) {
+					(RawToken::LineOthertongue(othertongue), offset) => {
+						Some((W::new_line_othertongue(othertongue), offset))
+					},
+					_ => None
 				}
+			} else {
+				None
 			}
+		},
+		_ => None
+	};
+
+	hinted_info.unwrap_or_else(|| any(src, offset, tab_count, is_in_new_line))
+}
+
+/// Returns the info of first recognized token and its probably last seen index in the source while
+/// trying the plugins first.
+///
+/// It works like [`any()`] but each plugin in the fifth argument is tried in order before the
+/// built-in lexers. The info returned by the first plugin that recognizes a token is returned.
+///
+/// ## Notes
+/// The plugins are not tried if the line at the offset has a different scope level. The scope
+/// level token is returned first like in [`any()`].
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::LexerPlugin;
+/// use chearmyp_lexer::secondary_lexers::any_with_plugins;
+/// use chearmyp_token::Token;
+///
+/// type Boundary = Range<usize>;
+/// type Boundaries = Vec<Range<usize>>;
+/// type SimpleToken = Token<Boundary, Boundaries>;
+///
+/// struct TagPlugin;
+///
+/// impl<'a> LexerPlugin<&'a [u8], Boundary, Boundaries, SimpleToken> for TagPlugin {
+/// 	fn try_lex(&self, src: &'a [u8], offset: usize, _: usize) -> Option<(SimpleToken, usize)> {
+/// 		if src.get(offset) != Some(&b'@') { return None; }
+///
+/// 		let end = src[offset..].iter().position(|&byte| byte == b'\n')
+/// 			.map_or(src.len(), |size| offset + size);
+/// 		Some((Token::new_line_comment(offset + 1..end), end))
+/// 	}
+/// }
+///
+/// let plugins: [&dyn LexerPlugin<&[u8], Boundary, Boundaries, SimpleToken>; 1] = [&TagPlugin];
+/// let (token, last_index) = any_with_plugins(&b"@tag"[..], 0, 0, false, &plugins);
+/// assert_eq!(token, Token::new_line_comment(1..4));
+/// assert_eq!(last_index, 4);
+///
+/// let (token, last_index) = any_with_plugins(&b"hello|"[..], 0, 0, false, &plugins);
+/// assert_eq!(token, Token::new_simplex(0..5));
+/// assert_eq!(last_index, 6);
+/// ```
+///
+/// [`any()`]: ./fn.any.html
+pub fn any_with_plugins<T, U, V, W>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	is_in_new_line: bool,
+	plugins: &[&dyn LexerPlugin<T, U, V, W>]
+) -> TokenInfo<W>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	let mut tabbed_offset = offset;
+
+	if is_in_new_line {
+		let (new_tab_count, _) = count_tabs(src.clone().forward_slice(offset), tab_count);
+		if new_tab_count != tab_count {
+			return (W::new_scope_level(new_tab_count), offset + new_tab_count);
+		} else {
+			tabbed_offset += tab_count;
 		}
 	}
+
+	plugins.iter()
+		.find_map(|plugin| plugin.try_lex(src.clone(), tabbed_offset, tab_count))
+		.unwrap_or_else(|| any(src, offset, tab_count, is_in_new_line))
 }
 
-#[cfg(test)]
-mod t {
-	use crate::native::{Range, Vec};
-	use crate::abstracts::AbstractToken;
-	use crate::token::Token;
+/// Returns the info of first recognized token and its probably last seen index in the source or an
+/// error if the last possible lexer has returned an unexpected token.
+///
+/// It works like [`any()`] but it does not panic. The error contains the offset where the token was
+/// being lexed.
+///
+/// ## Notes
+/// Blocks whose closing delimiter has wrong indentation or trailing content are rejected too. The
+/// kind in the error is `MismatchedBlockClose` or `MalformedBlock` respectively.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::{try_any, UnexpectedTokenError};
+/// use chearmyp_token::Token;
+///
+/// let info: Result<(Token<Range<usize>, Vec<Range<usize>>>, usize), UnexpectedTokenError>
+/// 	= try_any(&b"hello"[..], 0, 0, false);
+/// assert_eq!(info, Ok((Token::new_complex(0..5), 5)));
+/// ```
+///
+/// [`any()`]: ./fn.any.html
+pub fn try_any<T, U, V, W>(src: T, offset: usize, tab_count: usize, is_in_new_line: bool)
+-> Result<TokenInfo<W>, UnexpectedTokenError>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	try_any_with_config(src, offset, tab_count, is_in_new_line, &LexerConfig::default())
+}
 
-	use super::any;
+/// Returns the info of first recognized token and its probably last seen index in the source
+/// according to the configuration.
+///
+/// It works like [`any()`] but it needs a lexer configuration as the fifth argument. The
+/// configuration may enable other forms of tokens to be recognized.
+///
+/// ## Notes
+/// May panic if the last possible lexer has returned an unexpected token. Use
+/// [`try_any_with_config()`] to handle it instead.
+///
+/// If a block comment or block othertongue has fewer lines than the minimum, the lines collected
+/// are returned as the block token. If it has more lines than the maximum, the lines collected
+/// before reaching the maximum are returned and the rest of the block is skipped. Use
+/// [`try_any_with_config()`] to reject them.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::{any_with_config, LexerConfig};
+/// use chearmyp_token::Token;
+///
+/// let config = LexerConfig {
+/// 	double_colon_attacher: true,
+/// 	..LexerConfig::default()
+/// };
+/// let (token, last_index): (
+///   Token<Range<usize>, Vec<Range<usize>>>,
+///   usize
+/// ) = any_with_config(&b"hello::world"[..], 0, 0, false, &config);
+/// assert_eq!(token, Token::new_attacher(0..5, 7..12));
+/// assert_eq!(last_index, 12);
+/// ```
+///
+/// [`any()`]: ./fn.any.html
+/// [`try_any_with_config()`]: ./fn.try_any_with_config.html
+pub fn any_with_config<T, U, V, W>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	is_in_new_line: bool,
+	config: &LexerConfig
+) -> TokenInfo<W>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	lex_any(src, offset, tab_count, is_in_new_line, config, |_, _, _, _| {}, &mut None)
+		.expect(UNEXPECTED_TOKEN_MESSAGE)
+}
 
-	macro_rules! test_any {
-		(
-			source: $source:expr,
-			expected token: $token_constructor:ident($($token_content:expr),+),
-			expected last seen index: $last_seen_index:literal
-		) => {
-			test_any!{
-				source: $source,
-				offset: 0,
-				expected token: $token_constructor($($token_content),+),
-				expected last seen index: $last_seen_index
-			}
-		};
-		(
-			source: $source:expr,
-			offset: $offset:literal,
-			expected token: $token_constructor:ident($($token_content:expr),+),
-			expected last seen index: $last_seen_index:literal
-		) => {
-			test_any!{
-				source: $source,
-				offset: $offset,
+/// Returns the info of first recognized token and its probably last seen index in the source
+/// according to the configuration or an error if the last possible lexer has returned an unexpected
+/// token.
+///
+/// It works like [`any_with_config()`] but it does not panic.
+///
+/// ## Notes
+/// Like [`try_any()`], blocks with a mismatched or malformed closing line are rejected. Blocks
+/// which have fewer lines than the minimum or more lines than the maximum are rejected too. The
+/// error contains the offset where the block starts and the kind of the raw token returned by the
+/// block lexer.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::{try_any_with_config, LexerConfig, UnexpectedTokenError};
+/// use chearmyp_token::Token;
+///
+/// let config = LexerConfig {
+/// 	min_block_lines: 1,
+/// 	..LexerConfig::default()
+/// };
+/// let info: Result<(Token<Range<usize>, Vec<Range<usize>>>, usize), UnexpectedTokenError>
+/// 	= try_any_with_config(&b"===\n==="[..], 0, 0, false, &config);
+/// assert_eq!(info, Err(UnexpectedTokenError {
+/// 	byte_offset: 0,
+/// 	raw_token_kind_debug: "BlockTooShort".to_string()
+/// }));
+/// ```
+///
+/// [`any_with_config()`]: ./fn.any_with_config.html
+/// [`try_any()`]: ./fn.try_any.html
+pub fn try_any_with_config<T, U, V, W>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	is_in_new_line: bool,
+	config: &LexerConfig
+) -> Result<TokenInfo<W>, UnexpectedTokenError>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	match any_with_diagnostic(src, offset, tab_count, is_in_new_line, config)? {
+		(_, Some(error)) => Err(error),
+		(info, None) => Ok(info)
+	}
+}
+
+/// Returns the info of first recognized token according to the configuration and the boundaries of
+/// the items in its content if it is a list attacher.
+///
+/// It works like [`any_with_config()`] but it also returns the items recognized by
+/// [`list_attacher()`] if the list values are configured. The token still has the whole content
+/// since the tokens do not have items.
+///
+/// ## Notes
+/// The items will be `None` if the token is not an attacher, if the list values are not
+/// configured, or if the label is namespaced while the namespaced labels are configured.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::LexerConfig;
+/// use chearmyp_lexer::secondary_lexers::any_with_list_items;
+/// use chearmyp_token::Token;
+///
+/// let config = LexerConfig {
+/// 	list_values: Some(b','),
+/// 	..LexerConfig::default()
+/// };
+/// let ((token, last_index), items): (
+///   (Token<Range<usize>, Vec<Range<usize>>>, usize),
+///   Option<Vec<Range<usize>>>
+/// ) = any_with_list_items(&b"ports:\t80, 443"[..], 0, 0, false, &config);
+/// assert_eq!(token, Token::new_attacher(0..5, 7..14));
+/// assert_eq!(last_index, 14);
+/// assert_eq!(items, Some(vec![7..9, 11..14]));
+/// ```
+///
+/// [`any_with_config()`]: ./fn.any_with_config.html
+/// [`list_attacher()`]: ../primary_lexers/fn.list_attacher.html
+pub fn any_with_list_items<T, U, V, W>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	is_in_new_line: bool,
+	config: &LexerConfig
+) -> (TokenInfo<W>, Option<V>)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	let mut list_items = None;
+	let on_lexed = |_, _, _: &RawToken<U, V>, _| {};
+	let info = lex_any(src, offset, tab_count, is_in_new_line, config, on_lexed, &mut list_items)
+		.expect(UNEXPECTED_TOKEN_MESSAGE);
+	let list_items = if W::kind(&info.0) == TokenKind::Attacher { list_items } else { None };
+	(info, list_items)
+}
+
+/// Contains the info of a token and the error for it if it is a rejected block.
+pub type DiagnosedTokenInfo<W> = (TokenInfo<W>, Option<UnexpectedTokenError>);
+
+/// Returns the info of first recognized token like [`any_with_config()`] along with the error that
+/// [`try_any_with_config()`] would return for it if it is a rejected block.
+///
+/// [`any_with_config()`]: ./fn.any_with_config.html
+/// [`try_any_with_config()`]: ./fn.try_any_with_config.html
+pub fn any_with_diagnostic<T, U, V, W>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	is_in_new_line: bool,
+	config: &LexerConfig
+) -> Result<DiagnosedTokenInfo<W>, UnexpectedTokenError>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	let mut diagnostic = None;
+	let diagnose = |_, lexed_offset, raw_token: &RawToken<U, V>, _| {
+		if is_rejected_block(raw_token) {
+			diagnostic = Some(UnexpectedTokenError {
+				byte_offset: lexed_offset,
+				raw_token_kind_debug: format!("{:?}", raw_token.kind())
+			});
+		}
+	};
+
+	let info = lex_any(src, offset, tab_count, is_in_new_line, config, diagnose, &mut None)?;
+	Ok((info, diagnostic))
+}
+
+/// Returns the info of first recognized token and its probably last seen index in the source while
+/// passing the invalid raw tokens to a handler.
+///
+/// It works like [`any()`] but it needs a closure as the fifth argument (known as handler). The
+/// handler is called with the offset where a primary lexer started and the invalid raw token that
+/// it returned. The caller may log them, accumulate them as errors, or panic.
+///
+/// ## Notes
+/// The primary lexers return invalid raw tokens while the other lexers are still being tried.
+/// Therefore, the handler may be called even if a token has been recognized at the end.
+///
+/// Like [`any()`], it panics if the last possible lexer has returned an unexpected token. The
+/// handler is called before that if the unexpected token is invalid.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::RawToken;
+/// use chearmyp_lexer::secondary_lexers::any_with_handler;
+/// use chearmyp_token::Token;
+///
+/// let mut invalid_offsets = Vec::new();
+/// let (token, last_index): (
+///   Token<Range<usize>, Vec<Range<usize>>>,
+///   usize
+/// ) = any_with_handler(&b"hello|"[..], 0, 0, false, |offset, raw_token| {
+/// 	assert_eq!(raw_token, &RawToken::Invalid);
+/// 	invalid_offsets.push(offset);
+/// });
+/// assert_eq!(token, Token::new_simplex(0..5));
+/// assert_eq!(last_index, 6);
+/// assert_eq!(invalid_offsets, vec![0]);
+/// ```
+///
+/// [`any()`]: ./fn.any.html
+pub fn any_with_handler<T, U, V, W, F>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	is_in_new_line: bool,
+	mut on_error: F
+) -> TokenInfo<W>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	F: FnMut(usize, &RawToken<U, V>) {
+	let config = LexerConfig::default();
+	let handle = |_, lexed_offset, raw_token: &RawToken<U, V>, _| {
+		if let RawToken::Invalid = raw_token {
+			on_error(lexed_offset, raw_token);
+		}
+	};
+
+	lex_any(src, offset, tab_count, is_in_new_line, &config, handle, &mut None)
+		.expect(UNEXPECTED_TOKEN_MESSAGE)
+}
+
+/// Returns the info of first recognized token and its probably last seen index in the source while
+/// recording the lexers that were tried.
+///
+/// It works like [`any()`] but it needs a vector as the fifth argument where the lexers tried are
+/// pushed in order as trace events. It is available if the `trace` feature is enabled so it can be
+/// used in place of [`any()`] while debugging.
+///
+/// ## Notes
+/// The lexer names are the names of the functions called by [`any()`]. Some of them choose the
+/// primary lexer according to the configuration, like `configured_attacher`.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::{InvalidReason, TraceEvent, TraceResult};
+/// use chearmyp_lexer::secondary_lexers::any_traced;
+/// use chearmyp_token::Token;
+///
+/// let mut trace = Vec::new();
+/// let (token, last_index): (
+///   Token<Range<usize>, Vec<Range<usize>>>,
+///   usize
+/// ) = any_traced(&b"hello|"[..], 0, 0, false, &mut trace);
+/// assert_eq!(token, Token::new_simplex(0..5));
+/// assert_eq!(last_index, 6);
+/// assert_eq!(trace, vec![
+/// 	TraceEvent {
+/// 		lexer_name: "configured_attacher",
+/// 		result: TraceResult::Invalid { reason: InvalidReason::Unrecognized }
+/// 	},
+/// 	TraceEvent { lexer_name: "simplex", result: TraceResult::Matched }
+/// ]);
+/// ```
+///
+/// [`any()`]: ./fn.any.html
+#[cfg(feature = "trace")]
+pub fn any_traced<T, U, V, W>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	is_in_new_line: bool,
+	trace: &mut Vec<TraceEvent>
+) -> TokenInfo<W>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	let config = LexerConfig::default();
+	let record = |lexer_name, _, raw_token: &RawToken<U, V>, is_matched| {
+		let result = if is_matched {
+			TraceResult::Matched
+		} else {
+			TraceResult::Invalid { reason: InvalidReason::from(raw_token) }
+		};
+		trace.push(TraceEvent { lexer_name, result });
+	};
+
+	lex_any(src, offset, tab_count, is_in_new_line, &config, record, &mut None)
+		.expect(UNEXPECTED_TOKEN_MESSAGE)
+}
+
+fn lex_any<T, U, V, W, F>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	is_in_new_line: bool,
+	config: &LexerConfig,
+	mut on_lexed: F,
+	list_items: &mut Option<V>
+) -> Result<TokenInfo<W>, UnexpectedTokenError>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	F: FnMut(&'static str, usize, &RawToken<U, V>, bool) {
+	let mut tabbed_offset = offset;
+	let indentation_width = config.indentation_width();
+
+	if is_in_new_line && indentation_width > 0 {
+		let line = src.clone().forward_slice(offset);
+		let new_tab_count = count_tabs_with_width(line.clone(), tab_count, indentation_width);
+		if new_tab_count != tab_count {
+			let indentation_end = find_indentation_end(&line, new_tab_count, indentation_width);
+			return Ok((W::new_scope_level(new_tab_count), offset + indentation_end));
+		} else {
+			tabbed_offset += find_indentation_end(&line, tab_count, indentation_width);
+		}
+	} else if is_in_new_line {
+		let (new_tab_count, _) = count_tabs(src.clone().forward_slice(offset), tab_count);
+		if new_tab_count != tab_count {
+			return Ok((W::new_scope_level(new_tab_count), offset + new_tab_count));
+		} else {
+			tabbed_offset += tab_count;
+		}
+	}
+
+	let mut offset = tabbed_offset;
+	let mut raw_token;
+
+	macro_rules! lex {
+		(
+			$parser:ident$(($($other_argument:tt),+))?
+			$(unless $raw_token:ident($($content:tt),+) turns into $new_token:ident => $block:block)?
+			$(
+				which expects
+					$expected_raw_token:ident($($expected_content:tt),+)
+					turning into $expected_new_token:ident
+			)?
+		) => {
+			let lexed_offset = offset;
+			let info = $parser(src.clone(), offset, $($($other_argument,)*)?);
+			raw_token = info.0;
+			offset = info.1;
+			$(
+				let is_matched = matches!(raw_token, RawToken::$raw_token(..));
+				on_lexed(stringify!($parser), lexed_offset, &raw_token, is_matched);
+				if let RawToken::$raw_token($($content,)+) = raw_token {
+					let token = W::$new_token($($content,)+);
+					Ok((token, offset))
+				} else $block
+			)?
+			$(
+				let is_matched = matches!(raw_token, RawToken::$expected_raw_token(..));
+				on_lexed(stringify!($parser), lexed_offset, &raw_token, is_matched);
+				if let RawToken::$expected_raw_token($($expected_content,)+) = raw_token {
+					let token = W::$expected_new_token($($expected_content,)+);
+					Ok((token, offset))
+				} else {
+					Err(UnexpectedTokenError {
+						byte_offset: tabbed_offset,
+						raw_token_kind_debug: format!("{:?}", raw_token.kind())
+					})
+				}
+			)?
+		};
+	}
+
+	if src.is_same_needle_at(offset, POUND_SIGN) {
+		lex!{
+			configured_block_comment(tab_count, config)
+			unless BlockComment(comment) turns into new_block_comment => {
+				if let RawToken::MismatchedBlockClose { lines, .. }
+				| RawToken::UnclosedBlockComment(lines)
+				| RawToken::BlockTooLong { lines_collected: lines, .. }
+				| RawToken::BlockTooShort { lines, .. }
+				| RawToken::MalformedBlock(lines) = raw_token {
+					Ok((W::new_block_comment(lines), offset))
+				} else {
+					lex!{ line_comment which expects LineComment(comment) turning into new_line_comment }
+				}
+			}
+		}
+	} else if src.is_same_needle_at(offset, SPACE_THEN_EQUAL)
+	&& src.is_same_needle_at(offset + 1, EQUAL_THEN_SPACE) {
+		offset += 1;
+		lex!{
+			line_othertongue
+			which expects LineOthertongue(othertongue)
+			turning into new_line_othertongue
+		}
+	} else if src.is_same_needle_at(offset, EQUAL) {
+		lex!{
+			configured_block_othertongue(tab_count, config)
+			unless BlockOthertongue(othertongue) turns into new_block_othertongue => {
+				if let RawToken::MismatchedBlockClose { lines, .. }
+				| RawToken::UnclosedBlockOthertongue(lines)
+				| RawToken::BlockTooLong { lines_collected: lines, .. }
+				| RawToken::BlockTooShort { lines, .. }
+				| RawToken::MalformedBlock(lines) = raw_token {
+					return Ok((W::new_block_othertongue(lines), offset));
+				}
+
+				lex!{
+					line_othertongue
+					unless LineOthertongue(othertongue) turns into new_line_othertongue => {
+						lex!{
+							configured_attacher(offset, config, list_items)
+							unless Attacher(label, content) turns into new_attacher => {
+								let search_offset = if offset > tabbed_offset {
+									offset - 1
+								} else {
+									tabbed_offset
+								};
+								let slice_start_offset = tabbed_offset;
+								offset = slice_start_offset;
+
+								lex!{
+									simplex(search_offset)
+									unless Simplex(concept) turns into new_simplex => {
+										// Inlined othertongues may be skipped by simplex lexer.
+										let search_offset = tabbed_offset;
+										let slice_start_offset = tabbed_offset;
+										offset = slice_start_offset;
+										lex!{
+											complex(search_offset)
+											which expects Complex(concept)
+											turning into new_complex
+										}
+									}
+								}
+							}
+						}
+					}
+				}
+			}
+		}
+	} else {
+		lex!{
+			configured_attacher(offset, config, list_items)
+			unless Attacher(label, content) turns into new_attacher => {
+				let search_offset = if offset > tabbed_offset {
+					offset - 1
+				} else {
+					tabbed_offset
+				};
+				let slice_start_offset = tabbed_offset;
+				offset = slice_start_offset;
+
+				lex!{
+					simplex(search_offset)
+					unless Simplex(concept) turns into new_simplex => {
+						// Inlined othertongues may be skipped by simplex lexer.
+						let search_offset = tabbed_offset;
+						let slice_start_offset = tabbed_offset;
+						offset = slice_start_offset;
+						lex!{
+							complex(search_offset)
+							which expects Complex(concept)
+							turning into new_complex
+						}
+					}
+				}
+			}
+		}
+	}
+}
+
+/// Returns true if the raw token is a block which is only kept by the lenient lexers.
+fn is_rejected_block<U, V>(raw_token: &RawToken<U, V>) -> bool
+where
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	matches!(
+		raw_token,
+		RawToken::MismatchedBlockClose { .. }
+		| RawToken::MalformedBlock(_)
+		| RawToken::BlockTooLong { .. }
+		| RawToken::BlockTooShort { .. }
+	)
+}
+
+fn configured_attacher<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	config: &LexerConfig,
+	list_items: &mut Option<V>
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	if config.strict_labels {
+		let info = attacher_strict(src.clone(), slice_offset, slice_offset);
+		// Other invalid attachers may still be recognized by the other configured attachers.
+		if let RawToken::InvalidLabel(_) = info.0 {
+			return info;
+		}
+	}
+
+	if let Some(namespace_sep) = config.namespaced_labels {
+		let info = attacher_namespaced(src.clone(), slice_offset, search_offset, namespace_sep);
+		match info {
+			(RawToken::NamespacedAttacher(_, _), _) => {
+				// The tokens cannot keep the segments so the attacher is lexed again.
+				return usual_attacher(src, slice_offset, search_offset, config);
+			},
+			// Only an empty segment is reported. Other attachers are tried if there is no colon.
+			(RawToken::Invalid, _)
+			if is_usual_attacher::<T, U, V>(&src, slice_offset, search_offset) => return info,
+			_ => {}
+		}
+	}
+
+	if config.dotted_labels {
+		let info = dotted_attacher(src.clone(), slice_offset, search_offset);
+		match info {
+			(RawToken::DottedAttacher { .. }, _) => {
+				// The tokens cannot keep the segments so the attacher is lexed again.
+				return usual_attacher(src, slice_offset, search_offset, config);
+			},
+			// Only an empty segment is reported. Other attachers are tried if there is no colon.
+			(RawToken::Invalid, _)
+			if is_usual_attacher::<T, U, V>(&src, slice_offset, search_offset) => return info,
+			_ => {}
+		}
+	}
+
+	if let Some(item_sep) = config.list_values {
+		match list_attacher(src.clone(), slice_offset, search_offset, item_sep) {
+			(RawToken::ListAttacher(_, items), _) => {
+				// The tokens cannot keep the items so they are returned separately.
+				*list_items = Some(items);
+				return usual_attacher(src, slice_offset, search_offset, config);
+			},
+			// Only an empty item is reported. Other attachers are tried if there is no usual one.
+			info @ (RawToken::Invalid, _)
+			if is_usual_attacher::<T, U, V>(&src, slice_offset, search_offset) => return info,
+			_ => {}
+		}
+	}
+
+	if config.double_colon_attacher {
+		let info = attacher_double_colon(src.clone(), slice_offset, search_offset);
+		if let RawToken::Attacher(_, _) = info.0 {
+			return info;
+		}
+	}
+
+	if config.arrow_attacher {
+		let info = attacher_arrow(src.clone(), slice_offset, search_offset);
+		if let RawToken::Attacher(_, _) = info.0 {
+			return info;
+		}
+	}
+
+	if config.semicolon_attacher {
+		let info = attacher_semicolon(src.clone(), slice_offset, search_offset);
+		if let RawToken::Attacher(_, _) = info.0 {
+			return info;
+		}
+	}
+
+	usual_attacher(src, slice_offset, search_offset, config)
+}
+
+/// Returns true if the plain attacher is recognized. The attachers built on it return its invalid
+/// raw token as is, so it tells apart their own invalid raw tokens.
+fn is_usual_attacher<T, U, V>(src: &T, slice_offset: usize, search_offset: usize) -> bool
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize> {
+	matches!(
+		attacher::<T, U, V>(src.clone(), slice_offset, search_offset).0,
+		RawToken::Attacher(_, _)
+	)
+}
+
+fn usual_attacher<T, U, V>(
+	src: T,
+	slice_offset: usize,
+	search_offset: usize,
+	config: &LexerConfig
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize> {
+	if config.inline_comments {
+		attacher_with_comment(src, slice_offset, search_offset, config.trim_attacher_content)
+	} else if config.trim_attacher_content {
+		attacher_trimmed(src, slice_offset, search_offset)
+	} else {
+		attacher(src, slice_offset, search_offset)
+	}
+}
+
+fn configured_block_comment<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	config: &LexerConfig
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	if config.nested_block_comments {
+		let info = block_comment_nested_with_width(
+			src.clone(),
+			offset,
+			tab_count,
+			config.indentation_width()
+		);
+		return keep_trailing_new_line(&src, info, config);
+	}
+
+	let info = block_comment_bounded(
+		src.clone(),
+		offset,
+		tab_count,
+		config.min_block_lines,
+		config.max_block_lines,
+		config.indentation_width()
+	);
+	let info = skip_rest_of_block(&src, offset, tab_count, POUND_SIGN, info, config);
+	keep_trailing_new_line(&src, info, config)
+}
+
+fn configured_block_othertongue<T, U, V>(
+	src: T,
+	offset: usize,
+	tab_count: usize,
+	config: &LexerConfig
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U> {
+	if let Some(delimiter_count) = config.othertongue_delimiter_count {
+		let mut equal_sign_count = 0;
+		while src.is_same_needle_at(offset + equal_sign_count, EQUAL) { equal_sign_count += 1; }
+		if equal_sign_count != delimiter_count {
+			return (RawToken::Invalid, offset);
+		}
+	}
+
+	let info = match tagged_block_othertongue_bounded(
+		src.clone(),
+		offset,
+		tab_count,
+		config.min_block_lines,
+		config.max_block_lines,
+		config.indentation_width()
+	) {
+		// The tokens do not have tags so the tag is dropped.
+		(RawToken::TaggedBlockOthertongue { lines, .. }, offset) => {
+			(RawToken::BlockOthertongue(lines), offset)
+		},
+		(RawToken::Invalid, _) => block_othertongue_bounded(
+			src.clone(),
+			offset,
+			tab_count,
+			config.min_block_lines,
+			config.max_block_lines,
+			config.indentation_width()
+		),
+		info => info
+	};
+	let info = skip_rest_of_block(&src, offset, tab_count, EQUAL, info, config);
+	keep_trailing_new_line(&src, info, config)
+}
+
+/// Moves the last seen index of a block which has more lines than the maximum after its closing
+/// line. The lines after the maximum are not collected but they are still part of the block.
+fn skip_rest_of_block<T, U, V>(
+	src: &T,
+	offset: usize,
+	tab_count: usize,
+	special_character: &'static str,
+	info: RawTokenInfo<U, V>,
+	config: &LexerConfig
+) -> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone {
+	match info {
+		(raw_token @ RawToken::BlockTooLong { .. }, last_seen_index) => {
+			let block_end = block_end(
+				src.clone(),
+				offset,
+				tab_count,
+				special_character,
+				config.indentation_width()
+			);
+			(raw_token, block_end.unwrap_or(last_seen_index))
+		},
+		info => info
+	}
+}
+
+fn keep_trailing_new_line<T, U, V>(src: &T, info: RawTokenInfo<U, V>, config: &LexerConfig)
+-> RawTokenInfo<U, V>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let (raw_token, last_seen_index) = info;
+	let is_closed = matches!(
+		raw_token,
+		RawToken::BlockComment(_)
+		| RawToken::MetaBlockComment { .. }
+		| RawToken::BlockCommentWithHeader { .. }
+		| RawToken::BlockOthertongue(_)
+		| RawToken::MismatchedBlockClose { .. }
+		| RawToken::MalformedBlock(_)
+		| RawToken::BlockTooLong { .. }
+		| RawToken::BlockTooShort { .. }
+	);
+
+	// Closed blocks only end in a new line if the new line after the closing line was consumed.
+	if !config.block_consume_trailing_newline
+	&& is_closed
+	&& last_seen_index > 0
+	&& src.is_same_needle_at(last_seen_index - 1, NEW_LINE) {
+		(raw_token, last_seen_index - 1)
+	} else {
+		(raw_token, last_seen_index)
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, String, Vec};
+	use crate::abstracts::AbstractToken;
+	use crate::token::Token;
+	use crate::lexer_config::LexerConfig;
+
+	use crate::token::TokenKind;
+
+	use crate::lexer_plugin::LexerPlugin;
+	use crate::unexpected_token_error::UnexpectedTokenError;
+
+	use super::{
+		any,
+		any_hinted,
+		any_or_end,
+		any_spanned,
+		any_with_config,
+		any_with_handler,
+		any_with_list_items,
+		any_with_plugins,
+		try_any,
+		try_any_with_config
+	};
+
+	type SimpleInfo = (Token<Range<usize>, Vec<Range<usize>>>, usize);
+	type SpannedInfo = (Token<Range<usize>, Vec<Range<usize>>>, Range<usize>);
+
+	macro_rules! test_any {
+		(
+			source: $source:expr,
+			config: $config:expr,
+			expected token: $token_constructor:ident($($token_content:expr),+),
+			expected last seen index: $last_seen_index:literal
+		) => {
+			let result = try_any_with_config::<
+				&[u8],
+				Range<usize>,
+				Vec<Range<usize>>,
+				Token<Range<usize>, Vec<Range<usize>>>
+			>(
+				&&$source[..],
+				0,
+				0,
+				false,
+				&$config
+			);
+			assert_eq!(result, Ok((
+				Token::<Range<usize>, Vec<Range<usize>>>::$token_constructor(
+					$($token_content),+
+				),
+				$last_seen_index
+			)));
+		};
+		(
+			source: $source:expr,
+			expected token: $token_constructor:ident($($token_content:expr),+),
+			expected last seen index: $last_seen_index:literal
+		) => {
+			test_any!{
+				source: $source,
+				offset: 0,
+				expected token: $token_constructor($($token_content),+),
+				expected last seen index: $last_seen_index
+			}
+		};
+		(
+			source: $source:expr,
+			offset: $offset:literal,
+			expected token: $token_constructor:ident($($token_content:expr),+),
+			expected last seen index: $last_seen_index:literal
+		) => {
+			test_any!{
+				source: $source,
+				offset: $offset,
 				tab count: 0,
 				is in new line: false,
 				expected token: $token_constructor($($token_content),+),
@@ -219,12 +1218,12 @@ mod t {
 				offset: $offset,
 				tab count: $tab_count,
 				is in new line: $is_in_new_line,
-				info: (
+				result: Ok((
 					Token::<Range<usize>, Vec<Range<usize>>>::$token_constructor(
 						$($token_content),+
 					),
 					$last_seen_index
-				)
+				))
 			}
 		};
 		(
@@ -232,9 +1231,9 @@ mod t {
 			offset: $offset:literal,
 			tab count: $tab_count:literal,
 			is in new line: $is_in_new_line:literal,
-			info: $expected_info:expr
+			result: $expected_result:expr
 		) => {
-			let info = any::<
+			let result = try_any::<
 				&[u8],
 				Range<usize>,
 				Vec<Range<usize>>,
@@ -245,106 +1244,839 @@ mod t {
 				$tab_count,
 				$is_in_new_line
 			);
-			assert_eq!(info, $expected_info);
+			assert_eq!(result, $expected_result);
 		};
 	}
 
 	#[test]
-	fn can_lex_line_comment() {
+	fn can_lex_line_comment() {
+		test_any!(
+			source: b"#abc",
+			expected token: new_line_comment(1..4),
+			expected last seen index: 4
+		);
+	}
+
+	#[test]
+	fn can_lex_empty_line_comment() {
+		test_any!(
+			source: b"#",
+			expected token: new_line_comment(1..1),
+			expected last seen index: 1
+		);
+	}
+
+	#[test]
+	fn can_lex_block_comment() {
+		let mut expected_lines = Vec::new();
+		expected_lines.push(4..7);
+
+		test_any!(
+			source: b"###\n\tde\n###",
+			expected token: new_block_comment(expected_lines),
+			expected last seen index: 11
+		);
+	}
+
+	#[test]
+	fn can_lex_block_comment_with_mismatched_close_leniently() {
+		let source = &b"\t###\n\t\ta\n\t\tb\n###\nc"[..];
+
+		let (token, last_seen_index): SimpleInfo = any(source, 1, 1, false);
+
+		assert_eq!(token, Token::new_block_comment(vec![5..8, 9..12]));
+		assert_eq!(last_seen_index, 17);
+		test_any!(
+			source: source,
+			offset: 1,
+			tab count: 1,
+			is in new line: false,
+			result: Err(UnexpectedTokenError {
+				byte_offset: 1,
+				raw_token_kind_debug: String::from("MismatchedBlockClose")
+			})
+		);
+	}
+
+	#[test]
+	fn can_lex_simplex() {
+		test_any!(
+			source: b"efg|",
+			expected token: new_simplex(0..3),
+			expected last seen index: 4
+		);
+	}
+
+	#[test]
+	fn can_lex_complex() {
+		test_any!(
+			source: b"hi",
+			expected token: new_complex(0..2),
+			expected last seen index: 2
+		);
+	}
+
+	#[test]
+	fn cannot_lex_at_end_of_source() {
+		type Info = Option<(Token<Range<usize>, Vec<Range<usize>>>, usize)>;
+
+		let info: Info = any_or_end(&b""[..], 0, 0, false);
+		assert_eq!(info, None);
+
+		let info: Info = any_or_end(&b"a\n"[..], 2, 0, true);
+		assert_eq!(info, None);
+
+		let info: Info = any_or_end(&b"a\n\tb|"[..], 2, 0, true);
+		assert_eq!(info, Some((Token::new_scope_level(1), 3)));
+	}
+
+	#[test]
+	fn can_lex_attacher() {
+		test_any!(
+			source: b"jklm:\tn",
+			expected token: new_attacher(0..4, 6..7),
+			expected last seen index: 7
+		);
+	}
+
+	#[test]
+	fn can_lex_line_othertongue() {
+		test_any!(
+			source: b"= o",
+			expected token: new_line_othertongue(2..3),
+			expected last seen index: 3
+		);
+	}
+
+	#[test]
+	fn can_lex_line_othertongue_with_equal_sign() {
+		test_any!(
+			source: b"= a=b",
+			expected token: new_line_othertongue(2..5),
+			expected last seen index: 5
+		);
+	}
+
+	#[test]
+	fn can_lex_line_othertongue_after_space() {
+		test_any!(
+			source: b" = o",
+			expected token: new_line_othertongue(3..4),
+			expected last seen index: 4
+		);
+	}
+
+	#[test]
+	fn can_lex_line_othertongue_after_tab_and_space() {
+		test_any!(
+			source: b"\t = pq",
+			offset: 0,
+			tab count: 1,
+			is in new line: true,
+			expected token: new_line_othertongue(4..6),
+			expected last seen index: 6
+		);
+	}
+
+	#[test]
+	fn can_lex_invalid_line_othertongue_into_complex() {
+		test_any!(
+			source: b"=o",
+			expected token: new_complex(0..2),
+			expected last seen index: 2
+		);
+	}
+
+	#[test]
+	fn can_lex_block_othertongue() {
+		let mut expected_lines = Vec::new();
+		expected_lines.push(4..8);
+
+		test_any!(
+			source: b"===\npqrs\n===",
+			expected token: new_block_othertongue(expected_lines),
+			expected last seen index: 12
+		);
+	}
+
+	#[test]
+	fn can_lex_tagged_block_othertongue_by_dropping_tag() {
 		test_any!(
-			source: b"#abc",
-			expected token: new_line_comment(1..4),
-			expected last seen index: 4
+			source: b"=== tag\nx\ny\n===",
+			expected token: new_block_othertongue(vec![8..9, 10..11]),
+			expected last seen index: 15
 		);
 	}
 
 	#[test]
-	fn can_lex_empty_line_comment() {
+	fn can_lex_block_othertongue_with_malformed_closing_line_leniently() {
+		let source = &b"===\np\nq\n===t\nu"[..];
+
+		let (token, last_seen_index): SimpleInfo = any(source, 0, 0, false);
+
+		assert_eq!(token, Token::new_block_othertongue(vec![4..5, 6..7]));
+		assert_eq!(last_seen_index, 13);
 		test_any!(
-			source: b"#",
-			expected token: new_line_comment(1..1),
-			expected last seen index: 1
+			source: source,
+			offset: 0,
+			tab count: 0,
+			is in new line: false,
+			result: Err(UnexpectedTokenError {
+				byte_offset: 0,
+				raw_token_kind_debug: String::from("MalformedBlock")
+			})
 		);
 	}
 
 	#[test]
-	fn can_lex_block_comment() {
-		let mut expected_lines = Vec::new();
-		expected_lines.push(4..7);
+	fn can_lex_unclosed_block_comment() {
+		test_any!(
+			source: b"###\nab\ncd",
+			expected token: new_block_comment(vec![4..6, 7..9]),
+			expected last seen index: 10
+		);
+	}
 
+	#[test]
+	fn can_lex_unclosed_block_othertongue() {
 		test_any!(
-			source: b"###\n\tde\n###",
-			expected token: new_block_comment(expected_lines),
-			expected last seen index: 11
+			source: b"===\nef\ngh",
+			expected token: new_block_othertongue(vec![4..6, 7..9]),
+			expected last seen index: 10
 		);
 	}
 
 	#[test]
-	fn can_lex_simplex() {
+	fn can_lex_indented_attacher_from_new_line() {
 		test_any!(
-			source: b"efg|",
-			expected token: new_simplex(0..3),
+			source: b"	tuvw:	x",
+			offset: 0,
+			tab count: 1,
+			is in new line: true,
+			expected token: new_attacher(1..5, 7..8),
+			expected last seen index: 8
+		);
+	}
+
+	#[test]
+	fn can_lex_double_colon_attacher_if_configured() {
+		test_any!(
+			source: b"yz::a",
+			config: LexerConfig {
+				double_colon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..2, 4..5),
+			expected last seen index: 5
+		);
+	}
+
+	#[test]
+	fn can_lex_usual_attacher_if_double_colon_attacher_is_configured() {
+		test_any!(
+			source: b"bc:\td",
+			config: LexerConfig {
+				double_colon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..2, 4..5),
+			expected last seen index: 5
+		);
+	}
+
+	#[test]
+	fn can_lex_arrow_attacher_if_configured() {
+		test_any!(
+			source: b"e -> f",
+			config: LexerConfig {
+				arrow_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..1, 5..6),
+			expected last seen index: 6
+		);
+	}
+
+	#[test]
+	fn can_lex_semicolon_attacher_if_configured() {
+		test_any!(
+			source: b"v; w",
+			config: LexerConfig {
+				semicolon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..1, 3..4),
 			expected last seen index: 4
 		);
 	}
 
 	#[test]
-	fn can_lex_complex() {
+	fn can_lex_block_comment_without_trailing_new_line_if_configured() {
 		test_any!(
-			source: b"hi",
-			expected token: new_complex(0..2),
-			expected last seen index: 2
+			source: b"###\na\nb\n###\nc",
+			config: LexerConfig {
+				block_consume_trailing_newline: false,
+				..LexerConfig::default()
+			},
+			expected token: new_block_comment(vec![4..5, 6..7]),
+			expected last seen index: 11
 		);
 	}
 
 	#[test]
-	fn can_lex_attacher() {
+	fn can_lex_block_othertongue_without_trailing_new_line_if_configured() {
 		test_any!(
-			source: b"jklm:\tn",
-			expected token: new_attacher(0..4, 6..7),
+			source: b"===\na\nb\n===\nc",
+			config: LexerConfig {
+				block_consume_trailing_newline: false,
+				..LexerConfig::default()
+			},
+			expected token: new_block_othertongue(vec![4..5, 6..7]),
+			expected last seen index: 11
+		);
+	}
+
+	#[cfg(feature = "trace")]
+	#[test]
+	fn can_trace_lexers_tried() {
+		use crate::trace_event::{InvalidReason, TraceEvent, TraceResult};
+		use super::any_traced;
+
+		let mut trace = Vec::new();
+		let (token, last_seen_index): (Token<Range<usize>, Vec<Range<usize>>>, usize)
+			= any_traced(&b"#a"[..], 0, 0, false, &mut trace);
+
+		assert_eq!(token, Token::new_line_comment(1..2));
+		assert_eq!(last_seen_index, 2);
+		assert_eq!(trace, vec![
+			TraceEvent {
+				lexer_name: "configured_block_comment",
+				result: TraceResult::Invalid { reason: InvalidReason::Unrecognized }
+			},
+			TraceEvent { lexer_name: "line_comment", result: TraceResult::Matched }
+		]);
+	}
+
+	#[test]
+	fn can_lex_attacher_with_ambiguous_label_if_not_strict() {
+		test_any!(
+			source: b"|x: y",
+			config: LexerConfig::default(),
+			expected token: new_attacher(0..2, 4..5),
+			expected last seen index: 5
+		);
+	}
+
+	#[test]
+	fn cannot_lex_attacher_with_ambiguous_label_if_strict() {
+		test_any!(
+			source: b"|x: y",
+			config: LexerConfig {
+				strict_labels: true,
+				..LexerConfig::default()
+			},
+			expected token: new_complex(0..5),
+			expected last seen index: 5
+		);
+	}
+
+	#[test]
+	fn can_lex_list_attacher_if_configured() {
+		test_any!(
+			source: b"a: b, c",
+			config: LexerConfig {
+				list_values: Some(b','),
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..1, 3..7),
 			expected last seen index: 7
 		);
 	}
 
 	#[test]
-	fn can_lex_line_othertongue() {
+	fn can_lex_list_items_if_configured() {
+		type ListInfo = (SimpleInfo, Option<Vec<Range<usize>>>);
+		let config = LexerConfig {
+			list_values: Some(b','),
+			..LexerConfig::default()
+		};
+
+		let (info, items): ListInfo = any_with_list_items(&b"\ta:\tb, c"[..], 0, 1, true, &config);
+		assert_eq!(info, (Token::new_attacher(1..2, 4..8), 8));
+		assert_eq!(items, Some(vec![4..5, 7..8]));
+
+		let (info, items): ListInfo = any_with_list_items(&b"d, e"[..], 0, 0, false, &config);
+		assert_eq!(info, (Token::new_complex(0..4), 4));
+		assert_eq!(items, None);
+
+		let config = LexerConfig::default();
+		let (info, items): ListInfo = any_with_list_items(&b"f:\tg, h"[..], 0, 0, false, &config);
+		assert_eq!(info, (Token::new_attacher(0..1, 3..7), 7));
+		assert_eq!(items, None);
+	}
+
+	#[test]
+	fn cannot_lex_list_attacher_with_empty_item_if_configured() {
 		test_any!(
-			source: b"= o",
-			expected token: new_line_othertongue(2..3),
-			expected last seen index: 3
+			source: b"a: b,, c",
+			config: LexerConfig {
+				list_values: Some(b','),
+				..LexerConfig::default()
+			},
+			expected token: new_complex(0..8),
+			expected last seen index: 8
 		);
 	}
 
 	#[test]
-	fn can_lex_invalid_line_othertongue_into_complex() {
+	fn can_lex_other_attachers_if_list_values_are_configured() {
 		test_any!(
-			source: b"=o",
-			expected token: new_complex(0..2),
-			expected last seen index: 2
+			source: b"a::b,c",
+			config: LexerConfig {
+				list_values: Some(b','),
+				double_colon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..1, 3..6),
+			expected last seen index: 6
+		);
+		test_any!(
+			source: b"d -> e, f",
+			config: LexerConfig {
+				list_values: Some(b','),
+				arrow_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..1, 5..9),
+			expected last seen index: 9
+		);
+		test_any!(
+			source: b"g; h, i",
+			config: LexerConfig {
+				list_values: Some(b','),
+				semicolon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..1, 3..7),
+			expected last seen index: 7
 		);
 	}
 
 	#[test]
-	fn can_lex_block_othertongue() {
-		let mut expected_lines = Vec::new();
-		expected_lines.push(4..8);
+	fn cannot_lex_list_attacher_with_empty_item_by_other_attachers() {
+		test_any!(
+			source: b"j: k,, l",
+			config: LexerConfig {
+				list_values: Some(b','),
+				arrow_attacher: true,
+				semicolon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_complex(0..8),
+			expected last seen index: 8
+		);
+	}
 
+	#[test]
+	fn can_lex_nested_block_comment_if_configured() {
 		test_any!(
-			source: b"===\npqrs\n===",
-			expected token: new_block_othertongue(expected_lines),
-			expected last seen index: 12
+			source: b"###\n\t###\n###\n###",
+			config: LexerConfig {
+				nested_block_comments: true,
+				..LexerConfig::default()
+			},
+			expected token: new_block_comment(vec![4..8, 9..12]),
+			expected last seen index: 16
+		);
+		test_any!(
+			source: b"###\n###\na\n###\n###\nb",
+			config: LexerConfig {
+				nested_block_comments: true,
+				block_consume_trailing_newline: false,
+				..LexerConfig::default()
+			},
+			expected token: new_block_comment(vec![4..7, 8..9, 10..13]),
+			expected last seen index: 17
 		);
 	}
 
 	#[test]
-	fn can_lex_indented_attacher_from_new_line() {
+	fn can_lex_namespaced_attacher_if_configured() {
 		test_any!(
-			source: b"	tuvw:	x",
-			offset: 0,
-			tab count: 1,
-			is in new line: true,
-			expected token: new_attacher(1..5, 7..8),
+			source: b"h.i:\tj",
+			config: LexerConfig {
+				namespaced_labels: Some(b'.'),
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..3, 5..6),
+			expected last seen index: 6
+		);
+	}
+
+	#[test]
+	fn cannot_lex_namespaced_attacher_with_empty_segment_if_configured() {
+		test_any!(
+			source: b"k..l:\tm",
+			config: LexerConfig {
+				namespaced_labels: Some(b'.'),
+				..LexerConfig::default()
+			},
+			expected token: new_complex(0..5),
+			expected last seen index: 5
+		);
+	}
+
+	#[test]
+	fn can_lex_other_attachers_if_namespaced_labels_are_configured() {
+		test_any!(
+			source: b"a.b::c",
+			config: LexerConfig {
+				namespaced_labels: Some(b'.'),
+				double_colon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..3, 5..6),
+			expected last seen index: 6
+		);
+		test_any!(
+			source: b"d.e -> f",
+			config: LexerConfig {
+				namespaced_labels: Some(b'.'),
+				arrow_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..3, 7..8),
+			expected last seen index: 8
+		);
+		test_any!(
+			source: b"g.h; i",
+			config: LexerConfig {
+				namespaced_labels: Some(b'.'),
+				semicolon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..3, 5..6),
+			expected last seen index: 6
+		);
+	}
+
+	#[test]
+	fn cannot_lex_namespaced_attacher_with_empty_segment_by_other_attachers() {
+		test_any!(
+			source: b"j..k:\tl",
+			config: LexerConfig {
+				namespaced_labels: Some(b'.'),
+				arrow_attacher: true,
+				semicolon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_complex(0..5),
+			expected last seen index: 5
+		);
+	}
+
+	#[test]
+	fn can_lex_dotted_attacher_if_configured() {
+		test_any!(
+			source: b"m.n.o:\tp",
+			config: LexerConfig {
+				dotted_labels: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..5, 7..8),
+			expected last seen index: 8
+		);
+		test_any!(
+			source: b"q..r:\ts",
+			config: LexerConfig {
+				dotted_labels: true,
+				..LexerConfig::default()
+			},
+			expected token: new_complex(0..5),
+			expected last seen index: 5
+		);
+	}
+
+	#[test]
+	fn can_lex_other_attachers_if_dotted_labels_are_configured() {
+		test_any!(
+			source: b"t.u::v",
+			config: LexerConfig {
+				dotted_labels: true,
+				double_colon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..3, 5..6),
+			expected last seen index: 6
+		);
+		test_any!(
+			source: b"w.x -> y",
+			config: LexerConfig {
+				dotted_labels: true,
+				arrow_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..3, 7..8),
+			expected last seen index: 8
+		);
+		test_any!(
+			source: b"z.a; b",
+			config: LexerConfig {
+				dotted_labels: true,
+				semicolon_attacher: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..3, 5..6),
+			expected last seen index: 6
+		);
+	}
+
+	#[test]
+	fn can_lex_attacher_with_trimmed_content_if_configured() {
+		test_any!(
+			source: b"n:\to  ",
+			config: LexerConfig {
+				trim_attacher_content: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..1, 3..4),
+			expected last seen index: 6
+		);
+	}
+
+	#[test]
+	fn can_lex_attacher_without_inline_comment_if_configured() {
+		test_any!(
+			source: b"p:\tq # r\ns",
+			config: LexerConfig {
+				inline_comments: true,
+				..LexerConfig::default()
+			},
+			expected token: new_attacher(0..1, 3..4),
 			expected last seen index: 8
 		);
 	}
+
+	#[test]
+	fn can_skip_rest_of_block_beyond_line_limit_if_configured() {
+		let config = LexerConfig {
+			max_block_lines: Some(1),
+			..LexerConfig::default()
+		};
+
+		let cases = [
+			(&b"###\na\nb:\tc\n###\nd"[..], TokenKind::BlockComment),
+			(&b"===\na\nb:\tc\n===\nd"[..], TokenKind::BlockOthertongue)
+		];
+
+		for (source, kind) in cases {
+			let (token, last_seen_index) = any_with_config::<
+				&[u8],
+				Range<usize>,
+				Vec<Range<usize>>,
+				Token<Range<usize>, Vec<Range<usize>>>
+			>(source, 0, 0, false, &config);
+			let result = try_any_with_config::<
+				&[u8],
+				Range<usize>,
+				Vec<Range<usize>>,
+				Token<Range<usize>, Vec<Range<usize>>>
+			>(source, 0, 0, false, &config);
+
+			assert_eq!(token.kind(), kind);
+			assert_eq!(last_seen_index, 15);
+			assert_eq!(result, Err(UnexpectedTokenError {
+				byte_offset: 0,
+				raw_token_kind_debug: String::from("BlockTooLong")
+			}));
+		}
+	}
+
+	#[test]
+	fn can_lex_block_with_limited_lines_if_configured() {
+		let config = LexerConfig {
+			max_block_lines: Some(2),
+			..LexerConfig::default()
+		};
+
+		test_any!(
+			source: b"###\np\nq\n###",
+			config: config.clone(),
+			expected token: new_block_comment(vec![4..5, 6..7]),
+			expected last seen index: 11
+		);
+		test_any!(
+			source: b"===\ns\nt\n===",
+			config: config,
+			expected token: new_block_othertongue(vec![4..5, 6..7]),
+			expected last seen index: 11
+		);
+	}
+
+	#[test]
+	fn can_lex_block_othertongue_with_exact_delimiters_if_configured() {
+		let config = LexerConfig {
+			othertongue_delimiter_count: Some(4),
+			..LexerConfig::default()
+		};
+
+		test_any!(
+			source: b"====\nx\n===\n====",
+			config: config.clone(),
+			expected token: new_block_othertongue(vec![5..6, 7..10]),
+			expected last seen index: 15
+		);
+		test_any!(
+			source: b"===",
+			config: config,
+			expected token: new_complex(0..3),
+			expected last seen index: 3
+		);
+	}
+
+	#[test]
+	fn cannot_lex_empty_blocks_if_lines_are_required() {
+		let config = LexerConfig {
+			min_block_lines: 1,
+			..LexerConfig::default()
+		};
+
+		for source in [&b"###\n###"[..], &b"===\n==="[..]] {
+			let result = try_any_with_config::<
+				&[u8],
+				Range<usize>,
+				Vec<Range<usize>>,
+				Token<Range<usize>, Vec<Range<usize>>>
+			>(source, 0, 0, false, &config);
+			assert_eq!(result, Err(UnexpectedTokenError {
+				byte_offset: 0,
+				raw_token_kind_debug: String::from("BlockTooShort")
+			}));
+		}
+
+		test_any!(
+			source: b"===\nv\nw\n===",
+			config: config,
+			expected token: new_block_othertongue(vec![4..5, 6..7]),
+			expected last seen index: 11
+		);
+	}
+
+	#[test]
+	fn can_keep_empty_blocks_if_lines_are_required_leniently() {
+		let config = LexerConfig {
+			min_block_lines: 1,
+			..LexerConfig::default()
+		};
+
+		let (token, last_seen_index) = any_with_config::<
+			&[u8],
+			Range<usize>,
+			Vec<Range<usize>>,
+			Token<Range<usize>, Vec<Range<usize>>>
+		>(&b"===\n===\na"[..], 0, 0, false, &config);
+
+		assert_eq!(token.kind(), TokenKind::BlockOthertongue);
+		assert_eq!(last_seen_index, 8);
+	}
+
+	#[test]
+	fn cannot_lex_double_colon_attacher_by_default() {
+		test_any!(
+			source: b"ef::g",
+			config: LexerConfig::default(),
+			expected token: new_complex(0..5),
+			expected last seen index: 5
+		);
+	}
+
+	#[test]
+	fn can_lex_same_token_with_any_hint() {
+		let sources: [&[u8]; 11] = [
+			b"hi:\tj",
+			b"\tk:\tl",
+			b"\t\tm:\tn",
+			b"# o",
+			b"### p ###",
+			b"###\nq\n###",
+			b"= r",
+			b" = s",
+			b"===\nt\n===",
+			b"u|",
+			b"v w"
+		];
+		let hints = [
+			TokenKind::ScopeLevel,
+			TokenKind::LineComment,
+			TokenKind::BlockComment,
+			TokenKind::Simplex,
+			TokenKind::Complex,
+			TokenKind::Attacher,
+			TokenKind::LineOthertongue,
+			TokenKind::BlockOthertongue
+		];
+
+		for source in sources {
+			for hint in hints {
+				let expected_info: (Token<Range<usize>, Vec<Range<usize>>>, usize)
+					= any(source, 0, 1, true);
+				let info = any_hinted(source, 0, 1, true, hint);
+				assert_eq!(info, expected_info, "Source {:?} with hint {:?}", source, hint);
+			}
+		}
+	}
+
+	#[test]
+	fn can_lex_with_span() {
+		let (token, span): SpannedInfo = any_spanned(&b"w\n\tx:\ty"[..], 2, 1, true);
+
+		assert_eq!(token, Token::new_attacher(3..4, 6..7));
+		assert_eq!(span, 2..7);
+	}
+
+	struct ExclamationPlugin;
+
+	impl<'a> LexerPlugin<
+		&'a [u8],
+		Range<usize>,
+		Vec<Range<usize>>,
+		Token<Range<usize>, Vec<Range<usize>>>
+	> for ExclamationPlugin {
+		fn try_lex(&self, src: &'a [u8], offset: usize, _: usize)
+		-> Option<(Token<Range<usize>, Vec<Range<usize>>>, usize)> {
+			if src.get(offset) == Some(&b'!') {
+				Some((Token::new_simplex(offset + 1..src.len()), src.len()))
+			} else {
+				None
+			}
+		}
+	}
+
+	#[test]
+	fn can_lex_with_plugins() {
+		let plugins: [&dyn LexerPlugin<_, _, _, _>; 1] = [&ExclamationPlugin];
+		let source = &b"a\n\t!b"[..];
+
+		let info: (Token<Range<usize>, Vec<Range<usize>>>, usize)
+			= any_with_plugins(source, 2, 0, true, &plugins);
+		assert_eq!(info, (Token::new_scope_level(1), 3));
+
+		let info = any_with_plugins(source, 2, 1, true, &plugins);
+		assert_eq!(info, (Token::new_simplex(4..5), 5));
+
+		let info = any_with_plugins(source, 0, 0, true, &plugins);
+		assert_eq!(info, any(source, 0, 0, true));
+	}
+
+	#[test]
+	fn can_handle_invalid_raw_tokens() {
+		let mut invalid_offsets = Vec::new();
+		let handle = |offset, _: &_| invalid_offsets.push(offset);
+
+		let info: (Token<Range<usize>, Vec<Range<usize>>>, usize)
+			= any_with_handler(&b"a\n\tb c"[..], 2, 1, true, handle);
+
+		assert_eq!(info, (Token::new_complex(3..6), 6));
+		assert_eq!(invalid_offsets, vec![3, 3]);
+	}
 }
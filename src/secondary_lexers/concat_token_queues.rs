@@ -0,0 +1,75 @@
+use crate::abstracts::AbstractTokenQueue;
+use crate::readable_token_queue::ReadableTokenQueue;
+
+/// Returns the base token queue after the tokens of the extension have been pushed into it.
+///
+/// It needs the token queue to keep as the first argument (known as base) and the token queue to
+/// drain as the second argument (known as extension). The tokens keep their order.
+///
+/// ## Notes
+/// The boundaries of the tokens are not moved. If the token queues came from different sources,
+/// the boundaries of the tokens from the extension are still relative to their own source.
+///
+/// No scope level token is added between the token queues. The tokens are the same as lexing the
+/// sources together only if the base ends at the first scope level.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::concat_token_queues;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+///
+/// let base: Queue = lex(&&b"a"[..], VecDeque::new());
+/// let extension: Queue = lex(&&b"b|"[..], VecDeque::new());
+/// let queue = concat_token_queues(base, extension);
+///
+/// assert_eq!(queue, VecDeque::from(vec![Token::new_complex(0..1), Token::new_simplex(0..1)]));
+/// ```
+pub fn concat_token_queues<U, V, W, Y>(mut base: Y, mut extension: Y) -> Y
+where
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> + ReadableTokenQueue<W> {
+	while let Some(token) = extension.pop_front_token() {
+		base.push_token(token);
+	}
+
+	base
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::abstracts::AbstractToken;
+	use crate::token::Token;
+	use crate::secondary_lexers::lex;
+	use super::concat_token_queues;
+
+	type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+
+	#[test]
+	fn can_concat_same_kinds_as_lex() {
+		let base: Queue = lex(&&b"a|\nb:\tc\n"[..], VecDeque::new());
+		let extension: Queue = lex(&&b"d\n\t# e"[..], VecDeque::new());
+		let concatenated: Queue = lex(&&b"a|\nb:\tc\nd\n\t# e"[..], VecDeque::new());
+
+		let queue = concat_token_queues(base, extension);
+
+		assert_eq!(
+			queue.iter().map(|token| token.kind()).collect::<Vec<_>>(),
+			concatenated.iter().map(|token| token.kind()).collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn can_concat_empty_extension() {
+		let base: Queue = lex(&&b"f"[..], VecDeque::new());
+
+		let queue = concat_token_queues(base, VecDeque::new());
+
+		assert_eq!(queue, VecDeque::from(vec![Token::new_complex(0..1)]));
+	}
+}
@@ -0,0 +1,121 @@
+use crate::abstracts::{
+	AbstractToken,
+	AbstractSource,
+	AbstractBoundary,
+	ComparableAbstractSource,
+	AbstractBoundaryCollection
+};
+use crate::secondary_lexers::lex_each;
+use crate::token::TokenKind;
+
+/// Returns the number of tokens that [`lex()`] would push from the source.
+///
+/// The source is lexed without keeping the tokens or their boundaries. Therefore, it can be used to
+/// allocate a token queue with enough capacity before lexing the source.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::secondary_lexers::{count_tokens, lex};
+/// use chearmyp_token::Token;
+///
+/// let source = &b"a\n\tb:\tc\n\t# d"[..];
+/// let count = count_tokens(source);
+/// assert_eq!(count, 4);
+///
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>>
+/// 	= lex(&source, VecDeque::with_capacity(count));
+/// assert_eq!(queue.len(), count);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn count_tokens<T>(src: T) -> usize
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone {
+	let mut count = 0;
+	lex_each::<T, IgnoredBoundary, IgnoredBoundary, CountedToken, _>(src, |_, _| count += 1);
+	count
+}
+
+struct IgnoredBoundary;
+
+impl AbstractBoundary<usize> for IgnoredBoundary {
+	fn new(_: usize, _: usize) -> Self {
+		IgnoredBoundary
+	}
+}
+
+impl AbstractBoundaryCollection<usize, IgnoredBoundary> for IgnoredBoundary {
+	fn new(_: usize, _: usize) -> Self {
+		IgnoredBoundary
+	}
+
+	fn add(&mut self, _: IgnoredBoundary) {}
+}
+
+struct CountedToken(TokenKind);
+
+impl AbstractToken<usize, IgnoredBoundary, usize, IgnoredBoundary, IgnoredBoundary>
+for CountedToken {
+	fn kind(&self) -> TokenKind {
+		self.0
+	}
+
+	fn new_scope_level(_: usize) -> Self {
+		CountedToken(TokenKind::ScopeLevel)
+	}
+
+	fn new_line_comment(_: IgnoredBoundary) -> Self {
+		CountedToken(TokenKind::LineComment)
+	}
+
+	fn new_block_comment(_: IgnoredBoundary) -> Self {
+		CountedToken(TokenKind::BlockComment)
+	}
+
+	fn new_simplex(_: IgnoredBoundary) -> Self {
+		CountedToken(TokenKind::Simplex)
+	}
+
+	fn new_complex(_: IgnoredBoundary) -> Self {
+		CountedToken(TokenKind::Complex)
+	}
+
+	fn new_attacher(_: IgnoredBoundary, _: IgnoredBoundary) -> Self {
+		CountedToken(TokenKind::Attacher)
+	}
+
+	fn new_line_othertongue(_: IgnoredBoundary) -> Self {
+		CountedToken(TokenKind::LineOthertongue)
+	}
+
+	fn new_block_othertongue(_: IgnoredBoundary) -> Self {
+		CountedToken(TokenKind::BlockOthertongue)
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::token::Token;
+	use crate::secondary_lexers::lex;
+	use super::count_tokens;
+
+	macro_rules! test_count_tokens {
+		($source:literal) => {
+			let source = &$source[..];
+			let token_queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>>
+				= lex(&source, VecDeque::new());
+			assert_eq!(count_tokens(source), token_queue.len(), "Count of {:?}", source);
+		};
+	}
+
+	#[test]
+	fn can_count_same_tokens_as_lex() {
+		test_count_tokens!(b"");
+		test_count_tokens!(b"a|\n\tb\n\t\tc:\td");
+		test_count_tokens!(b"###\ne\n###\nf\n\t= g\n\t===\n\th\n\t===");
+		test_count_tokens!(b"i\n\t\tj\n\tk|\nl");
+	}
+}
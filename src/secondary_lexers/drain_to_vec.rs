@@ -0,0 +1,98 @@
+use crate::native::{Vec, VecDeque};
+use crate::readable_token_queue::ReadableTokenQueue;
+
+/// Returns the tokens of the token queue in a vector.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::drain_to_vec;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\nb|";
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>> = lex(&&source[..], VecDeque::new());
+/// let tokens = drain_to_vec(queue);
+///
+/// assert_eq!(tokens, vec![Token::new_complex(0..1), Token::new_simplex(2..3)]);
+/// ```
+pub fn drain_to_vec<W, Y>(mut queue: Y) -> Vec<W>
+where
+	Y: ReadableTokenQueue<W> {
+	let mut tokens = Vec::with_capacity(queue.token_count());
+	while let Some(token) = queue.pop_front_token() {
+		tokens.push(token);
+	}
+
+	tokens
+}
+
+/// Returns the tokens of the token queue in a double-ended queue.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::secondary_lexers::into_vec_deque;
+/// use chearmyp_token::Token;
+///
+/// let tokens: Vec<Token<Range<usize>, Vec<Range<usize>>>> = vec![Token::new_complex(0..1)];
+/// let queue = into_vec_deque(tokens);
+///
+/// assert_eq!(queue, VecDeque::from(vec![Token::new_complex(0..1)]));
+/// ```
+pub fn into_vec_deque<W, Y>(mut queue: Y) -> VecDeque<W>
+where
+	Y: ReadableTokenQueue<W> {
+	let mut tokens = VecDeque::with_capacity(queue.token_count());
+	while let Some(token) = queue.pop_front_token() {
+		tokens.push_back(token);
+	}
+
+	tokens
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::abstracts::AbstractToken;
+	use crate::token::Token;
+	use super::{drain_to_vec, into_vec_deque};
+
+	type SampleToken = Token<Range<usize>, Vec<Range<usize>>>;
+
+	#[test]
+	fn can_drain_to_vec() {
+		let queue: VecDeque<SampleToken> = VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_line_comment(3..4)
+		]);
+
+		assert_eq!(drain_to_vec(queue), vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_line_comment(3..4)
+		]);
+	}
+
+	#[test]
+	fn can_convert_into_vec_deque() {
+		let tokens: Vec<SampleToken> = vec![Token::new_simplex(0..1), Token::new_scope_level(0)];
+
+		assert_eq!(into_vec_deque(tokens), VecDeque::from(vec![
+			Token::new_simplex(0..1),
+			Token::new_scope_level(0)
+		]));
+	}
+
+	#[test]
+	fn can_drain_empty_queue() {
+		let queue: VecDeque<SampleToken> = VecDeque::new();
+
+		assert!(drain_to_vec(queue).is_empty());
+	}
+}
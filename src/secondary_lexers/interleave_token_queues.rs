@@ -0,0 +1,98 @@
+use crate::either::Either;
+use crate::readable_token_queue::ReadableTokenQueue;
+
+/// Returns an iterator that takes the tokens from two token queues by turns.
+///
+/// It needs the token queue whose tokens are tagged as left as the first argument and the token
+/// queue whose tokens are tagged as right as the second argument. The first token comes from the
+/// left token queue. Once a token queue runs out of tokens, the rest of the tokens in the other
+/// token queue are taken one after another and keep their tag.
+///
+/// ## Notes
+/// The tokens are removed from the token queues as they are taken. The boundaries of the tokens are
+/// not moved so they are still relative to their own sources.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::{lex, Either};
+/// use chearmyp_lexer::secondary_lexers::interleave_token_queues;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+///
+/// let a: Queue = lex(&&b"a|\nb|"[..], VecDeque::new());
+/// let b: Queue = lex(&&b"c"[..], VecDeque::new());
+/// let mut tokens = interleave_token_queues(a, b);
+///
+/// assert_eq!(tokens.next(), Some(Either::Left(Token::new_simplex(0..1))));
+/// assert_eq!(tokens.next(), Some(Either::Right(Token::new_complex(0..1))));
+/// assert_eq!(tokens.next(), Some(Either::Left(Token::new_simplex(3..4))));
+/// assert_eq!(tokens.next(), None);
+/// ```
+pub fn interleave_token_queues<W, Y>(mut a: Y, mut b: Y) -> impl Iterator<Item = Either<W, W>>
+where
+	Y: ReadableTokenQueue<W> {
+	let mut is_left_turn = true;
+
+	core::iter::from_fn(move || {
+		let token = if is_left_turn {
+			a.pop_front_token().map(Either::Left).or_else(|| b.pop_front_token().map(Either::Right))
+		} else {
+			b.pop_front_token().map(Either::Right).or_else(|| a.pop_front_token().map(Either::Left))
+		};
+		is_left_turn = !is_left_turn;
+		token
+	})
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::abstracts::AbstractToken;
+	use crate::either::Either;
+	use crate::token::Token;
+	use crate::secondary_lexers::lex;
+	use super::interleave_token_queues;
+
+	type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+
+	#[test]
+	fn can_interleave_queues_of_same_length() {
+		let a: Queue = lex(&&b"a|\nb|"[..], VecDeque::new());
+		let b: Queue = lex(&&b"c|\nd|"[..], VecDeque::new());
+
+		let tokens = interleave_token_queues(a, b).collect::<Vec<_>>();
+
+		assert_eq!(tokens, vec![
+			Either::Left(Token::new_simplex(0..1)),
+			Either::Right(Token::new_simplex(0..1)),
+			Either::Left(Token::new_simplex(3..4)),
+			Either::Right(Token::new_simplex(3..4))
+		]);
+	}
+
+	#[test]
+	fn can_keep_tag_of_remaining_tokens() {
+		let a: Queue = lex(&&b"a"[..], VecDeque::new());
+		let b: Queue = lex(&&b"b|\nc|\nd|"[..], VecDeque::new());
+
+		let tokens = interleave_token_queues(a, b).collect::<Vec<_>>();
+
+		assert_eq!(tokens, vec![
+			Either::Left(Token::new_complex(0..1)),
+			Either::Right(Token::new_simplex(0..1)),
+			Either::Right(Token::new_simplex(3..4)),
+			Either::Right(Token::new_simplex(6..7))
+		]);
+	}
+
+	#[test]
+	fn can_interleave_empty_queues() {
+		let tokens = interleave_token_queues(Queue::new(), Queue::new()).collect::<Vec<_>>();
+
+		assert!(tokens.is_empty());
+	}
+}
@@ -8,222 +8,2379 @@ use crate::abstracts::{
 	ComparableAbstractSource
 };
 use crate::token::TokenKind;
-use crate::any;
-use crate::special_characters::NEW_LINE;
+use crate::native::{Range, Vec};
+use crate::helpers::{
+	count_tabs_with_width,
+	find_indentation_end,
+	find_line_ending,
+	hash_source
+};
+use crate::lexer_config::{BlankLinePolicy, LexerConfig};
+use crate::lexer_context::{LexerContext, SpannedToken};
+use crate::lexer_state::LexerState;
+use crate::measurable_abstract_source::{find_byte_len, find_byte_len_after};
+use crate::source_map::SourceMap;
+use crate::scope_error::{ScopeError, ScopeErrorKind};
+use crate::source_too_large_error::SourceTooLargeError;
+use crate::secondary_lexers::any::{
+	any_with_diagnostic,
+	DiagnosedTokenInfo,
+	UNEXPECTED_TOKEN_MESSAGE
+};
+use crate::unexpected_token_error::UnexpectedTokenError;
+use crate::lex_error::LexError;
+use crate::configured_lex_error::ConfiguredLexError;
+use crate::special_characters::{CARRIAGE_RETURN, NEW_LINE, TAB};
+use crate::secondary_lexers::TokenSink;
+use crate::primary_lexers::blank_line;
+use crate::raw_token::RawToken;
+
+/// Returns a stream of tokens based from the source.
+///
+/// The source is the first argument which contain an array of bytes. This is the main lexer.
+///
+/// ## Notes
+/// The content of an attacher ends before a tab. Therefore, `a:\tb\tc:\td` is lexed into two
+/// attachers on the same line. The tabs between them only separate the tokens and are skipped.
+///
+/// Carriage returns found between tokens are skipped too. They do not start a new line so a bare
+/// carriage return does not end up inside the next token.
+///
+/// Block tokens consume the line ending after their closing delimiter. The line after a block is
+/// still lexed as a new line so its indentation produces a scope level token like any other line.
+///
+/// Blocks whose closing delimiter has wrong indentation or trailing content are kept as block
+/// tokens like in [`any()`]. Use [`lex_recover()`] to find them.
+///
+/// The lexer keeps no global state. Therefore, lexing the same source always returns the same
+/// tokens.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::{AbstractToken, AbstractTokenQueue};
+/// use chearmyp_lexer::lex;
+/// use chearmyp_token::Token;
+/// let source = b"
+/// a complex
+/// 	this:	is an attacher
+/// 	a simplex|
+/// ## This is a line comment
+/// ";
+///
+/// let queue: VecDeque<
+/// 	Token<
+/// 		Range<usize>,
+/// 		Vec<Range<usize>>
+/// 	>
+/// > = lex(&&source[..], VecDeque::new());
+///
+/// assert_eq!(queue[0], Token::<Range<usize>, Vec<Range<usize>>>::new_complex(1..10));
+/// assert_eq!(queue[1], Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(1));
+/// assert_eq!(queue[2], Token::<Range<usize>, Vec<Range<usize>>>::new_attacher(12..16, 18..32));
+/// assert_eq!(queue[3], Token::<Range<usize>, Vec<Range<usize>>>::new_simplex(34..43));
+/// assert_eq!(queue[4], Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(0));
+/// assert_eq!(queue[5], Token::<Range<usize>, Vec<Range<usize>>>::new_line_comment(46..69));
+/// ```
+///
+/// [`any()`]: ./fn.any.html
+/// [`lex_recover()`]: ./fn.lex_recover.html
+pub fn lex<T, U, V, W, X, Y>(src: &T, mut token_queue: Y) -> Y
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	lex_each::<T, U, V, W, _>(src.clone(), |token, _| token_queue.push_token(token));
+	token_queue
+}
+
+/// Returns the tokens found in the source in a vector.
+///
+/// It works like [`lex()`] but the tokens are pushed to a vector instead of a token queue. The
+/// tokens can be accessed by index without the need to specify the type of the token queue.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::secondary_lexers::lex_vec;
+/// use chearmyp_token::Token;
+///
+/// let tokens: Vec<Token<Range<usize>, Vec<Range<usize>>>> = lex_vec(&&b"a\n\tb|"[..]);
+/// assert_eq!(tokens, vec![
+/// 	Token::new_complex(0..1),
+/// 	Token::new_scope_level(1),
+/// 	Token::new_simplex(3..4)
+/// ]);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_vec<T, U, V, W>(src: &T) -> Vec<W>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	let mut tokens = Vec::new();
+	lex_each::<T, U, V, W, _>(src.clone(), |token, _| tokens.push(token));
+	tokens
+}
+
+/// Passes the tokens found in the source to the sink as soon as each of them has been lexed.
+///
+/// It works like [`lex()`] but the tokens are not kept in a token queue. The sink receives each
+/// token along with the range of bytes it occupies in the source.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::secondary_lexers::{lex_sink, TokenSink};
+/// use chearmyp_token::Token;
+///
+/// struct EndCollector(Vec<usize>);
+///
+/// impl<U, V, W> TokenSink<U, V, W> for EndCollector {
+/// 	fn sink(&mut self, _token: W, byte_range: Range<usize>) {
+/// 		self.0.push(byte_range.end);
+/// 	}
+/// }
+///
+/// let mut collector = EndCollector(Vec::new());
+/// lex_sink::<_, Range<usize>, Vec<Range<usize>>, Token<_, _>, _>(
+/// 	&&b"a\n\tb:\tc"[..],
+/// 	&mut collector
+/// );
+/// assert_eq!(collector.0, vec![1, 3, 7]);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_sink<T, U, V, W, S>(src: &T, sink: &mut S)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	S: TokenSink<U, V, W> {
+	lex_each::<T, U, V, W, _>(src.clone(), |token, byte_range| sink.sink(token, byte_range));
+}
+
+/// Returns a stream of tokens based from the source if the source is not larger than the limit.
+///
+/// It works like [`lex()`] but it checks the size of the source first. If the source has more
+/// bytes than the limit, nothing will be lexed.
+///
+/// ## Notes
+/// Checking if a source is within the limit only needs one check. Finding the actual size of a
+/// larger source needs a number of checks proportional to the logarithm of its size.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::{SourceTooLargeError, secondary_lexers::lex_bounded};
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = b"a\nb|";
+///
+/// let queue: Result<Queue, SourceTooLargeError> = lex_bounded(&&source[..], 4, VecDeque::new());
+/// assert_eq!(queue.unwrap().len(), 2);
+///
+/// let queue: Result<Queue, SourceTooLargeError> = lex_bounded(&&source[..], 3, VecDeque::new());
+/// assert_eq!(queue, Err(SourceTooLargeError { actual_size: 4, limit: 3 }));
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_bounded<T, U, V, W, X, Y>(src: &T, max_bytes: usize, token_queue: Y)
+-> Result<Y, SourceTooLargeError>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	if src.is_empty_at(max_bytes) {
+		Ok(lex(src, token_queue))
+	} else {
+		Err(SourceTooLargeError {
+			actual_size: find_byte_len_after(src, max_bytes),
+			limit: max_bytes
+		})
+	}
+}
+
+/// Returns a stream of tokens based from the source up to the maximum number of tokens and if the
+/// lexing stopped early.
+///
+/// It works like [`lex()`] but it stops once the token queue has received the maximum number of
+/// tokens. The returned boolean is true if there are more tokens left in the source.
+///
+/// ## Notes
+/// The new lines, the carriage returns, and the tabs between inlined tokens are not tokens so they
+/// are not counted. If the source only has such bytes after the last token, the lexing is not
+/// considered stopped early.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::secondary_lexers::lex_with_limit;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = b"a\n\tb|\n";
+///
+/// let (queue, is_limited): (Queue, bool) = lex_with_limit(&&source[..], VecDeque::new(), 2);
+/// assert_eq!(queue.len(), 2);
+/// assert!(is_limited);
+///
+/// let (queue, is_limited): (Queue, bool) = lex_with_limit(&&source[..], VecDeque::new(), 3);
+/// assert_eq!(queue.len(), 3);
+/// assert!(!is_limited);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_with_limit<T, U, V, W, X, Y>(src: &T, mut token_queue: Y, max_tokens: usize) -> (Y, bool)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut cursor = LexerCursor::default();
+	let is_limited = lex_each_with_config::<T, U, V, W, _, _>(
+		src,
+		&LexerConfig::default(),
+		&mut cursor,
+		BlockPolicy::Keep,
+		|cursor| cursor.token_count >= max_tokens,
+		|token, _, _| token_queue.push_token(token)
+	).expect(UNEXPECTED_TOKEN_MESSAGE);
+
+	(token_queue, is_limited)
+}
+
+/// Returns a stream of tokens based from the source and the fingerprint of the source.
+///
+/// It works like [`lex()`] but it also returns the digest of the source bytes from
+/// [`hash_source()`]. The digest is computed before lexing so it can be kept as a cache key to know
+/// if the source has changed.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::helpers::hash_source;
+/// use chearmyp_lexer::secondary_lexers::lex_with_fingerprint;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = &b"a\nb|"[..];
+///
+/// let (queue, fingerprint): (Queue, u64) = lex_with_fingerprint(&source, VecDeque::new());
+/// assert_eq!(queue.len(), 2);
+/// assert_eq!(fingerprint, hash_source(&source));
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+/// [`hash_source()`]: ../helpers/fn.hash_source.html
+pub fn lex_with_fingerprint<T, U, V, W, X, Y>(src: &T, token_queue: Y) -> (Y, u64)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + AsRef<[u8]> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let fingerprint = hash_source(src);
+	(lex(src, token_queue), fingerprint)
+}
+
+/// Returns a stream of tokens based from the source without the comments.
+///
+/// It works like [`lex()`] but line comments and block comments are dropped while lexing instead of
+/// being pushed to the token queue.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::{AbstractToken, AbstractTokenQueue};
+/// use chearmyp_lexer::secondary_lexers::lex_no_comments;
+/// use chearmyp_token::Token;
+/// let source = b"
+/// ## This is a line comment
+/// a complex
+/// ####
+/// This is a block comment
+/// ####
+/// a simplex|
+/// ";
+///
+/// let queue: VecDeque<
+/// 	Token<
+/// 		Range<usize>,
+/// 		Vec<Range<usize>>
+/// 	>
+/// > = lex_no_comments(&&source[..], VecDeque::new());
+///
+/// assert_eq!(queue.len(), 2);
+/// assert_eq!(queue[0], Token::<Range<usize>, Vec<Range<usize>>>::new_complex(26..35));
+/// assert_eq!(queue[1], Token::<Range<usize>, Vec<Range<usize>>>::new_simplex(68..77));
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_no_comments<T, U, V, W, X, Y>(src: &T, mut token_queue: Y) -> Y
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	lex_each::<T, U, V, W, _>(src.clone(), |token, _| {
+		let kind = W::kind(&token);
+		if kind != TokenKind::LineComment && kind != TokenKind::BlockComment {
+			token_queue.push_token(token);
+		}
+	});
+	token_queue
+}
+
+/// Returns a stream of tokens based from the source which are only at the target scope level.
+///
+/// It works like [`lex()`] but only the tokens found while the scope level is the target depth are
+/// pushed. The scope level tokens are never pushed.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::secondary_lexers::lex_at_depth;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb|\n\t\tc\nd";
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>>
+/// 	= lex_at_depth(&&source[..], 0, VecDeque::new());
+///
+/// assert_eq!(queue, VecDeque::from(vec![Token::new_complex(0..1), Token::new_complex(10..11)]));
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_at_depth<T, U, V, W, X, Y>(src: &T, target_depth: usize, mut token_queue: Y) -> Y
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	lex_at_depth_with_config(src, target_depth, token_queue, &LexerConfig::default())
+}
+
+/// Returns a stream of tokens based from the source and the byte ranges they occupy.
+///
+/// It works like [`lex()`] but it also returns the byte range of each token. The ranges are in the
+/// same order as the tokens in the token queue.
+///
+/// ## Notes
+/// The byte range of a scope level token covers the tabs that were counted. Each range starts where
+/// the lexer started looking for the token and ends at the last index returned for it. The end of
+/// an unclosed block is the length of the source so each range can slice the source. If the token
+/// queue was empty, there are as many ranges as tokens in the returned queue.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::secondary_lexers::lex_spanned;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb|";
+/// let (queue, spans): (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, Vec<Range<usize>>)
+/// 	= lex_spanned(&&source[..], VecDeque::new());
+///
+/// assert_eq!(queue.len(), 3);
+/// assert_eq!(spans, vec![0..1, 2..3, 3..5]);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_spanned<T, U, V, W, X, Y>(src: &T, mut token_queue: Y) -> (Y, Vec<Range<usize>>)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut spans = Vec::new();
+	lex_each::<T, U, V, W, _>(src.clone(), |token, range| {
+		token_queue.push_token(token);
+		spans.push(range);
+	});
+	(token_queue, spans)
+}
+
+/// Returns a stream of tokens based from the source and the maximum scope level found.
+///
+/// It works like [`lex()`] but it also keeps track of the deepest scope level while lexing.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::secondary_lexers::lex_with_max_depth;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb\n\t\tc\nd";
+/// let (queue, max_depth): (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, usize)
+/// 	= lex_with_max_depth(&&source[..], VecDeque::new());
+///
+/// assert_eq!(queue.len(), 7);
+/// assert_eq!(max_depth, 2);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_with_max_depth<T, U, V, W, X, Y>(src: &T, mut token_queue: Y) -> (Y, usize)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	lex_with_max_depth_and_config(src, token_queue, &LexerConfig::default())
+}
+
+/// Calls the callback for each token found in the source with the byte range it occupies.
+///
+/// It works like [`lex()`] but the tokens are given to the callback instead of being pushed to a
+/// token queue. Therefore, the tokens can be collected into any structure.
+///
+/// ## Notes
+/// The byte range of a scope level token covers the tabs that were counted. The byte range never
+/// ends after the source, even for an unclosed block.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::secondary_lexers::lex_each;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb|";
+/// let mut tokens = Vec::new();
+/// lex_each(&source[..], |token: Token<Range<usize>, Vec<Range<usize>>>, range| {
+/// 	tokens.push((token, range));
+/// });
+///
+/// assert_eq!(tokens, vec![
+/// 	(Token::new_complex(0..1), 0..1),
+/// 	(Token::new_scope_level(1), 2..3),
+/// 	(Token::new_simplex(3..4), 3..5)
+/// ]);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_each<T, U, V, W, F>(src: T, mut callback: F)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	F: FnMut(W, Range<usize>) {
+	let total_size = find_byte_len(&src);
+	lex_each_with_default_config(&src, |token, range: Range<usize>, _| {
+		callback(token, range.start..range.end.min(total_size));
+	});
+}
+
+/// Returns the tokens found in the source along with the state of the lexer at the time each token
+/// was found.
+///
+/// It works like [`lex()`] but each token is kept with its last index occupied in the source and a
+/// lexer context. The context contains the tab count stored by the lexer and the line where the
+/// token starts so parsers do not need to derive them from the scope level tokens.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::{LexerContext, SpannedToken};
+/// use chearmyp_lexer::secondary_lexers::lex_with_context;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb|";
+/// let tokens: Vec<SpannedToken<Token<Range<usize>, Vec<Range<usize>>>>>
+/// 	= lex_with_context(&&source[..]);
+///
+/// assert_eq!(tokens[2], SpannedToken {
+/// 	token: Token::new_simplex(3..4),
+/// 	end_offset: 5,
+/// 	context: LexerContext { tab_count: 1, line: 2 }
+/// });
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_with_context<T, U, V, W>(src: &T) -> Vec<SpannedToken<W>>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	let mut tokens = Vec::new();
+	lex_each_with_default_config::<T, U, V, W, _>(src, |token, range, context| {
+		tokens.push(SpannedToken { token, end_offset: range.end, context });
+	});
+	tokens
+}
+
+/// Returns a stream of tokens based from the source and the line number of each token.
+///
+/// It works like [`lex()`] but it also returns the 1-based line number where each token starts.
+/// The line number at an index belongs to the token at the same index in the token queue.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::secondary_lexers::lex_with_lines;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = &b"a\n\tb|\n# c"[..];
+///
+/// let (queue, line_numbers): (Queue, Vec<usize>) = lex_with_lines(&source, VecDeque::new());
+/// assert_eq!(queue.len(), 5);
+/// assert_eq!(line_numbers, vec![1, 2, 2, 3, 3]);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_with_lines<T, U, V, W, X, Y>(src: &T, mut token_queue: Y) -> (Y, Vec<usize>)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut line_numbers = Vec::new();
+	lex_each_with_default_config::<T, U, V, W, _>(src, |token, _, context| {
+		token_queue.push_token(token);
+		line_numbers.push(context.line);
+	});
+	(token_queue, line_numbers)
+}
+
+/// Returns a stream of tokens based from the source and the kind of each token.
+///
+/// It works like [`lex()`] but it also returns the kinds of the tokens in the same order. The kind
+/// at an index belongs to the token at the same index in the token queue. Tools that only check
+/// the structure of the source may read the kinds without reading the token queue.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::TokenKind;
+/// use chearmyp_lexer::secondary_lexers::lex_with_token_kinds;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = &b"a\n\tb|\n# c"[..];
+///
+/// let (queue, kinds): (Queue, Vec<TokenKind>) = lex_with_token_kinds(&source, VecDeque::new());
+/// assert_eq!(queue.len(), 5);
+/// assert_eq!(kinds, vec![
+/// 	TokenKind::Complex,
+/// 	TokenKind::ScopeLevel,
+/// 	TokenKind::Simplex,
+/// 	TokenKind::ScopeLevel,
+/// 	TokenKind::LineComment
+/// ]);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_with_token_kinds<T, U, V, W, X, Y>(src: &T, mut token_queue: Y) -> (Y, Vec<TokenKind>)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut kinds = Vec::new();
+	lex_each::<T, U, V, W, _>(src.clone(), |token, _| {
+		kinds.push(W::kind(&token));
+		token_queue.push_token(token);
+	});
+	(token_queue, kinds)
+}
+
+/// Returns a stream of tokens based from the source and the map of its lines.
+///
+/// It works like [`lex()`] but it also returns a [`SourceMap`] built from the source before lexing.
+/// Unlike the line numbers of [`lex_with_lines()`], the lines are found from the byte ranges of the
+/// tokens. Therefore, they can still be found after the token queue has been reordered or filtered.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::SourceMap;
+/// use chearmyp_lexer::secondary_lexers::lex_with_source_map;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = &b"a\n\tb|\n# c"[..];
+///
+/// let (queue, source_map): (Queue, SourceMap) = lex_with_source_map(&source, VecDeque::new());
+/// assert_eq!(queue.len(), 5);
+/// assert_eq!(source_map.line_of_token(6..9), 3);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+/// [`lex_with_lines()`]: ./fn.lex_with_lines.html
+/// [`SourceMap`]: ../struct.SourceMap.html
+pub fn lex_with_source_map<T, U, V, W, X, Y>(src: &T, token_queue: Y) -> (Y, SourceMap)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let source_map = SourceMap::new(src);
+	(lex(src, token_queue), source_map)
+}
+
+/// Returns a stream of tokens based from the source while reporting the progress of lexing.
+///
+/// It works like [`lex()`] but it calls the progress callback with the number of scanned bytes and
+/// the size of the source. To limit the overhead, the callback is called only after a token ends in
+/// a different interval of `progress_interval` bytes than the last reported token. The callback is
+/// always called once the whole source has been scanned.
+///
+/// ## Notes
+/// The size of the source is found once before lexing. A progress interval of zero is treated as
+/// one byte.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::lex_with_progress;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = &b"a\n\tb:\tc\n\td|"[..];
+/// let mut reports = Vec::new();
+///
+/// let queue: Queue = lex_with_progress(&source, 4, VecDeque::new(), |scanned, total| {
+/// 	reports.push((scanned, total));
+/// });
+/// assert_eq!(queue, lex::<_, _, _, _, _, Queue>(&source, VecDeque::new()));
+/// assert_eq!(reports, vec![(7, 11), (11, 11)]);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_with_progress<T, U, V, W, X, Y, F>(
+	src: &T,
+	progress_interval: usize,
+	mut token_queue: Y,
+	mut on_progress: F
+) -> Y
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W>,
+	F: FnMut(usize, usize) {
+	let total_size = find_byte_len(src);
+	let progress_interval = progress_interval.max(1);
+	let mut last_interval = 0;
+	let mut last_scanned_size = 0;
+	lex_each_with_default_config::<T, U, V, W, _>(src, |token, range, _| {
+		token_queue.push_token(token);
+		let scanned_size = range.end.min(total_size);
+		let current_interval = scanned_size / progress_interval;
+		if current_interval != last_interval {
+			last_interval = current_interval;
+			last_scanned_size = scanned_size;
+			on_progress(scanned_size, total_size);
+		}
+	});
+
+	if last_scanned_size != total_size {
+		on_progress(total_size, total_size);
+	}
+
+	token_queue
+}
+
+/// Returns a stream of tokens based from the source according to the configuration or an error if
+/// the configuration rejects the source.
+///
+/// It works like [`lex()`] but it needs a lexer configuration as the third argument. If
+/// indentation jumps or deep indentations are rejected, the error contains the index that the scope
+/// level token would have in the token queue.
+///
+/// ## Notes
+/// If a line has more tabs than the maximum tab depth, the level in the error will be the number of
+/// tabs counted before stopping, which is one more than the maximum.
+///
+/// Ignored blank lines are skipped before their indentation is checked so they never cause errors.
+///
+/// Blocks rejected by [`try_any_with_config()`], like a block which has fewer lines than the
+/// minimum, return a token error whose skipped range covers the block.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::{ConfiguredLexError, LexerConfig, ScopeError, ScopeErrorKind};
+/// use chearmyp_lexer::secondary_lexers::lex_with_config;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// type QueueResult = Result<Queue, ConfiguredLexError>;
+/// let config = LexerConfig {
+/// 	reject_indentation_jumps: true,
+/// 	..LexerConfig::default()
+/// };
+///
+/// let queue: QueueResult = lex_with_config(&&b"a\n\tb"[..], VecDeque::new(), &config);
+/// assert_eq!(queue.unwrap().len(), 3);
+///
+/// let queue: QueueResult = lex_with_config(&&b"a\n\t\tb"[..], VecDeque::new(), &config);
+/// assert_eq!(queue, Err(ConfiguredLexError::Scope(ScopeError {
+/// 	kind: ScopeErrorKind::LevelJumpTooLarge,
+/// 	token_index: 1,
+/// 	from_level: 0,
+/// 	to_level: 2
+/// })));
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+/// [`try_any_with_config()`]: ./fn.try_any_with_config.html
+pub fn lex_with_config<T, U, V, W, X, Y>(src: &T, mut token_queue: Y, config: &LexerConfig)
+-> Result<Y, ConfiguredLexError>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut cursor = LexerCursor::default();
+	let result = lex_each_with_config::<T, U, V, W, _, _>(
+		src,
+		config,
+		&mut cursor,
+		BlockPolicy::Reject,
+		|_| false,
+		|token, _, _| token_queue.push_token(token)
+	);
+
+	match result {
+		Ok(_) => Ok(token_queue),
+		Err(LexFailure::Rejected(error)) => Err(ConfiguredLexError::Scope(error)),
+		Err(LexFailure::Skipped(error)) => Err(ConfiguredLexError::Token(error))
+	}
+}
+
+/// Returns a stream of tokens based from the source and the errors found while lexing.
+///
+/// It works like [`lex()`] but it does not stop when the last possible lexer returns an unexpected
+/// raw token. Instead, the rest of the line is skipped and lexing continues on the next line.
+/// Blocks rejected by [`try_any()`], like blocks with a malformed closing line, are skipped whole.
+///
+/// ## Notes
+/// The token queue has no token for the skipped parts so there is a hole at each error site. The
+/// skipped range of each error can be used to find where the holes are.
+///
+/// Other than the skipped parts, the source is lexed by the same main loop as [`lex()`]. Therefore,
+/// the tabs between inlined tokens and the carriage returns are skipped the same way.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::{LexError, secondary_lexers::lex_recover};
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb|";
+/// let (queue, errors): (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, Vec<LexError>)
+/// 	= lex_recover(&&source[..], VecDeque::new());
+///
+/// assert_eq!(queue.len(), 3);
+/// assert!(errors.is_empty());
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+/// [`try_any()`]: ./fn.try_any.html
+pub fn lex_recover<T, U, V, W, X, Y>(src: &T, mut token_queue: Y) -> (Y, Vec<LexError>)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut errors = Vec::new();
+	let mut cursor = LexerCursor::default();
+	let config = LexerConfig::default();
+
+	loop {
+		let result = lex_each_with_config::<T, U, V, W, _, _>(
+			src,
+			&config,
+			&mut cursor,
+			BlockPolicy::Reject,
+			|_| false,
+			|token, _, _| token_queue.push_token(token)
+		);
+
+		match result {
+			Err(LexFailure::Skipped(error)) => errors.push(error),
+			// The default configuration does not reject any indentation.
+			_ => break
+		}
+	}
+
+	(token_queue, errors)
+}
+
+/// Returns a stream of tokens based from the source which has been lexed one chunk at a time.
+///
+/// It works like [`lex()`] but the source is lexed in chunks of bytes whose size is the second
+/// argument. The lexer stops at the end of each chunk before continuing with the next chunk. Use
+/// [`lex_chunked_async()`] to let other tasks run between the chunks.
+///
+/// ## Notes
+/// A token which crosses the end of a chunk is lexed whole so the next chunk starts after it. A
+/// chunk size of 0 is treated as 1.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::lex_chunked;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = &b"a\n\tb:\tc\n\td|"[..];
+///
+/// let queue: Queue = lex_chunked(&source, 4, VecDeque::new());
+/// let expected_queue: Queue = lex(&source, VecDeque::new());
+/// assert_eq!(queue, expected_queue);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+/// [`lex_chunked_async()`]: ./fn.lex_chunked_async.html
+pub fn lex_chunked<T, U, V, W, X, Y>(src: &T, chunk_size: usize, mut token_queue: Y) -> Y
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut cursor = LexerCursor::default();
+	while lex_chunk::<T, U, V, W, Y>(src, &mut cursor, chunk_size, &mut token_queue) {}
+
+	token_queue
+}
+
+/// Returns a stream of tokens based from the source which has been lexed one chunk at a time while
+/// letting other tasks run between the chunks.
+///
+/// It works like [`lex_chunked()`] but it yields to the Tokio runtime after each chunk. Therefore,
+/// lexing a large source does not block the other tasks in the same thread for long.
+///
+/// ## Notes
+/// Only available with the `async` feature.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::lex_chunked_async;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = &b"a\n\tb:\tc\n\td|"[..];
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// let queue: Queue = runtime.block_on(lex_chunked_async(&source, 4, VecDeque::new()));
+/// let expected_queue: Queue = lex(&source, VecDeque::new());
+/// assert_eq!(queue, expected_queue);
+/// ```
+///
+/// [`lex_chunked()`]: ./fn.lex_chunked.html
+#[cfg(feature = "async")]
+pub async fn lex_chunked_async<T, U, V, W, X, Y>(src: &T, chunk_size: usize, mut token_queue: Y)
+-> Y
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut cursor = LexerCursor::default();
+	while lex_chunk::<T, U, V, W, Y>(src, &mut cursor, chunk_size, &mut token_queue) {
+		tokio::task::yield_now().await;
+	}
+
+	token_queue
+}
+
+/// Lexes the tokens which start within the chunk at the cursor. Returns true if there are more
+/// tokens after the chunk.
+fn lex_chunk<T, U, V, W, Y>(
+	src: &T,
+	cursor: &mut LexerCursor,
+	chunk_size: usize,
+	token_queue: &mut Y
+) -> bool
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let chunk_end = cursor.state.scanned_size.saturating_add(chunk_size.max(1));
+
+	lex_each_with_config::<T, U, V, W, _, _>(
+		src,
+		&LexerConfig::default(),
+		cursor,
+		BlockPolicy::Keep,
+		|cursor| cursor.state.scanned_size >= chunk_end,
+		|token, _, _| token_queue.push_token(token)
+	).expect(UNEXPECTED_TOKEN_MESSAGE)
+}
+
+/// Contains the state of the main loop which is kept when it stops before the end of the source.
+#[derive(Debug, Clone, Copy)]
+struct LexerCursor {
+	state: LexerState,
+	token_count: usize,
+	line_count: usize
+}
+
+impl Default for LexerCursor {
+	fn default() -> Self {
+		Self {
+			state: LexerState::default(),
+			token_count: 0,
+			line_count: 1
+		}
+	}
+}
+
+/// Decides what the main loop does with the blocks that the fallible lexers reject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlockPolicy {
+	/// Passes the lines collected as a block token like `any_with_config()`.
+	Keep,
+
+	/// Skips the whole block and fails like `try_any_with_config()`.
+	Reject
+}
+
+/// Contains the reason why the main loop has failed at a token.
+#[derive(Debug)]
+enum LexFailure {
+	/// The configuration has rejected the indentation of the line. The cursor stays at the line.
+	Rejected(ScopeError),
+
+	/// The last possible lexer has returned an unexpected raw token or a block has been rejected.
+	/// The cursor has been moved after the skipped range so the main loop can resume.
+	Skipped(LexError)
+}
+
+/// Calls the callback for each token found from the cursor until the end of the source. Returns
+/// true if it stopped early because the stop condition was met before a token.
+fn lex_each_with_config<T, U, V, W, F, S>(
+	src: &T,
+	config: &LexerConfig,
+	cursor: &mut LexerCursor,
+	policy: BlockPolicy,
+	mut should_stop: S,
+	mut callback: F
+) -> Result<bool, LexFailure>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	F: FnMut(W, Range<usize>, LexerContext),
+	S: FnMut(&LexerCursor) -> bool {
+	while skip_separators(src, config, cursor, &mut callback) {
+		if should_stop(cursor) {
+			return Ok(true);
+		}
+
+		lex_token(src, config, cursor, policy, &mut callback)?;
+	}
+
+	Ok(false)
+}
+
+/// Pushes the tokens found while the scope level is the target depth like [`lex_at_depth()`].
+///
+/// The depth is the tab count of the lexer so the configured indentation is counted in its unit
+/// instead of bytes.
+///
+/// [`lex_at_depth()`]: ./fn.lex_at_depth.html
+fn lex_at_depth_with_config<T, U, V, W, Y>(
+	src: &T,
+	target_depth: usize,
+	mut token_queue: Y,
+	config: &LexerConfig
+) -> Y
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut cursor = LexerCursor::default();
+	let push_at_depth = |token: W, _: Range<usize>, context: LexerContext| {
+		if W::kind(&token) != TokenKind::ScopeLevel && context.tab_count == target_depth {
+			token_queue.push_token(token);
+		}
+	};
+	lex_each_with_config(src, config, &mut cursor, BlockPolicy::Keep, |_| false, push_at_depth)
+		.expect(UNEXPECTED_TOKEN_MESSAGE);
+	token_queue
+}
+
+/// Pushes the tokens and finds the deepest scope level like [`lex_with_max_depth()`].
+///
+/// Like [`lex_at_depth_with_config()`], the depth is the tab count of the lexer.
+///
+/// [`lex_with_max_depth()`]: ./fn.lex_with_max_depth.html
+/// [`lex_at_depth_with_config()`]: ./fn.lex_at_depth_with_config.html
+fn lex_with_max_depth_and_config<T, U, V, W, Y>(src: &T, mut token_queue: Y, config: &LexerConfig)
+-> (Y, usize)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	let mut cursor = LexerCursor::default();
+	let mut max_depth = 0;
+	let push_with_depth = |token: W, _: Range<usize>, context: LexerContext| {
+		max_depth = max_depth.max(context.tab_count);
+		token_queue.push_token(token);
+	};
+	lex_each_with_config(src, config, &mut cursor, BlockPolicy::Keep, |_| false, push_with_depth)
+		.expect(UNEXPECTED_TOKEN_MESSAGE);
+	(token_queue, max_depth)
+}
+
+/// Calls the callback for each token in the source using the default configuration.
+fn lex_each_with_default_config<T, U, V, W, F>(src: &T, callback: F)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	F: FnMut(W, Range<usize>, LexerContext) {
+	let mut cursor = LexerCursor::default();
+	// The default configuration does not reject any indentation. Like `any()`, it only fails if the
+	// last possible lexer has returned an unexpected raw token.
+	let config = LexerConfig::default();
+	lex_each_with_config(src, &config, &mut cursor, BlockPolicy::Keep, |_| false, callback)
+		.expect(UNEXPECTED_TOKEN_MESSAGE);
+}
+
+/// Skips the line endings, the carriage returns, the tabs between inlined tokens, and the ignored
+/// blank lines. Returns false if the end of the source has been reached.
+fn skip_separators<T, U, V, W, F>(
+	src: &T,
+	config: &LexerConfig,
+	cursor: &mut LexerCursor,
+	callback: &mut F
+) -> bool
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	F: FnMut(W, Range<usize>, LexerContext) {
+	let state = &mut cursor.state;
+
+	while !src.is_empty_at(state.scanned_size) {
+		let line_ending_size = if src.is_same_needle_at(state.scanned_size, NEW_LINE) {
+			1
+		} else if config.strip_cr
+		&& src.is_same_needle_at(state.scanned_size, CARRIAGE_RETURN)
+		&& src.is_same_needle_at(state.scanned_size + 1, NEW_LINE) {
+			2
+		} else {
+			0
+		};
+
+		if line_ending_size > 0 {
+			let line_ending_end = state.scanned_size + line_ending_size;
+			if config.emit_newlines {
+				let context = LexerContext { tab_count: state.tab_count, line: cursor.line_count };
+				let token = W::new_scope_level(state.tab_count);
+				callback(token, state.scanned_size..line_ending_end, context);
+				cursor.token_count += 1;
+			}
+
+			state.scanned_size = line_ending_end;
+			cursor.line_count += 1;
+			state.is_in_new_line = true;
+			continue;
+		}
+
+		// Carriage returns between tokens are neither tokens nor line endings.
+		if src.is_same_needle_at(state.scanned_size, CARRIAGE_RETURN) {
+			state.scanned_size += 1;
+			continue;
+		}
+
+		// Tabs between inlined tokens only separate them.
+		if !state.is_in_new_line && src.is_same_needle_at(state.scanned_size, TAB) {
+			state.scanned_size += 1;
+			continue;
+		}
+
+		// The blank lines are checked before their tabs are counted as the scope level.
+		if state.is_in_new_line && config.blank_line_policy != BlankLinePolicy::EmitScopeLevel {
+			let blank_line_info = blank_line::<T, U, V>(src.clone(), state.scanned_size);
+			if let (RawToken::BlankLine(_), line_end) = blank_line_info {
+				if config.blank_line_policy == BlankLinePolicy::EmitBlankLineToken {
+					let tab_count = state.tab_count;
+					let context = LexerContext { tab_count, line: cursor.line_count };
+					let token = W::new_scope_level(tab_count);
+					callback(token, state.scanned_size..line_end, context);
+					cursor.token_count += 1;
+				}
+
+				state.scanned_size = line_end;
+				continue;
+			}
+		}
+
+		return true;
+	}
+
+	false
+}
+
+/// Lexes the token at the cursor and moves the cursor after it.
+fn lex_token<T, U, V, W, F>(
+	src: &T,
+	config: &LexerConfig,
+	cursor: &mut LexerCursor,
+	policy: BlockPolicy,
+	callback: &mut F
+) -> Result<(), LexFailure>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V>,
+	F: FnMut(W, Range<usize>, LexerContext) {
+	let state = cursor.state;
+
+	// The indentation is counted in the configured unit like the scope levels of the lines.
+	let indentation_width = config.indentation_width();
+
+	if let (true, Some(max_tabs)) = (state.is_in_new_line, config.max_tab_depth) {
+		let line = src.clone().forward_slice(state.scanned_size);
+		let allowed_end = find_indentation_end(&line, max_tabs, indentation_width);
+		if find_indentation_end(&line, max_tabs + 1, indentation_width) > allowed_end {
+			return Err(LexFailure::Rejected(ScopeError {
+				kind: ScopeErrorKind::LevelTooDeep,
+				token_index: cursor.token_count,
+				from_level: state.tab_count,
+				to_level: max_tabs + 1
+			}));
+		}
+	}
+
+	if state.is_in_new_line && config.reject_indentation_jumps {
+		let line = src.clone().forward_slice(state.scanned_size);
+		let new_tab_count = count_tabs_with_width(line, state.tab_count, indentation_width);
+		if new_tab_count > state.tab_count + 1 {
+			return Err(LexFailure::Rejected(ScopeError {
+				kind: ScopeErrorKind::LevelJumpTooLarge,
+				token_index: cursor.token_count,
+				from_level: state.tab_count,
+				to_level: new_tab_count
+			}));
+		}
+	}
+
+	let info = if config.strip_cr {
+		any_before_carriage_return(src, &state, config)
+	} else {
+		None
+	};
+	let info = info.unwrap_or_else(|| any_with_diagnostic(
+		src.clone(),
+		state.scanned_size,
+		state.tab_count,
+		state.is_in_new_line,
+		config
+	));
+	let ((token, last_seen_index), diagnostic) = match info {
+		Ok(info) => info,
+		Err(cause) => {
+			let line_ending = find_line_ending(src, state.scanned_size);
+			return Err(skip(src, cursor, state.scanned_size..line_ending, cause));
+		}
+	};
+
+	if let (BlockPolicy::Reject, Some(cause)) = (policy, diagnostic) {
+		return Err(skip(src, cursor, state.scanned_size..last_seen_index, cause));
+	}
+
+	let tab_count = if W::kind(&token) != TokenKind::ScopeLevel {
+		state.tab_count
+	} else if indentation_width > 0 {
+		let indentation = src.clone().slice(state.scanned_size, last_seen_index);
+		count_tabs_with_width(indentation, 0, indentation_width)
+	} else {
+		last_seen_index - state.scanned_size
+	};
+
+	if config.emit_intermediate_scope_levels && W::kind(&token) == TokenKind::ScopeLevel {
+		for level in (tab_count + 1..state.tab_count).rev() {
+			let context = LexerContext { tab_count: level, line: cursor.line_count };
+			callback(W::new_scope_level(level), state.scanned_size..state.scanned_size, context);
+			cursor.token_count += 1;
+		}
+	}
+
+	let context = LexerContext { tab_count, line: cursor.line_count };
+	callback(token, state.scanned_size..last_seen_index, context);
+	cursor.token_count += 1;
+	cursor.state.tab_count = tab_count;
+	move_cursor(src, cursor, last_seen_index);
+
+	Ok(())
+}
+
+/// Moves the cursor after the skipped range and returns the error for it.
+fn skip<T>(
+	src: &T,
+	cursor: &mut LexerCursor,
+	skipped_range: Range<usize>,
+	cause: UnexpectedTokenError
+) -> LexFailure
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	move_cursor(src, cursor, skipped_range.end);
+	LexFailure::Skipped(LexError { skipped_range, cause })
+}
+
+/// Moves the cursor to the last seen index while counting the lines passed.
+fn move_cursor<T>(src: &T, cursor: &mut LexerCursor, last_seen_index: usize)
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	let state = &mut cursor.state;
+
+	// Block tokens occupy more than one line.
+	cursor.line_count += (state.scanned_size..last_seen_index)
+		.filter(|&index| src.is_same_needle_at(index, NEW_LINE))
+		.count();
+	state.is_in_new_line = ends_with_new_line(src, state.scanned_size, last_seen_index);
+	state.scanned_size = last_seen_index;
+}
+
+/// Lexes the current line without its carriage return so that the inlined tokens would not
+/// contain it. Block tokens are lexed again with the whole source since they span many lines.
+fn any_before_carriage_return<T, U, V, W>(src: &T, state: &LexerState, config: &LexerConfig)
+-> Option<Result<DiagnosedTokenInfo<W>, UnexpectedTokenError>>
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> {
+	let line_end = find_line_ending(src, state.scanned_size);
+	if line_end == state.scanned_size || !src.is_same_needle_at(line_end - 1, CARRIAGE_RETURN) {
+		return None;
+	}
+
+	let line = src.clone().slice(0, line_end - 1);
+	let info = any_with_diagnostic::<T, U, V, W>(
+		line,
+		state.scanned_size,
+		state.tab_count,
+		state.is_in_new_line,
+		config
+	);
+	match info {
+		Ok(((ref token, _), _)) if W::kind(token) == TokenKind::BlockComment => None,
+		Ok(((ref token, _), _)) if W::kind(token) == TokenKind::BlockOthertongue => None,
+		info => Some(info)
+	}
+}
+
+/// Returns true if the token ends with the line ending it consumed, like a block token. The next
+/// token would then be at the start of a new line.
+fn ends_with_new_line<T>(src: &T, start: usize, end: usize) -> bool
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> {
+	end > start && src.is_same_needle_at(end - 1, NEW_LINE)
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::abstracts::{
+		AbstractSource,
+		AbstractTokenQueue,
+		SimpleAbstractToken,
+		ComparableAbstractSource
+	};
+	use crate::token::{Token, TokenKind};
+
+	use crate::source_too_large_error::SourceTooLargeError;
+
+	use crate::lex_error::LexError;
+	use crate::lexer_config::{BlankLinePolicy, LexerConfig};
+	use crate::lexer_context::{LexerContext, SpannedToken};
+	use crate::scope_error::{ScopeError, ScopeErrorKind};
+	use crate::configured_lex_error::ConfiguredLexError;
+	use crate::unexpected_token_error::UnexpectedTokenError;
+	use crate::source_map::SourceMap;
+
+	use proptest::{collection, proptest, sample};
+
+	use super::{
+		lex,
+		lex_at_depth,
+		lex_at_depth_with_config,
+		lex_bounded,
+		lex_chunked,
+		lex_each,
+		lex_no_comments,
+		lex_recover,
+		lex_spanned,
+		lex_vec,
+		lex_with_config,
+		lex_with_context,
+		lex_with_limit,
+		lex_with_lines,
+		lex_with_max_depth,
+		lex_with_max_depth_and_config,
+		lex_with_progress,
+		lex_with_source_map,
+		lex_with_token_kinds
+	};
+
+	type BoundedResult = Result<
+		VecDeque<Token<Range<usize>, Vec<Range<usize>>>>,
+		SourceTooLargeError
+	>;
+	type ConfiguredResult = Result<Queue, ConfiguredLexError>;
+	type MaxDepthResult = (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, usize);
+	type LimitedResult = (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, bool);
+	type SpannedResult = (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, Vec<Range<usize>>);
+	type ContextualToken = SpannedToken<Token<Range<usize>, Vec<Range<usize>>>>;
+	type RecoveredResult = (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, Vec<LexError>);
+	type LineNumberedResult = (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, Vec<usize>);
+	type KindedResult = (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, Vec<TokenKind>);
+	type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+
+	fn assert_lex_deterministic<T>(src: T)
+	where
+		T: AbstractSource + ComparableAbstractSource<&'static str> + Clone {
+		let first_token_queue: Queue = lex(&src, VecDeque::new());
+		let second_token_queue: Queue = lex(&src, VecDeque::new());
+
+		assert_eq!(first_token_queue, second_token_queue);
+	}
+
+	#[test]
+	fn can_lex_line_comment() {
+		let source = b"# Hello World";
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_line_comment(1..13));
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_block_comment() {
+		let source = b"###\nHello world\n###";
+		let first_index = 4;
+		let last_index = source.len() - 4;
+		let expected_lines = vec![first_index..last_index];
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_block_comment(expected_lines));
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_block_comment_without_text_of_opening_line() {
+		let source = b"###abc\nx\ny\n###";
+
+		let token_queue: Queue = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![Token::new_block_comment(vec![7..8, 9..10])]));
+	}
+
+	#[test]
+	fn can_lex_simplex() {
+		let source = b"hello_world|";
+		let last_index = source.len() - 1;
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_simplex(0..last_index));
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_complex() {
+		let source = b"HelloWorld";
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_complex(0..source.len()));
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_attacher() {
+		let source = b"hello:	world";
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_attacher(0..5, 7..12));
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_inlined_attachers() {
+		let source = b"a:\tb\tc:\td";
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_attacher(0..1, 3..4),
+			Token::new_attacher(5..6, 8..9)
+		]));
+	}
+
+	#[test]
+	fn can_lex_line_othertongue() {
+		let source = b"= hello-world";
+		let first_index = 2;
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_line_othertongue(first_index..source.len()));
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_complex_with_inlined_othertongue() {
+		let source = b"hello = world";
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_complex(0..5),
+			Token::new_line_othertongue(8..13)
+		]));
+	}
+
+	#[test]
+	fn can_lex_block_othertongue() {
+		let source = b"===\nhelloWorld\n===";
+		let first_index = 4;
+		let last_index = source.len() - 4;
+		let expected_lines = vec![first_index..last_index];
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_block_othertongue(expected_lines));
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_initial_empty_scopes() {
+		let source = b"\t\t";
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(2)
+		);
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_sources_with_tabs_only() {
+		let single_tab: Queue = lex(&&b"\t"[..], VecDeque::new());
+		let many_tabs: Queue = lex(&&b"\t\t\t"[..], VecDeque::new());
+		let tab_before_word: Queue = lex(&&b"\t\nword"[..], VecDeque::new());
+
+		assert_eq!(single_tab, VecDeque::from(vec![Token::new_scope_level(1)]));
+		assert_eq!(many_tabs, VecDeque::from(vec![Token::new_scope_level(3)]));
+		assert_eq!(tab_before_word, VecDeque::from(vec![
+			Token::new_scope_level(1),
+			Token::new_scope_level(0),
+			Token::new_complex(2..6)
+		]));
+	}
+
+	#[test]
+	fn can_lex_skipping_carriage_returns_between_tokens() {
+		let source = b"a\t\rb\n\r\rc";
+
+		let token_queue: Queue = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_complex(3..4),
+			Token::new_complex(7..8)
+		]));
+	}
+
+	#[test]
+	fn can_lex_interleaved_scope_levels_and_tokens() {
+		let source = b"a\n\tb\n\t\tc|\n\t\td:\te\n\tf\ng";
+
+		let token_queue: Queue = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_complex(3..4),
+			Token::new_scope_level(2),
+			Token::new_simplex(7..8),
+			Token::new_attacher(12..13, 15..16),
+			Token::new_scope_level(1),
+			Token::new_complex(18..19),
+			Token::new_scope_level(0),
+			Token::new_complex(20..21)
+		]));
+	}
+
+	#[test]
+	fn can_lex_scope_levels_after_blocks() {
+		let source = b"a\n\t###\n\tb\n\tc\n\t###\n\t\td\ne";
+
+		let token_queue: Queue = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_block_comment(vec![7..9, 10..12]),
+			Token::new_scope_level(2),
+			Token::new_complex(20..21),
+			Token::new_scope_level(0),
+			Token::new_complex(22..23)
+		]));
+	}
+
+	#[test]
+	fn can_lex_dedented_line_with_inner_tabs() {
+		let source = b"a\n\tb\nc\t\td|";
+
+		let token_queue: Queue = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_complex(3..4),
+			Token::new_scope_level(0),
+			Token::new_complex(5..6),
+			Token::new_simplex(8..9)
+		]));
+	}
+
+	proptest! {
+		#[test]
+		fn can_lex_generated_sources_deterministically(
+			source in collection::vec(sample::select(&b"ab :|#=\t\n\r"[..]), 0..48)
+		) {
+			assert_lex_deterministic(&source[..]);
+		}
+	}
+
+	#[test]
+	fn can_lex_with_limit() {
+		let source = b"a\n\tb:\tc\td|\n";
+
+		let (token_queue, is_limited): LimitedResult
+			= lex_with_limit(&&source[..], VecDeque::new(), 3);
+
+		assert!(is_limited);
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_attacher(3..4, 6..7)
+		]));
+	}
+
+	#[test]
+	fn can_lex_within_limit() {
+		let source = b"a\n\tb:\tc\td|\n";
+
+		let (token_queue, is_limited): LimitedResult
+			= lex_with_limit(&&source[..], VecDeque::new(), 4);
+		let (empty_queue, is_empty_limited): LimitedResult
+			= lex_with_limit(&&b""[..], VecDeque::new(), 0);
+
+		assert!(!is_limited);
+		assert_eq!(token_queue.len(), 4);
+		assert!(!is_empty_limited);
+		assert!(empty_queue.is_empty());
+	}
+
+	#[test]
+	fn can_lex_with_limit_like_lex() {
+		let source = &b"a\r\n\tb|\r\n\t\tc:\td\r\n"[..];
+
+		let (token_queue, is_limited): LimitedResult = lex_with_limit(&source, VecDeque::new(), 5);
+
+		assert!(!is_limited);
+		assert_eq!(token_queue, lex(&source, VecDeque::new()));
+	}
+
+	#[test]
+	fn can_lex_initially_indented_concept() {
+		let source = b"\t\tconcept\n\tb|";
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_scope_level(2),
+			Token::new_complex(2..9),
+			Token::new_scope_level(1),
+			Token::new_simplex(11..12)
+		]));
+	}
+
+	#[test]
+	fn can_lex_some_empty_scopes() {
+		let source = b"a\n\t\t\nb";
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_complex(0..1)
+		);
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(2)
+		);
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(0)
+		);
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_complex(5..6)
+		);
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_without_comments() {
+		let source = b"# a\nb\n\t# c\n\td|\n###\ne\n###";
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_complex(4..5)
+		);
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(1)
+		);
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_simplex(12..13)
+		);
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(0)
+		);
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex_no_comments(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, expected_token_queue);
+	}
+
+	#[test]
+	fn can_lex_each_with_ranges() {
+		let source = b"a:\tb\n\t\tc\n#d";
+		let mut tokens = Vec::new();
+
+		lex_each(&source[..], |token: Token<Range<usize>, Vec<Range<usize>>>, range| {
+			tokens.push((token, range));
+		});
+
+		assert_eq!(tokens, vec![
+			(Token::new_attacher(0..1, 3..4), 0..4),
+			(Token::new_scope_level(2), 5..7),
+			(Token::new_complex(7..8), 7..8),
+			(Token::new_scope_level(0), 9..9),
+			(Token::new_line_comment(10..11), 9..11)
+		]);
+	}
+
+	#[test]
+	fn can_lex_source_within_limit() {
+		let source = b"abc";
+		let mut expected_token_queue = VecDeque::new();
+		expected_token_queue.push_token(
+			Token::<Range<usize>, Vec<Range<usize>>>::new_complex(0..3)
+		);
+
+		let token_queue: BoundedResult = lex_bounded(&&source[..], 3, VecDeque::new());
+
+		assert_eq!(token_queue, Ok(expected_token_queue));
+	}
+
+	#[test]
+	fn cannot_lex_source_beyond_limit() {
+		let source = [b'a'; 1000];
+
+		let token_queue: BoundedResult = lex_bounded(&&source[..], 10, VecDeque::new());
+
+		assert_eq!(token_queue, Err(SourceTooLargeError { actual_size: 1000, limit: 10 }));
+	}
+
+	#[test]
+	fn can_lex_empty_block_comment_like_lex_if_nested_comments_are_configured() {
+		let source = b"###\n###\nx";
+		let config = LexerConfig {
+			nested_block_comments: true,
+			..LexerConfig::default()
+		};
+
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
+
+		assert_eq!(token_queue, Ok(lex(&&source[..], VecDeque::new())));
+	}
 
-/// Returns a stream of tokens based from the source.
-///
-/// The source is the first argument which contain an array of bytes. This is the main lexer.
-///
-/// ## Examples
-/// ```
-/// use std::ops::Range;
-/// use std::collections::VecDeque;
-/// use abstract_chearmyp_token::{AbstractToken, AbstractTokenQueue};
-/// use chearmyp_lexer::lex;
-/// use chearmyp_token::Token;
-/// let source = b"
-/// a complex
-/// 	this:	is an attacher
-/// 	a simplex|
-/// ## This is a line comment
-/// ";
-///
-/// let queue: VecDeque<
-/// 	Token<
-/// 		Range<usize>,
-/// 		Vec<Range<usize>>
-/// 	>
-/// > = lex(&&source[..], VecDeque::new());
-///
-/// assert_eq!(queue[0], Token::<Range<usize>, Vec<Range<usize>>>::new_complex(1..10));
-/// assert_eq!(queue[1], Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(1));
-/// assert_eq!(queue[2], Token::<Range<usize>, Vec<Range<usize>>>::new_attacher(12..16, 18..32));
-/// assert_eq!(queue[3], Token::<Range<usize>, Vec<Range<usize>>>::new_simplex(34..43));
-/// assert_eq!(queue[4], Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(0));
-/// assert_eq!(queue[5], Token::<Range<usize>, Vec<Range<usize>>>::new_line_comment(46..69));
-/// ```
-pub fn lex<T, U, V, W, X, Y>(src: &T, mut token_queue: Y) -> Y
-where
-	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone,
-	U: AbstractBoundary<usize>,
-	V: AbstractBoundaryCollection<usize, U>,
-	W: AbstractToken<usize, U, usize, U, V> + From<X>,
-	X: AbstractScopeLevelToken + From<W>,
-	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
-	let mut tab_count = 0;
-	let mut scanned_size = 0;
-	let mut is_in_new_line = true;
+	#[test]
+	fn can_lex_indentation_jump_by_default() {
+		let source = b"a\n\t\t\tb";
 
-	while !src.is_empty_at(scanned_size) {
-		if src.is_same_needle_at(scanned_size, NEW_LINE) {
-			scanned_size += 1;
-			is_in_new_line = true;
-			continue;
-		}
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &LexerConfig::default());
 
-		let (token, last_seen_index) = any(src.clone(), scanned_size, tab_count, is_in_new_line);
-		if W::kind(&token) == TokenKind::ScopeLevel {
-			let scope_level_token = X::from(token);
-			let new_scope_level = X::level(&scope_level_token);
-			tab_count = new_scope_level;
-			let token = W::from(scope_level_token);
-			token_queue.push_token(token);
-		} else {
-			token_queue.push_token(token);
-		}
+		assert_eq!(token_queue.map(|token_queue| token_queue.len()), Ok(3));
+	}
 
-		scanned_size = last_seen_index;
-		is_in_new_line = false;
+	#[test]
+	fn cannot_lex_rejected_indentation_jump() {
+		let source = b"a\n\tb\n\t\t\tc";
+		let config = LexerConfig {
+			reject_indentation_jumps: true,
+			..LexerConfig::default()
+		};
+
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
+
+		assert_eq!(token_queue, Err(ConfiguredLexError::Scope(ScopeError {
+			kind: ScopeErrorKind::LevelJumpTooLarge,
+			token_index: 3,
+			from_level: 1,
+			to_level: 3
+		})));
 	}
 
-	token_queue
-}
+	#[test]
+	fn cannot_lex_rejected_space_indentation_jump() {
+		let source = b"a\n  b\n      c";
+		let config = LexerConfig {
+			reject_indentation_jumps: true,
+			indent_char: b' ',
+			indent_width: 2,
+			..LexerConfig::default()
+		};
 
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
 
-#[cfg(test)]
-mod t {
-	use crate::native::{Range, Vec, VecDeque};
-	use crate::abstracts::{SimpleAbstractToken, AbstractTokenQueue};
-	use crate::token::Token;
+		assert_eq!(token_queue, Err(ConfiguredLexError::Scope(ScopeError {
+			kind: ScopeErrorKind::LevelJumpTooLarge,
+			token_index: 3,
+			from_level: 1,
+			to_level: 3
+		})));
+	}
+
+	#[test]
+	fn can_lex_spaces_as_tabs_if_configured() {
+		let source = b"a\n    b:\tc\n\t    d|\ne";
+		let config = LexerConfig {
+			indent_char: b' ',
+			indent_width: 4,
+			..LexerConfig::default()
+		};
 
-	use super::lex;
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
+
+		assert_eq!(token_queue, Ok(VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_attacher(6..7, 9..10),
+			Token::new_scope_level(2),
+			Token::new_simplex(16..17),
+			Token::new_scope_level(0),
+			Token::new_complex(19..20)
+		])));
+	}
 
 	#[test]
-	fn can_lex_line_comment() {
-		let source = b"# Hello World";
-		let mut expected_token_queue = VecDeque::new();
-		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_line_comment(1..13));
+	fn can_lex_space_indentation_if_configured() {
+		let source = b"a\n  b:\tc\n    d|\ne";
+		let config = LexerConfig {
+			indent_char: b' ',
+			indent_width: 2,
+			..LexerConfig::default()
+		};
 
-		let token_queue: VecDeque<
-			Token<Range<usize>, Vec<Range<usize>>>
-		> = lex(&&source[..], VecDeque::new());
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
 
-		assert_eq!(token_queue, expected_token_queue);
+		assert_eq!(token_queue, Ok(VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_attacher(4..5, 7..8),
+			Token::new_scope_level(2),
+			Token::new_simplex(13..14),
+			Token::new_scope_level(0),
+			Token::new_complex(16..17)
+		])));
 	}
 
 	#[test]
-	fn can_lex_block_comment() {
-		let source = b"###\nHello world\n###";
-		let first_index = 4;
-		let last_index = source.len() - 4;
-		let expected_lines = vec![first_index..last_index];
-		let mut expected_token_queue = VecDeque::new();
-		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_block_comment(expected_lines));
+	fn can_close_space_indented_blocks_if_configured() {
+		let source = b"a\n  ===\n  b\n  ===\nc";
+		let config = LexerConfig {
+			indent_char: b' ',
+			indent_width: 2,
+			..LexerConfig::default()
+		};
 
-		let token_queue: VecDeque<
-			Token<Range<usize>, Vec<Range<usize>>>
-		> = lex(&&source[..], VecDeque::new());
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
 
-		assert_eq!(token_queue, expected_token_queue);
+		assert_eq!(token_queue, Ok(VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_block_othertongue(vec![8..11]),
+			Token::new_scope_level(0),
+			Token::new_complex(18..19)
+		])));
 	}
 
 	#[test]
-	fn can_lex_simplex() {
-		let source = b"hello_world|";
-		let last_index = source.len() - 1;
-		let mut expected_token_queue = VecDeque::new();
-		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_simplex(0..last_index));
+	fn can_lex_crlf_like_lf_if_configured() {
+		let lf_source = b"a\n\tb:\tc\n\td|\n\t# e\nf";
+		let crlf_source = b"a\r\n\tb:\tc\r\n\td|\r\n\t# e\r\nf";
+		let config = LexerConfig {
+			strip_cr: true,
+			..LexerConfig::default()
+		};
 
-		let token_queue: VecDeque<
-			Token<Range<usize>, Vec<Range<usize>>>
-		> = lex(&&source[..], VecDeque::new());
+		let lf_token_queue: ConfiguredResult
+			= lex_with_config(&&lf_source[..], VecDeque::new(), &config);
+		let crlf_token_queue: ConfiguredResult
+			= lex_with_config(&&crlf_source[..], VecDeque::new(), &config);
 
-		assert_eq!(token_queue, expected_token_queue);
+		assert_eq!(lf_token_queue, Ok(VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_attacher(3..4, 6..7),
+			Token::new_simplex(9..10),
+			Token::new_line_comment(14..16),
+			Token::new_scope_level(0),
+			Token::new_complex(17..18)
+		])));
+		assert_eq!(crlf_token_queue, Ok(VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_attacher(4..5, 7..8),
+			Token::new_simplex(11..12),
+			Token::new_line_comment(17..19),
+			Token::new_scope_level(0),
+			Token::new_complex(21..22)
+		])));
 	}
 
 	#[test]
-	fn can_lex_complex() {
-		let source = b"HelloWorld";
-		let mut expected_token_queue = VecDeque::new();
-		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_complex(0..source.len()));
+	fn cannot_lex_block_with_fewer_lines_than_minimum() {
+		let source = b"a\n===\n===\nb";
+		let config = LexerConfig {
+			min_block_lines: 1,
+			..LexerConfig::default()
+		};
 
-		let token_queue: VecDeque<
-			Token<Range<usize>, Vec<Range<usize>>>
-		> = lex(&&source[..], VecDeque::new());
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
 
-		assert_eq!(token_queue, expected_token_queue);
+		assert_eq!(token_queue, Err(ConfiguredLexError::Token(LexError {
+			skipped_range: 2..10,
+			cause: UnexpectedTokenError {
+				byte_offset: 2,
+				raw_token_kind_debug: String::from("BlockTooShort")
+			}
+		})));
 	}
 
 	#[test]
-	fn can_lex_attacher() {
-		let source = b"hello:	world";
-		let mut expected_token_queue = VecDeque::new();
-		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_attacher(0..5, 7..12));
+	fn cannot_lex_block_with_more_lines_than_maximum() {
+		let source = b"###\na\nb:\tc\n###\nd";
+		let config = LexerConfig {
+			max_block_lines: Some(1),
+			..LexerConfig::default()
+		};
 
-		let token_queue: VecDeque<
-			Token<Range<usize>, Vec<Range<usize>>>
-		> = lex(&&source[..], VecDeque::new());
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
 
-		assert_eq!(token_queue, expected_token_queue);
+		assert_eq!(token_queue, Err(ConfiguredLexError::Token(LexError {
+			skipped_range: 0..15,
+			cause: UnexpectedTokenError {
+				byte_offset: 0,
+				raw_token_kind_debug: String::from("BlockTooLong")
+			}
+		})));
 	}
 
 	#[test]
-	fn can_lex_line_othertongue() {
-		let source = b"= hello-world";
-		let first_index = 2;
-		let mut expected_token_queue = VecDeque::new();
-		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_line_othertongue(first_index..source.len()));
+	fn cannot_lex_indentation_deeper_than_limit() {
+		let source = b"a\n\tb\n\t\tc\n\t\t\t\t\td";
+		let config = LexerConfig {
+			max_tab_depth: Some(2),
+			..LexerConfig::default()
+		};
 
-		let token_queue: VecDeque<
-			Token<Range<usize>, Vec<Range<usize>>>
-		> = lex(&&source[..], VecDeque::new());
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
 
-		assert_eq!(token_queue, expected_token_queue);
+		assert_eq!(token_queue, Err(ConfiguredLexError::Scope(ScopeError {
+			kind: ScopeErrorKind::LevelTooDeep,
+			token_index: 5,
+			from_level: 2,
+			to_level: 3
+		})));
 	}
 
 	#[test]
-	fn can_lex_block_othertongue() {
-		let source = b"===\nhelloWorld\n===";
-		let first_index = 4;
-		let last_index = source.len() - 4;
-		let expected_lines = vec![first_index..last_index];
-		let mut expected_token_queue = VecDeque::new();
-		expected_token_queue.push_token(Token::<Range<usize>, Vec<Range<usize>>>::new_block_othertongue(expected_lines));
+	fn cannot_lex_space_indentation_deeper_than_limit() {
+		let source = b"a\n  b\n    c";
+		let config = LexerConfig {
+			max_tab_depth: Some(1),
+			indent_char: b' ',
+			indent_width: 2,
+			..LexerConfig::default()
+		};
 
-		let token_queue: VecDeque<
-			Token<Range<usize>, Vec<Range<usize>>>
-		> = lex(&&source[..], VecDeque::new());
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
 
-		assert_eq!(token_queue, expected_token_queue);
+		assert_eq!(token_queue, Err(ConfiguredLexError::Scope(ScopeError {
+			kind: ScopeErrorKind::LevelTooDeep,
+			token_index: 3,
+			from_level: 1,
+			to_level: 2
+		})));
 	}
 
 	#[test]
-	fn can_lex_initial_empty_scopes() {
-		let source = b"\t\t";
-		let mut expected_token_queue = VecDeque::new();
-		expected_token_queue.push_token(
-			Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(2)
-		);
+	fn can_lex_block_followed_by_deeper_line_without_trailing_new_line() {
+		let source = b"a\n\t###\n\tb\n\t###\n\t\tc";
+		let config = LexerConfig {
+			block_consume_trailing_newline: false,
+			..LexerConfig::default()
+		};
 
-		let token_queue: VecDeque<
-			Token<Range<usize>, Vec<Range<usize>>>
-		> = lex(&&source[..], VecDeque::new());
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
 
-		assert_eq!(token_queue, expected_token_queue);
+		assert_eq!(token_queue.map(|token_queue| token_queue.len()), Ok(5));
 	}
 
 	#[test]
-	fn can_lex_some_empty_scopes() {
-		let source = b"a\n\t\t\nb";
+	fn can_lex_intermediate_scope_levels_if_configured() {
+		let source = b"a\n\tb\n\t\tc\n\t\t\td\ne\n\tf";
+		let config = LexerConfig {
+			emit_intermediate_scope_levels: true,
+			..LexerConfig::default()
+		};
+
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
+
+		assert_eq!(token_queue, Ok(VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_complex(3..4),
+			Token::new_scope_level(2),
+			Token::new_complex(7..8),
+			Token::new_scope_level(3),
+			Token::new_complex(12..13),
+			Token::new_scope_level(2),
+			Token::new_scope_level(1),
+			Token::new_scope_level(0),
+			Token::new_complex(14..15),
+			Token::new_scope_level(1),
+			Token::new_complex(17..18)
+		])));
+	}
+
+	#[test]
+	fn can_lex_new_lines_as_scope_levels_if_configured() {
+		let source = b"a\r\n\tb|\n\tc";
+		let config = LexerConfig {
+			emit_newlines: true,
+			strip_cr: true,
+			..LexerConfig::default()
+		};
+
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
+
+		assert_eq!(token_queue, Ok(VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(0),
+			Token::new_scope_level(1),
+			Token::new_simplex(4..5),
+			Token::new_scope_level(1),
+			Token::new_complex(8..9)
+		])));
+	}
+
+	#[test]
+	fn can_lex_ignoring_blank_lines() {
+		let source = b"a\n\t\t\n  \n\tb\n \t";
+		let config = LexerConfig {
+			blank_line_policy: BlankLinePolicy::Ignore,
+			..LexerConfig::default()
+		};
+
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
+
+		assert_eq!(token_queue, Ok(VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_complex(9..10)
+		])));
+	}
+
+	#[test]
+	fn can_lex_blank_lines_as_scope_levels_if_configured() {
+		let source = b"a\n\t\t\n\tb\n  \r\nc";
+		let config = LexerConfig {
+			blank_line_policy: BlankLinePolicy::EmitBlankLineToken,
+			strip_cr: true,
+			..LexerConfig::default()
+		};
+
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
+
+		assert_eq!(token_queue, Ok(VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(0),
+			Token::new_scope_level(1),
+			Token::new_complex(6..7),
+			Token::new_scope_level(1),
+			Token::new_scope_level(0),
+			Token::new_complex(12..13)
+		])));
+	}
+
+	#[test]
+	fn can_lex_indentation_within_limit() {
+		let source = b"a\n\tb\n\t\tc\nd";
+		let config = LexerConfig {
+			max_tab_depth: Some(2),
+			..LexerConfig::default()
+		};
+
+		let token_queue: ConfiguredResult = lex_with_config(&&source[..], VecDeque::new(), &config);
+
+		assert_eq!(token_queue, Ok(lex(&&source[..], VecDeque::new())));
+	}
+
+	#[test]
+	fn can_lex_at_depth() {
+		let source = b"a\n\tb:\tc\n\t\td\n\t# e\nf|";
 		let mut expected_token_queue = VecDeque::new();
 		expected_token_queue.push_token(
-			Token::<Range<usize>, Vec<Range<usize>>>::new_complex(0..1)
-		);
-		expected_token_queue.push_token(
-			Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(2)
-		);
-		expected_token_queue.push_token(
-			Token::<Range<usize>, Vec<Range<usize>>>::new_scope_level(0)
+			Token::<Range<usize>, Vec<Range<usize>>>::new_attacher(3..4, 6..7)
 		);
 		expected_token_queue.push_token(
-			Token::<Range<usize>, Vec<Range<usize>>>::new_complex(5..6)
+			Token::<Range<usize>, Vec<Range<usize>>>::new_line_comment(14..16)
 		);
 
 		let token_queue: VecDeque<
 			Token<Range<usize>, Vec<Range<usize>>>
-		> = lex(&&source[..], VecDeque::new());
+		> = lex_at_depth(&&source[..], 1, VecDeque::new());
 
 		assert_eq!(token_queue, expected_token_queue);
 	}
+
+	#[test]
+	fn can_lex_at_depth_of_space_indentation() {
+		let source = b"a\n  b\n    c\n  d|";
+		let config = LexerConfig {
+			indent_char: b' ',
+			indent_width: 2,
+			..LexerConfig::default()
+		};
+
+		let token_queue: VecDeque<
+			Token<Range<usize>, Vec<Range<usize>>>
+		> = lex_at_depth_with_config(&&source[..], 1, VecDeque::new(), &config);
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_complex(4..5),
+			Token::new_simplex(14..15)
+		]));
+	}
+
+	#[test]
+	fn can_lex_with_max_depth() {
+		let source = b"a\n\t\tb\n\tc\n\t\t\td|";
+
+		let (token_queue, max_depth): MaxDepthResult = lex_with_max_depth(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, lex(&&source[..], VecDeque::new()));
+		assert_eq!(max_depth, 3);
+	}
+
+	#[test]
+	fn can_lex_with_max_depth_of_space_indentation() {
+		let source = b"a\n  b\n    c\nd";
+		let config = LexerConfig {
+			indent_char: b' ',
+			indent_width: 2,
+			..LexerConfig::default()
+		};
+
+		let (token_queue, max_depth): MaxDepthResult
+			= lex_with_max_depth_and_config(&&source[..], VecDeque::new(), &config);
+		let expected_token_queue: ConfiguredResult
+			= lex_with_config(&&source[..], VecDeque::new(), &config);
+
+		assert_eq!(Ok(token_queue), expected_token_queue);
+		assert_eq!(max_depth, 2);
+	}
+
+	#[test]
+	fn can_lex_with_zero_max_depth() {
+		let source = b"a\nb";
+
+		let (_, max_depth): MaxDepthResult = lex_with_max_depth(&&source[..], VecDeque::new());
+
+		assert_eq!(max_depth, 0);
+	}
+
+	#[test]
+	fn can_lex_with_spans() {
+		let source = b"a:\tb\n\t# c\n\td|";
+
+		let (token_queue, spans): SpannedResult = lex_spanned(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, lex(&&source[..], VecDeque::new()));
+		assert_eq!(spans, vec![0..4, 5..6, 6..9, 10..13]);
+	}
+
+	#[test]
+	fn can_lex_with_spans_within_source() {
+		let source = b"a\n===\nb\nc";
+
+		let (token_queue, spans): SpannedResult = lex_spanned(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, lex(&&source[..], VecDeque::new()));
+		assert_eq!(spans, vec![0..1, 2..9]);
+		assert_eq!(&source[spans[1].clone()], b"===\nb\nc");
+	}
+
+	#[test]
+	fn can_lex_without_errors_to_recover() {
+		let source = b"a:\tb\n\t# c\n\t\td|\n\t= e\n###\nf\n###";
+
+		let (token_queue, errors): RecoveredResult = lex_recover(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, lex(&&source[..], VecDeque::new()));
+		assert_eq!(errors, Vec::new());
+	}
+
+	#[test]
+	fn can_recover_from_malformed_blocks() {
+		let source = b"a\n###\nb\n### c\nd";
+
+		let (token_queue, errors): RecoveredResult = lex_recover(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_complex(14..15)
+		]));
+		assert_eq!(errors, vec![LexError {
+			skipped_range: 2..14,
+			cause: UnexpectedTokenError {
+				byte_offset: 2,
+				raw_token_kind_debug: String::from("MalformedBlock")
+			}
+		}]);
+	}
+
+	#[test]
+	fn can_recover_with_inlined_tokens() {
+		for source in [&b"a|\tb|"[..], &b"a:\tb\tc:\td"[..], &b"a:\tb\r\tc"[..]] {
+			let (token_queue, errors): RecoveredResult = lex_recover(&source, VecDeque::new());
+
+			assert_eq!(token_queue, lex(&source, VecDeque::new()));
+			assert_eq!(errors, Vec::new());
+		}
+	}
+
+	#[test]
+	fn can_lex_in_chunks() {
+		let sources = [
+			&b"a:\tb\n\t# c\n\t\td|\n\t= e\n===\nf\n===\ng"[..],
+			&b"a\r\n\tb|\tc:\td\r\n\t\te"[..]
+		];
+
+		for source in sources {
+			let expected_queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>>
+				= lex(&source, VecDeque::new());
+
+			for chunk_size in [0, 1, 5, 64] {
+				let token_queue = lex_chunked(&source, chunk_size, VecDeque::new());
+				assert_eq!(token_queue, expected_queue, "Chunk size: {}", chunk_size);
+			}
+		}
+	}
+
+	#[test]
+	fn can_lex_with_line_numbers() {
+		let source = b"a:\tb\n\t# c\n\t\td|\n\t= e\n===\nf\n===\ng";
+
+		let (token_queue, line_numbers): LineNumberedResult
+			= lex_with_lines(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, lex(&&source[..], VecDeque::new()));
+		assert_eq!(line_numbers, vec![1, 2, 2, 3, 3, 4, 4, 5, 5, 8]);
+	}
+
+	#[test]
+	fn can_lex_with_token_kinds() {
+		let source = b"a\n\tb:\tc\n\t\t\n= d";
+
+		let (token_queue, kinds): KindedResult = lex_with_token_kinds(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, lex::<_, _, _, _, Token<_, _>, _>(&&source[..], VecDeque::new()));
+		assert_eq!(kinds, vec![
+			TokenKind::Complex,
+			TokenKind::ScopeLevel,
+			TokenKind::Attacher,
+			TokenKind::ScopeLevel,
+			TokenKind::ScopeLevel,
+			TokenKind::LineOthertongue
+		]);
+	}
+
+	#[test]
+	fn can_lex_with_source_map() {
+		let source = b"a:\tb\n\t# c\n\t\td|\n\t= e\n===\nf\n===\ng";
+
+		let (token_queue, source_map): (Queue, SourceMap)
+			= lex_with_source_map(&&source[..], VecDeque::new());
+		let (_, spans): SpannedResult = lex_spanned(&&source[..], VecDeque::new());
+		let (_, line_numbers): LineNumberedResult = lex_with_lines(&&source[..], VecDeque::new());
+
+		assert_eq!(token_queue, lex(&&source[..], VecDeque::new()));
+		let lines = spans.into_iter().map(|span| source_map.line_of_token(span)).collect::<Vec<_>>();
+		assert_eq!(lines, line_numbers);
+	}
+
+	#[test]
+	fn can_lex_with_progress() {
+		let source = b"a:\tb\n\t# c\n\t\td|\n\t= e\n===\nf\n===\ng";
+		let mut reports = Vec::new();
+
+		let token_queue: Queue
+			= lex_with_progress(&&source[..], 8, VecDeque::new(), |scanned, total| {
+				reports.push((scanned, total));
+			});
+
+		assert_eq!(token_queue, lex(&&source[..], VecDeque::new()));
+		assert_eq!(reports, vec![(9, 31), (16, 31), (30, 31), (31, 31)]);
+	}
+
+	#[test]
+	fn can_lex_into_vec() {
+		let source = b"a:\tb\n\t# c\n\t\td|\n\t= e\n===\nf\n===\ng";
+
+		let tokens: Vec<Token<Range<usize>, Vec<Range<usize>>>> = lex_vec(&&source[..]);
+
+		assert_eq!(tokens, Vec::from(lex::<_, _, _, _, _, Queue>(&&source[..], VecDeque::new())));
+	}
+
+	#[test]
+	fn can_lex_with_context() {
+		let source = b"a\n\tb\n###\nc\n###\nd";
+
+		let tokens: Vec<ContextualToken> = lex_with_context(&&source[..]);
+
+		let contexts = tokens.iter().map(|token| token.context).collect::<Vec<_>>();
+		assert_eq!(contexts, vec![
+			LexerContext { tab_count: 0, line: 1 },
+			LexerContext { tab_count: 1, line: 2 },
+			LexerContext { tab_count: 1, line: 2 },
+			LexerContext { tab_count: 0, line: 3 },
+			LexerContext { tab_count: 0, line: 3 },
+			LexerContext { tab_count: 0, line: 6 }
+		]);
+		assert_eq!(
+			tokens.into_iter().map(|token| token.token).collect::<VecDeque<_>>(),
+			lex(&&source[..], VecDeque::new())
+		);
+	}
 }
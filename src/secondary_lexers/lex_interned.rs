@@ -0,0 +1,170 @@
+use smallvec::{smallvec, SmallVec};
+use crate::abstracts::{
+	AbstractToken,
+	AbstractSource,
+	AbstractBoundary,
+	AbstractTokenQueue,
+	ComparableAbstractSource,
+	AbstractBoundaryCollection
+};
+use crate::interned_token::{InternedIds, InternedToken};
+use crate::native::{Range, String};
+use crate::secondary_lexers::lex_each;
+use crate::string_interner::StringInterner;
+use crate::token::TokenKind;
+
+/// Returns the tokens found in the source whose strings have been replaced by their IDs.
+///
+/// It works like [`lex()`] but it needs a string interner as the second argument. The strings that
+/// the boundaries of each token cover are interned so tokens with the same strings have the same
+/// IDs. Therefore, the tokens can be compared without reading the source again.
+///
+/// ## Notes
+/// Only available with the `interner` feature. Invalid UTF-8 sequences are replaced with the
+/// replacement character before being interned.
+///
+/// ## Examples
+/// ```
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::{InternedToken, StringInterner};
+/// use chearmyp_lexer::secondary_lexers::lex_interned;
+///
+/// let source = &b"name:\tJuan\nname:\tMaria\nJuan|"[..];
+/// let mut interner = StringInterner::new();
+/// let queue: VecDeque<InternedToken> = lex_interned(&source, &mut interner, VecDeque::new());
+///
+/// let name = interner.id_of("name").unwrap();
+/// let juan = interner.id_of("Juan").unwrap();
+/// assert_eq!(queue[0].ids.as_slice(), &[name, juan]);
+/// assert_eq!(queue[1].ids[0], name);
+/// assert_eq!(queue[2].ids.as_slice(), &[juan]);
+/// assert_eq!(interner.len(), 3);
+/// ```
+///
+/// [`lex()`]: ./fn.lex.html
+pub fn lex_interned<T, Y>(src: &T, interner: &mut StringInterner, mut token_queue: Y) -> Y
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone + AsRef<[u8]>,
+	Y: AbstractTokenQueue<usize, u32, usize, u32, InternedIds, InternedToken> {
+	let bytes = src.as_ref();
+
+	lex_each::<T, RangedBoundary, RangedBoundaries, RangedToken, _>(src.clone(), |token, _| {
+		let token = if token.kind == TokenKind::ScopeLevel {
+			InternedToken::new_scope_level(token.level)
+		} else {
+			let ids = token.ranges.into_iter()
+				.map(|range| interner.intern(&String::from_utf8_lossy(&bytes[range])))
+				.collect();
+			InternedToken { kind: token.kind, ids }
+		};
+
+		token_queue.push_token(token);
+	});
+
+	token_queue
+}
+
+struct RangedBoundary(Range<usize>);
+
+impl AbstractBoundary<usize> for RangedBoundary {
+	fn new(start: usize, end: usize) -> Self {
+		RangedBoundary(start..end)
+	}
+}
+
+struct RangedBoundaries(SmallVec<[Range<usize>; 2]>);
+
+impl AbstractBoundaryCollection<usize, RangedBoundary> for RangedBoundaries {
+	fn new(start: usize, end: usize) -> Self {
+		RangedBoundaries(smallvec![start..end])
+	}
+
+	fn add(&mut self, boundary: RangedBoundary) {
+		self.0.push(boundary.0);
+	}
+}
+
+struct RangedToken {
+	kind: TokenKind,
+	level: usize,
+	ranges: SmallVec<[Range<usize>; 2]>
+}
+
+impl RangedToken {
+	fn new(kind: TokenKind, ranges: SmallVec<[Range<usize>; 2]>) -> Self {
+		RangedToken { kind, level: 0, ranges }
+	}
+}
+
+impl AbstractToken<usize, RangedBoundary, usize, RangedBoundary, RangedBoundaries>
+for RangedToken {
+	fn kind(&self) -> TokenKind {
+		self.kind
+	}
+
+	fn new_scope_level(level: usize) -> Self {
+		RangedToken { kind: TokenKind::ScopeLevel, level, ranges: SmallVec::new() }
+	}
+
+	fn new_line_comment(comment: RangedBoundary) -> Self {
+		Self::new(TokenKind::LineComment, smallvec![comment.0])
+	}
+
+	fn new_block_comment(comment: RangedBoundaries) -> Self {
+		Self::new(TokenKind::BlockComment, comment.0)
+	}
+
+	fn new_simplex(concept: RangedBoundary) -> Self {
+		Self::new(TokenKind::Simplex, smallvec![concept.0])
+	}
+
+	fn new_complex(concept: RangedBoundary) -> Self {
+		Self::new(TokenKind::Complex, smallvec![concept.0])
+	}
+
+	fn new_attacher(label: RangedBoundary, content: RangedBoundary) -> Self {
+		Self::new(TokenKind::Attacher, smallvec![label.0, content.0])
+	}
+
+	fn new_line_othertongue(othertongue: RangedBoundary) -> Self {
+		Self::new(TokenKind::LineOthertongue, smallvec![othertongue.0])
+	}
+
+	fn new_block_othertongue(othertongue: RangedBoundaries) -> Self {
+		Self::new(TokenKind::BlockOthertongue, othertongue.0)
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Vec, VecDeque};
+	use crate::interned_token::InternedToken;
+	use crate::string_interner::StringInterner;
+	use crate::token::TokenKind;
+	use super::lex_interned;
+
+	#[test]
+	fn can_intern_repeated_strings() {
+		let source = &b"a\n\tb:\tc\n\tb:\tc\n# b\n===\nc\n==="[..];
+		let mut interner = StringInterner::new();
+
+		let queue: VecDeque<InternedToken> = lex_interned(&source, &mut interner, VecDeque::new());
+
+		let kinds: Vec<TokenKind> = queue.iter().map(|token| token.kind).collect();
+		assert_eq!(kinds, vec![
+			TokenKind::Complex,
+			TokenKind::ScopeLevel,
+			TokenKind::Attacher,
+			TokenKind::Attacher,
+			TokenKind::ScopeLevel,
+			TokenKind::LineComment,
+			TokenKind::BlockOthertongue
+		]);
+		assert_eq!(queue[1].ids.as_slice(), &[1]);
+		assert_eq!(queue[2], queue[3]);
+		assert_eq!(queue[4].ids.as_slice(), &[0]);
+		assert_eq!(queue[5].ids.as_slice(), &[interner.id_of(" b").unwrap()]);
+		assert_eq!(queue[6].ids.as_slice(), &[interner.id_of("c").unwrap()]);
+		assert_eq!(interner.len(), 4);
+	}
+}
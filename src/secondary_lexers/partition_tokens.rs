@@ -0,0 +1,88 @@
+use crate::abstracts::AbstractToken;
+use crate::native::Vec;
+use crate::native::BTreeMap;
+use crate::readable_token_queue::ReadableTokenQueue;
+use crate::token::TokenKind;
+
+/// Represents the tokens grouped by their kinds.
+///
+/// It is a B-tree map whether the `no_std` feature is enabled or not.
+pub type TokenPartition<W> = BTreeMap<TokenKind, Vec<W>>;
+
+/// Returns the tokens of the token queue grouped by their kinds.
+///
+/// The token queue is drained in a single pass. The tokens in each group keep their order and
+/// there are no groups for the kinds without tokens.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::{AbstractToken, TokenKind};
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::partition_tokens;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a:\tb\nc|\nd:\te";
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>> = lex(&&source[..], VecDeque::new());
+/// let partition = partition_tokens(queue);
+///
+/// assert_eq!(partition[&TokenKind::Attacher], vec![
+/// 	Token::new_attacher(0..1, 3..4),
+/// 	Token::new_attacher(8..9, 11..12)
+/// ]);
+/// assert_eq!(partition[&TokenKind::Simplex], vec![Token::new_simplex(5..6)]);
+/// assert!(!partition.contains_key(&TokenKind::Complex));
+/// ```
+pub fn partition_tokens<U, V, W, Y>(mut queue: Y) -> TokenPartition<W>
+where
+	W: AbstractToken<usize, U, usize, U, V>,
+	Y: ReadableTokenQueue<W> {
+	let mut partition = TokenPartition::new();
+	while let Some(token) = queue.pop_front_token() {
+		partition.entry(W::kind(&token)).or_insert_with(Vec::new).push(token);
+	}
+
+	partition
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::abstracts::AbstractToken;
+	use crate::token::{Token, TokenKind};
+	use super::partition_tokens;
+
+	type SampleToken = Token<Range<usize>, Vec<Range<usize>>>;
+
+	#[test]
+	fn can_partition_tokens_by_kind() {
+		let queue: VecDeque<SampleToken> = VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_simplex(3..4),
+			Token::new_scope_level(0),
+			Token::new_complex(6..7)
+		]);
+
+		let partition = partition_tokens(queue);
+
+		assert_eq!(partition.len(), 3);
+		assert_eq!(partition[&TokenKind::Complex], vec![
+			Token::new_complex(0..1),
+			Token::new_complex(6..7)
+		]);
+		assert_eq!(partition[&TokenKind::ScopeLevel], vec![
+			Token::new_scope_level(1),
+			Token::new_scope_level(0)
+		]);
+		assert_eq!(partition[&TokenKind::Simplex], vec![Token::new_simplex(3..4)]);
+	}
+
+	#[test]
+	fn cannot_partition_empty_queue() {
+		let queue: VecDeque<SampleToken> = VecDeque::new();
+
+		assert!(partition_tokens(queue).is_empty());
+	}
+}
@@ -0,0 +1,109 @@
+use crate::abstracts::{AbstractSource, ComparableAbstractSource};
+use crate::special_characters::{NEW_LINE, TAB};
+
+/// Contains the statistics of a source found before lexing it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PrescanStats {
+	/// The estimated number of tokens that [`lex()`] would push from the source.
+	///
+	/// [`lex()`]: ./fn.lex.html
+	pub estimated_token_count: usize,
+
+	/// The number of lines in the source. An empty source has no lines.
+	pub line_count: usize,
+
+	/// The number of bytes in the longest line, excluding its line ending.
+	pub max_line_len: usize
+}
+
+/// Returns the statistics of the source found in a single pass.
+///
+/// The estimated token count assumes that each non-empty line has a token and that each change of
+/// indentation has a scope level token. Therefore, it can be used to allocate a token queue before
+/// lexing the source without reallocations in usual sources.
+///
+/// ## Notes
+/// The estimate does not lex the source. The lines in blocks are counted as tokens while the tokens
+/// that share a line are counted once. Use [`count_tokens()`] to know the exact count.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::{prescan, PrescanStats};
+/// use chearmyp_token::Token;
+///
+/// let source = &b"a\n\tb:\tc\n\n\td|\ne"[..];
+/// let stats = prescan(source);
+/// assert_eq!(stats, PrescanStats { estimated_token_count: 6, line_count: 5, max_line_len: 5 });
+///
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>>
+/// 	= lex(&source, VecDeque::with_capacity(stats.estimated_token_count));
+/// assert_eq!(queue.len(), 6);
+/// ```
+///
+/// [`count_tokens()`]: ./fn.count_tokens.html
+pub fn prescan<T>(src: T) -> PrescanStats
+where
+	T: AbstractSource + ComparableAbstractSource<&'static str> + Clone {
+	let mut stats = PrescanStats::default();
+	let mut previous_tab_count = 0;
+	let mut offset = 0;
+
+	while !src.is_empty_at(offset) {
+		let line_start = offset;
+		let mut tab_count = 0;
+		while src.is_same_needle_at(offset, TAB) {
+			tab_count += 1;
+			offset += 1;
+		}
+
+		let content_start = offset;
+		while !src.is_empty_at(offset) && !src.is_same_needle_at(offset, NEW_LINE) {
+			offset += 1;
+		}
+
+		if offset > content_start {
+			stats.estimated_token_count += if tab_count == previous_tab_count { 1 } else { 2 };
+			previous_tab_count = tab_count;
+		}
+
+		stats.line_count += 1;
+		stats.max_line_len = stats.max_line_len.max(offset - line_start);
+		if src.is_same_needle_at(offset, NEW_LINE) {
+			offset += 1;
+		}
+	}
+
+	stats
+}
+
+#[cfg(test)]
+mod t {
+	use super::{prescan, PrescanStats};
+
+	#[test]
+	fn can_prescan_empty_source() {
+		assert_eq!(prescan(&b""[..]), PrescanStats::default());
+	}
+
+	#[test]
+	fn can_prescan_blank_lines() {
+		let stats = prescan(&b"\n\t\t\n\n"[..]);
+
+		assert_eq!(stats, PrescanStats {
+			estimated_token_count: 0,
+			line_count: 3,
+			max_line_len: 2
+		});
+	}
+
+	#[test]
+	fn can_estimate_scope_levels() {
+		let stats = prescan(&b"a\n\tb\n\t\tc\nd"[..]);
+
+		assert_eq!(stats.estimated_token_count, 7);
+		assert_eq!(stats.line_count, 4);
+	}
+}
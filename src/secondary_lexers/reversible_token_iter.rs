@@ -0,0 +1,113 @@
+use core::marker::PhantomData;
+use crate::readable_token_queue::ReadableTokenQueue;
+
+/// Represents an iterator that takes the tokens from either end of the token queue.
+///
+/// ## Notes
+/// The tokens are removed from the token queue as they are taken. Use [`Iterator::rev()`] to take
+/// the tokens from the last one.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::ReversibleTokenIter;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb|";
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>> = lex(&&source[..], VecDeque::new());
+/// let mut tokens = ReversibleTokenIter::from(queue);
+///
+/// assert_eq!(tokens.next_back(), Some(Token::new_simplex(3..4)));
+/// assert_eq!(tokens.next(), Some(Token::new_complex(0..1)));
+/// assert_eq!(tokens.next_back(), Some(Token::new_scope_level(1)));
+/// assert_eq!(tokens.next(), None);
+/// ```
+///
+/// [`Iterator::rev()`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.rev
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReversibleTokenIter<W, Y> {
+	queue: Y,
+	token: PhantomData<W>
+}
+
+impl<W, Y> Iterator for ReversibleTokenIter<W, Y>
+where
+	Y: ReadableTokenQueue<W> {
+	type Item = W;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.queue.pop_front_token()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let token_count = self.queue.token_count();
+		(token_count, Some(token_count))
+	}
+}
+
+impl<W, Y> DoubleEndedIterator for ReversibleTokenIter<W, Y>
+where
+	Y: ReadableTokenQueue<W> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.queue.pop_back_token()
+	}
+}
+
+impl<W, Y> ExactSizeIterator for ReversibleTokenIter<W, Y>
+where
+	Y: ReadableTokenQueue<W> {}
+
+impl<W, Y> From<Y> for ReversibleTokenIter<W, Y>
+where
+	Y: ReadableTokenQueue<W> {
+	fn from(queue: Y) -> Self {
+		Self {
+			queue,
+			token: PhantomData
+		}
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::abstracts::AbstractToken;
+	use crate::token::Token;
+	use super::ReversibleTokenIter;
+
+	type SampleToken = Token<Range<usize>, Vec<Range<usize>>>;
+
+	fn create_queue() -> VecDeque<SampleToken> {
+		VecDeque::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_simplex(3..4)
+		])
+	}
+
+	#[test]
+	fn can_iterate_from_last_token() {
+		let tokens: Vec<SampleToken> = ReversibleTokenIter::from(create_queue()).rev().collect();
+
+		assert_eq!(tokens, vec![
+			Token::new_simplex(3..4),
+			Token::new_scope_level(1),
+			Token::new_complex(0..1)
+		]);
+	}
+
+	#[test]
+	fn can_iterate_from_both_ends() {
+		let mut tokens = ReversibleTokenIter::from(create_queue());
+
+		assert_eq!(tokens.len(), 3);
+		assert_eq!(tokens.next(), Some(Token::new_complex(0..1)));
+		assert_eq!(tokens.next_back(), Some(Token::new_simplex(3..4)));
+		assert_eq!(tokens.next_back(), Some(Token::new_scope_level(1)));
+		assert_eq!(tokens.next(), None);
+		assert_eq!(tokens.next_back(), None);
+	}
+}
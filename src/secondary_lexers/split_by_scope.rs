@@ -0,0 +1,118 @@
+use core::mem;
+use crate::abstracts::{
+	AbstractToken,
+	AbstractBoundary,
+	AbstractTokenQueue,
+	AbstractScopeLevelToken,
+	AbstractBoundaryCollection
+};
+use crate::native::Vec;
+use crate::readable_token_queue::ReadableTokenQueue;
+use crate::token::TokenKind;
+
+/// Returns the token queue split into the runs of tokens between the scope level tokens that enter
+/// or leave the outermost scope.
+///
+/// The scope level tokens that enter a scope from level 0 or return to level 0 separate the token
+/// queues and are not part of any of them. The scope level tokens of the nested scopes are kept in
+/// the token queue of their parent scope. The token queues without tokens are skipped.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::split_by_scope;
+/// use chearmyp_token::Token;
+///
+/// type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+/// let source = b"a\n\tb\n\t\tc\n\td\ne";
+/// let queue: Queue = lex(&&source[..], VecDeque::new());
+/// let scopes: Vec<Queue> = split_by_scope(queue);
+///
+/// assert_eq!(scopes, vec![
+/// 	VecDeque::from(vec![Token::new_complex(0..1)]),
+/// 	VecDeque::from(vec![
+/// 		Token::new_complex(3..4),
+/// 		Token::new_scope_level(2),
+/// 		Token::new_complex(7..8),
+/// 		Token::new_scope_level(1),
+/// 		Token::new_complex(10..11)
+/// 	]),
+/// 	VecDeque::from(vec![Token::new_complex(12..13)])
+/// ]);
+/// ```
+pub fn split_by_scope<U, V, W, X, Y>(mut queue: Y) -> Vec<Y>
+where
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X>,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> + ReadableTokenQueue<W> + Default {
+	let mut scopes = Vec::new();
+	let mut scope = Y::default();
+	let mut level = 0;
+
+	while let Some(token) = queue.pop_front_token() {
+		if W::kind(&token) != TokenKind::ScopeLevel {
+			scope.push_token(token);
+			continue;
+		}
+
+		let scope_level_token = X::from(token);
+		let new_level = scope_level_token.level();
+		if level == 0 || new_level == 0 {
+			if scope.token_count() > 0 {
+				scopes.push(mem::take(&mut scope));
+			}
+		} else {
+			scope.push_token(W::from(scope_level_token));
+		}
+
+		level = new_level;
+	}
+
+	if scope.token_count() > 0 {
+		scopes.push(scope);
+	}
+
+	scopes
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::abstracts::AbstractToken;
+	use crate::token::Token;
+	use super::split_by_scope;
+
+	type SampleQueue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+
+	#[test]
+	fn can_split_by_outermost_scope() {
+		let queue: SampleQueue = VecDeque::from(vec![
+			Token::new_scope_level(1),
+			Token::new_complex(1..2),
+			Token::new_scope_level(0),
+			Token::new_complex(3..4),
+			Token::new_complex(5..6),
+			Token::new_scope_level(1),
+			Token::new_scope_level(0)
+		]);
+
+		let scopes = split_by_scope(queue);
+
+		assert_eq!(scopes, vec![
+			VecDeque::from(vec![Token::new_complex(1..2)]),
+			VecDeque::from(vec![Token::new_complex(3..4), Token::new_complex(5..6)])
+		]);
+	}
+
+	#[test]
+	fn cannot_split_without_tokens() {
+		let scopes = split_by_scope(SampleQueue::new());
+
+		assert_eq!(scopes, Vec::<SampleQueue>::new());
+	}
+}
@@ -0,0 +1,153 @@
+use core::marker::PhantomData;
+use crate::readable_token_queue::ReadableTokenQueue;
+
+/// Represents a token queue with a position to read the tokens one by one.
+///
+/// ## Notes
+/// The token queue can be taken back using [`TokenCursor::into_queue()`].
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::TokenCursor;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb|";
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>> = lex(&&source[..], VecDeque::new());
+/// let mut cursor = TokenCursor::from(queue);
+///
+/// assert_eq!(cursor.advance(), Some(&Token::new_complex(0..1)));
+/// assert_eq!(cursor.peek(), Some(&Token::new_scope_level(1)));
+/// assert_eq!(cursor.peek_offset(1), Some(&Token::new_simplex(3..4)));
+/// assert_eq!(cursor.position(), 1);
+///
+/// cursor.backtrack(1);
+/// assert_eq!(cursor.peek(), Some(&Token::new_complex(0..1)));
+/// ```
+///
+/// [`TokenCursor::into_queue()`]: ./struct.TokenCursor.html#method.into_queue
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenCursor<W, Y> {
+	queue: Y,
+	pos: usize,
+	token: PhantomData<W>
+}
+
+impl<W, Y> TokenCursor<W, Y>
+where
+	Y: ReadableTokenQueue<W> {
+	/// Returns the token at the position and moves the position to the next token.
+	///
+	/// The position does not move if there are no more tokens.
+	pub fn advance(&mut self) -> Option<&W> {
+		let token = self.queue.token_at(self.pos);
+		if token.is_some() {
+			self.pos += 1;
+		}
+
+		token
+	}
+
+	/// Returns the token at the position without moving the position.
+	pub fn peek(&self) -> Option<&W> {
+		self.peek_offset(0)
+	}
+
+	/// Returns the token after a number of tokens from the position without moving the position.
+	pub fn peek_offset(&self, n: usize) -> Option<&W> {
+		self.queue.token_at(self.pos.checked_add(n)?)
+	}
+
+	/// Moves the position back by a number of tokens. It stops at the first token.
+	pub fn backtrack(&mut self, n: usize) {
+		self.pos = self.pos.saturating_sub(n);
+	}
+
+	/// Returns the position of the next token to read.
+	pub fn position(&self) -> usize {
+		self.pos
+	}
+}
+
+impl<W, Y> TokenCursor<W, Y> {
+	/// Returns the token queue used by the cursor.
+	pub fn into_queue(self) -> Y {
+		self.queue
+	}
+}
+
+impl<W, Y> From<Y> for TokenCursor<W, Y>
+where
+	Y: ReadableTokenQueue<W> {
+	fn from(queue: Y) -> Self {
+		Self {
+			queue,
+			pos: 0,
+			token: PhantomData
+		}
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use crate::abstracts::AbstractToken;
+	use crate::token::Token;
+	use super::TokenCursor;
+
+	type SampleToken = Token<Range<usize>, Vec<Range<usize>>>;
+
+	fn create_cursor() -> TokenCursor<SampleToken, Vec<SampleToken>> {
+		TokenCursor::from(vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_simplex(3..4)
+		])
+	}
+
+	#[test]
+	fn can_advance_until_end() {
+		let mut cursor = create_cursor();
+
+		assert_eq!(cursor.advance(), Some(&Token::new_complex(0..1)));
+		assert_eq!(cursor.advance(), Some(&Token::new_scope_level(1)));
+		assert_eq!(cursor.advance(), Some(&Token::new_simplex(3..4)));
+		assert_eq!(cursor.advance(), None);
+		assert_eq!(cursor.position(), 3);
+	}
+
+	#[test]
+	fn can_peek_without_moving() {
+		let cursor = create_cursor();
+
+		assert_eq!(cursor.peek(), Some(&Token::new_complex(0..1)));
+		assert_eq!(cursor.peek_offset(2), Some(&Token::new_simplex(3..4)));
+		assert_eq!(cursor.peek_offset(3), None);
+		assert_eq!(cursor.peek_offset(usize::MAX), None);
+		assert_eq!(cursor.position(), 0);
+	}
+
+	#[test]
+	fn can_backtrack_up_to_first_token() {
+		let mut cursor = create_cursor();
+		cursor.advance();
+		cursor.advance();
+
+		cursor.backtrack(1);
+		assert_eq!(cursor.position(), 1);
+
+		cursor.backtrack(5);
+		assert_eq!(cursor.position(), 0);
+	}
+
+	#[test]
+	fn can_take_back_queue() {
+		let mut cursor = create_cursor();
+		cursor.advance();
+
+		assert_eq!(cursor.into_queue().len(), 3);
+	}
+}
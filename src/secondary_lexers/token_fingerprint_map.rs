@@ -0,0 +1,114 @@
+use crate::abstracts::AbstractSource;
+use crate::helpers::hash_source;
+use crate::native::Vec;
+use crate::native::BTreeMap;
+use crate::raw_token::RawToken;
+use crate::readable_boundary::{ReadableBoundary, ReadableBoundaryCollection};
+use crate::readable_token_queue::ReadableTokenQueue;
+use super::token_to_source_fragment::token_to_source_fragment;
+
+/// Represents the indices of the tokens grouped by the fingerprints of their source fragments.
+///
+/// It is a B-tree map whether the `no_std` feature is enabled or not.
+pub type TokenFingerprintMap = BTreeMap<u64, Vec<usize>>;
+
+/// Returns the indices of the raw tokens in the token queue grouped by their fingerprints.
+///
+/// The fingerprint of a raw token is the FNV-1a digest of its source fragment, the same bytes
+/// written by [`token_to_source_fragment()`]. Raw tokens with equal fragments always have equal
+/// fingerprints so a group with more than one index may contain duplicates. The indices in each
+/// group are in ascending order.
+///
+/// ## Notes
+/// The token queue is only read. Raw tokens without fragment, like the scope levels and empty raw
+/// tokens, have no fingerprint. Since the fragments include the delimiters, raw tokens of different
+/// kinds with the same text have different fingerprints.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::RawToken;
+/// use chearmyp_lexer::helpers::hash_source;
+/// use chearmyp_lexer::primary_lexers::attacher;
+/// use chearmyp_lexer::secondary_lexers::build_token_fingerprint_map;
+///
+/// let source = b"a:\tb\na:\tb\nc:\td";
+/// let queue: Vec<RawToken<Range<usize>, Vec<Range<usize>>>> = [0, 5, 10].iter()
+/// 	.map(|&offset| attacher(&source[..], offset, offset).0)
+/// 	.collect();
+/// let fingerprint_map = build_token_fingerprint_map(&&source[..], &queue);
+///
+/// assert_eq!(fingerprint_map[&hash_source(b"a:\tb")], vec![0, 1]);
+/// assert_eq!(fingerprint_map[&hash_source(b"c:\td")], vec![2]);
+/// assert_eq!(fingerprint_map.len(), 2);
+/// ```
+///
+/// [`token_to_source_fragment()`]: ./fn.token_to_source_fragment.html
+pub fn build_token_fingerprint_map<T, U, V, Y>(src: &T, queue: &Y) -> TokenFingerprintMap
+where
+	T: AbstractSource + AsRef<[u8]>,
+	U: ReadableBoundary,
+	V: ReadableBoundaryCollection<U>,
+	Y: ReadableTokenQueue<RawToken<U, V>> {
+	let mut fingerprint_map = TokenFingerprintMap::new();
+	let mut fragment = Vec::new();
+
+	for token_index in 0..queue.token_count() {
+		let raw_token = queue.token_at(token_index).unwrap();
+		if let RawToken::ScopeLevel(_) = raw_token {
+			continue;
+		}
+
+		fragment.clear();
+		token_to_source_fragment(src, raw_token, &mut fragment);
+		if fragment.is_empty() {
+			continue;
+		}
+
+		fingerprint_map.entry(hash_source(&fragment)).or_default().push(token_index);
+	}
+
+	fingerprint_map
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use crate::helpers::hash_source;
+	use crate::raw_token::RawToken;
+	use super::build_token_fingerprint_map;
+
+	type SampleRawToken = RawToken<Range<usize>, Vec<Range<usize>>>;
+
+	#[test]
+	fn can_group_duplicate_tokens() {
+		let source = b"a\n\tb|\n\ta\nb|";
+		let queue: Vec<SampleRawToken> = vec![
+			RawToken::Complex(0..1),
+			RawToken::ScopeLevel(1),
+			RawToken::Simplex(3..4),
+			RawToken::ScopeLevel(1),
+			RawToken::Complex(7..8),
+			RawToken::ScopeLevel(0),
+			RawToken::Simplex(9..10)
+		];
+
+		let fingerprint_map = build_token_fingerprint_map(&&source[..], &queue);
+
+		assert_eq!(fingerprint_map.len(), 2);
+		assert_eq!(fingerprint_map[&hash_source(b"a")], vec![0, 4]);
+		assert_eq!(fingerprint_map[&hash_source(b"b|")], vec![2, 6]);
+	}
+
+	#[test]
+	fn cannot_fingerprint_tokens_without_fragment() {
+		let source = b"\t";
+		let queue: Vec<SampleRawToken> = vec![
+			RawToken::ScopeLevel(1),
+			RawToken::Empty,
+			RawToken::Invalid
+		];
+
+		assert!(build_token_fingerprint_map(&&source[..], &queue).is_empty());
+	}
+}
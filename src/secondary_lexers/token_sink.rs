@@ -0,0 +1,172 @@
+use crate::abstracts::AbstractTokenQueue;
+use crate::native::Range;
+#[cfg(not(feature = "no_std"))]
+use crate::abstracts::AbstractToken;
+#[cfg(not(feature = "no_std"))]
+use crate::token::TokenKind;
+#[cfg(not(feature = "no_std"))]
+use std::io::{Error, Write};
+
+/// Represents a receiver of tokens which processes each token as soon as it has been lexed.
+///
+/// Unlike the token queues, a sink does not need to keep the tokens. It can transform or send them
+/// elsewhere right away. Wrap a token queue in [`QueueSink`] to use it as a sink.
+///
+/// [`QueueSink`]: ./struct.QueueSink.html
+pub trait TokenSink<U, V, W> {
+	/// Receives the token and the range of bytes it occupies in the source.
+	fn sink(&mut self, token: W, byte_range: Range<usize>);
+}
+
+/// Contains the token queue which receives the tokens passed to the sink.
+///
+/// The byte ranges of the tokens are ignored.
+///
+/// ## Notes
+/// The token queues cannot be sinks directly. Implementing the sink trait for every token queue
+/// would prevent other types, like [`WritingSink`], from implementing it.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::secondary_lexers::{lex_sink, QueueSink};
+/// use chearmyp_token::Token;
+///
+/// let mut sink = QueueSink(VecDeque::new());
+/// lex_sink::<_, Range<usize>, Vec<Range<usize>>, Token<_, _>, _>(
+/// 	&&b"a\nb|"[..],
+/// 	&mut sink
+/// );
+///
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>> = sink.0;
+/// assert_eq!(queue.len(), 2);
+/// ```
+///
+/// [`WritingSink`]: ./struct.WritingSink.html
+pub struct QueueSink<Y>(pub Y);
+
+impl<U, V, W, Y> TokenSink<U, V, W> for QueueSink<Y>
+where
+	Y: AbstractTokenQueue<usize, U, usize, U, V, W> {
+	fn sink(&mut self, token: W, _byte_range: Range<usize>) {
+		self.0.push_token(token);
+	}
+}
+
+/// Contains the writer where the tokens are serialized into.
+///
+/// Each token is written as a line which has the kind of the token, the start of its byte range,
+/// and the end of its byte range separated by tabs, like `simplex\t5\t9`.
+///
+/// ## Notes
+/// The first error returned by the writer stops the writing of later tokens. It can be retrieved
+/// through [`WritingSink::into_inner()`]. Not available with the `no_std` feature.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::secondary_lexers::{lex_sink, WritingSink};
+/// use chearmyp_token::Token;
+///
+/// let mut sink = WritingSink::new(Vec::new());
+/// lex_sink::<_, Range<usize>, Vec<Range<usize>>, Token<_, _>, _>(
+/// 	&&b"a\n\tb|"[..],
+/// 	&mut sink
+/// );
+///
+/// let output = sink.into_inner().unwrap();
+/// assert_eq!(output, b"complex\t0\t1\nscope_level\t2\t3\nsimplex\t3\t5\n");
+/// ```
+///
+/// [`WritingSink::into_inner()`]: #method.into_inner
+#[cfg(not(feature = "no_std"))]
+pub struct WritingSink<Z> {
+	writer: Z,
+	error: Option<Error>
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<Z> WritingSink<Z>
+where
+	Z: Write {
+	/// Creates a sink which writes the tokens into the writer.
+	pub fn new(writer: Z) -> Self {
+		Self { writer, error: None }
+	}
+
+	/// Returns the writer or the first error that the writer has returned.
+	pub fn into_inner(self) -> Result<Z, Error> {
+		match self.error {
+			Some(error) => Err(error),
+			None => Ok(self.writer)
+		}
+	}
+}
+
+#[cfg(not(feature = "no_std"))]
+impl<U, V, W, Z> TokenSink<U, V, W> for WritingSink<Z>
+where
+	W: AbstractToken<usize, U, usize, U, V>,
+	Z: Write {
+	fn sink(&mut self, token: W, byte_range: Range<usize>) {
+		if self.error.is_some() {
+			return;
+		}
+
+		let kind = name_kind(W::kind(&token));
+		let result = writeln!(self.writer, "{}\t{}\t{}", kind, byte_range.start, byte_range.end);
+		self.error = result.err();
+	}
+}
+
+#[cfg(not(feature = "no_std"))]
+fn name_kind(kind: TokenKind) -> &'static str {
+	match kind {
+		TokenKind::ScopeLevel => "scope_level",
+		TokenKind::LineComment => "line_comment",
+		TokenKind::BlockComment => "block_comment",
+		TokenKind::Simplex => "simplex",
+		TokenKind::Complex => "complex",
+		TokenKind::Attacher => "attacher",
+		TokenKind::LineOthertongue => "line_othertongue",
+		TokenKind::BlockOthertongue => "block_othertongue"
+	}
+}
+
+#[cfg(all(test, not(feature = "no_std")))]
+mod t {
+	use std::io::{Error, ErrorKind, Write};
+	use crate::native::{Range, Vec};
+	use crate::token::Token;
+	use crate::abstracts::SimpleAbstractToken;
+	use super::{TokenSink, WritingSink};
+
+	type SimpleToken = Token<Range<usize>, Vec<Range<usize>>>;
+
+	struct FullWriter;
+
+	impl Write for FullWriter {
+		fn write(&mut self, _buf: &[u8]) -> Result<usize, Error> {
+			Err(Error::new(ErrorKind::WriteZero, "full"))
+		}
+
+		fn flush(&mut self) -> Result<(), Error> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn can_keep_first_error_of_writer() {
+		let mut sink = WritingSink::new(FullWriter);
+
+		TokenSink::<Range<usize>, Vec<Range<usize>>, SimpleToken>::sink(
+			&mut sink,
+			SimpleToken::new_simplex(0..1),
+			0..2
+		);
+
+		let error = sink.into_inner().err().map(|error| error.kind());
+		assert_eq!(error, Some(ErrorKind::WriteZero));
+	}
+}
@@ -0,0 +1,97 @@
+use crate::abstracts::AbstractSource;
+use crate::native::Vec;
+use crate::token_info::SpannedTokenInfo;
+
+/// Writes the bytes in the source that the spanned token occupies into the buffer.
+///
+/// It needs the source that was lexed as the first argument, the token paired with the byte range
+/// it occupies as the second argument, and the buffer where to write as the third argument. The
+/// spanned tokens can be found by [`any_spanned()`] or by pairing the tokens and the ranges from
+/// [`lex_spanned()`].
+///
+/// ## Notes
+/// The fragment is sliced from the source instead of being rebuilt from the token. Therefore, it
+/// keeps the delimiters, the separators, the carriage returns, the indentation, and the text that
+/// the lexers discard, like the rest of the opening line of a block comment. The end of the range
+/// is capped at the length of the source since the last seen index of an unclosed block is one
+/// more than it.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use chearmyp_lexer::any_spanned;
+/// use chearmyp_lexer::secondary_lexers::token_to_source_fragment;
+/// use chearmyp_token::Token;
+///
+/// let source = b"hello:\tworld\n### note\r\nbye\r\n###";
+/// let spanned_token: (Token<Range<usize>, Vec<Range<usize>>>, Range<usize>)
+/// 	= any_spanned(&source[..], 13, 0, true);
+/// let mut fragment = Vec::new();
+/// token_to_source_fragment(&&source[..], &spanned_token, &mut fragment);
+/// assert_eq!(fragment, b"### note\r\nbye\r\n###");
+/// ```
+///
+/// [`any_spanned()`]: ../fn.any_spanned.html
+/// [`lex_spanned()`]: ./fn.lex_spanned.html
+pub fn token_to_source_fragment<T, W>(src: &T, token: &SpannedTokenInfo<W>, buf: &mut Vec<u8>)
+where
+	T: AbstractSource + AsRef<[u8]> {
+	let bytes = src.as_ref();
+	let (_, span) = token;
+	let end = span.end.min(bytes.len());
+	buf.extend_from_slice(&bytes[span.start.min(end)..end]);
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::secondary_lexers::lex_spanned;
+	use crate::token::Token;
+	use super::token_to_source_fragment;
+
+	type SpannedResult = (VecDeque<Token<Range<usize>, Vec<Range<usize>>>>, Vec<Range<usize>>);
+
+	macro_rules! test_round_trip {
+		($source:literal into $($fragment:literal),+) => {
+			let source = &$source[..];
+			let (token_queue, spans): SpannedResult = lex_spanned(&source, VecDeque::new());
+			let fragments = token_queue.into_iter().zip(spans).map(|spanned_token| {
+				let mut fragment = Vec::new();
+				token_to_source_fragment(&source, &spanned_token, &mut fragment);
+				fragment
+			}).collect::<Vec<_>>();
+
+			assert_eq!(fragments, vec![$($fragment.to_vec()),+]);
+		};
+	}
+
+	#[test]
+	fn can_write_tokens_in_lines() {
+		test_round_trip!(b"a:\tb\nc|\n# d" into b"a:\tb", b"c|", b"# d");
+	}
+
+	#[test]
+	fn can_write_attachers_with_their_separators() {
+		test_round_trip!(b"a: b\nc:\td" into b"a: b", b"c:\td");
+	}
+
+	#[test]
+	fn can_write_block_comment_with_discarded_opening_line() {
+		test_round_trip!(b"### note\nhello\n###" into b"### note\nhello\n###");
+	}
+
+	#[test]
+	fn can_write_blocks_with_crlf() {
+		test_round_trip!(b"###\r\nhello\r\n###" into b"###\r\nhello\r\n###");
+	}
+
+	#[test]
+	fn can_write_empty_block() {
+		test_round_trip!(b"###\n###" into b"###\n###");
+	}
+
+	#[test]
+	fn can_write_unclosed_block() {
+		test_round_trip!(b"a\n===\nb" into b"a", b"===\nb");
+	}
+}
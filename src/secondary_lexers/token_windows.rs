@@ -0,0 +1,87 @@
+use crate::native::Vec;
+use crate::readable_token_queue::ReadableTokenQueue;
+
+/// Returns an iterator of overlapping windows of consecutive tokens from the token queue.
+///
+/// Each window has references to a number of tokens (known as the window size) and starts one
+/// token after the previous window. No more windows will be returned if there are fewer tokens left
+/// than the window size.
+///
+/// ## Notes
+/// The tokens are not copied. A window size of zero returns no windows.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::lex;
+/// use chearmyp_lexer::secondary_lexers::token_windows;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb|";
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>>
+/// 	= lex(&&source[..], VecDeque::new());
+/// let mut windows = token_windows(&queue, 2);
+///
+/// assert_eq!(windows.next(), Some(vec![&Token::new_complex(0..1), &Token::new_scope_level(1)]));
+/// assert_eq!(windows.next(), Some(vec![&Token::new_scope_level(1), &Token::new_simplex(3..4)]));
+/// assert_eq!(windows.next(), None);
+/// ```
+pub fn token_windows<'a, W, Y>(queue: &'a Y, window_size: usize)
+-> impl Iterator<Item = Vec<&'a W>> + 'a
+where
+	W: 'a,
+	Y: ReadableTokenQueue<W> {
+	let window_count = if window_size == 0 {
+		0
+	} else {
+		(queue.token_count() + 1).saturating_sub(window_size)
+	};
+
+	(0..window_count).map(move |start| {
+		(start..start + window_size)
+			.filter_map(|index| queue.token_at(index))
+			.collect()
+	})
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use crate::abstracts::AbstractToken;
+	use crate::token::Token;
+	use super::token_windows;
+
+	type SampleToken = Token<Range<usize>, Vec<Range<usize>>>;
+
+	fn create_queue() -> Vec<SampleToken> {
+		vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_attacher(3..4, 6..7),
+			Token::new_simplex(9..10)
+		]
+	}
+
+	#[test]
+	fn can_yield_overlapping_windows() {
+		let queue = create_queue();
+
+		let windows = token_windows(&queue, 3).collect::<Vec<_>>();
+
+		assert_eq!(windows, vec![
+			vec![&queue[0], &queue[1], &queue[2]],
+			vec![&queue[1], &queue[2], &queue[3]]
+		]);
+	}
+
+	#[test]
+	fn cannot_yield_windows_larger_than_queue() {
+		let queue = create_queue();
+
+		assert_eq!(token_windows(&queue, 5).count(), 0);
+		assert_eq!(token_windows(&queue, 0).count(), 0);
+		assert_eq!(token_windows(&queue, 4).count(), 1);
+	}
+}
@@ -0,0 +1,65 @@
+/// Returns the tokens that start on the line.
+///
+/// It needs the number of the line to look for as the first argument, the tokens as the second
+/// argument, and the line numbers of the tokens as the third argument. The line numbers should be
+/// sorted and parallel to the tokens, like the lines in the contexts returned by
+/// [`lex_with_context()`].
+///
+/// ## Notes
+/// The line numbers are binary searched. If there are more tokens than line numbers, the extra
+/// tokens are never returned. If no token starts on the line, it will return an empty slice.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use abstract_chearmyp_token::AbstractToken;
+/// use chearmyp_lexer::SpannedToken;
+/// use chearmyp_lexer::secondary_lexers::{lex_with_context, tokens_on_line};
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb:\tc\n\td|";
+/// let spanned_tokens: Vec<SpannedToken<Token<Range<usize>, Vec<Range<usize>>>>>
+/// 	= lex_with_context(&&source[..]);
+/// let (tokens, line_numbers): (Vec<_>, Vec<_>) = spanned_tokens.into_iter()
+/// 	.map(|spanned_token| (spanned_token.token, spanned_token.context.line))
+/// 	.unzip();
+///
+/// assert_eq!(tokens_on_line(2, &tokens, &line_numbers), &[
+/// 	Token::new_scope_level(1),
+/// 	Token::new_attacher(3..4, 6..7)
+/// ]);
+/// assert!(tokens_on_line(4, &tokens, &line_numbers).is_empty());
+/// ```
+///
+/// [`lex_with_context()`]: ./fn.lex_with_context.html
+pub fn tokens_on_line<'a, W>(line: usize, tokens: &'a [W], line_numbers: &[usize]) -> &'a [W] {
+	let line_numbers = &line_numbers[..line_numbers.len().min(tokens.len())];
+	let start = line_numbers.partition_point(|&line_number| line_number < line);
+	let end = line_numbers.partition_point(|&line_number| line_number <= line);
+	&tokens[start..end]
+}
+
+#[cfg(test)]
+mod t {
+	use super::tokens_on_line;
+
+	#[test]
+	fn can_find_tokens_on_line() {
+		let tokens = ['a', 'b', 'c', 'd', 'e'];
+		let line_numbers = [1, 2, 2, 2, 4];
+
+		assert_eq!(tokens_on_line(1, &tokens, &line_numbers), &['a']);
+		assert_eq!(tokens_on_line(2, &tokens, &line_numbers), &['b', 'c', 'd']);
+		assert_eq!(tokens_on_line(4, &tokens, &line_numbers), &['e']);
+	}
+
+	#[test]
+	fn cannot_find_tokens_on_other_lines() {
+		let tokens = ['a', 'b'];
+		let line_numbers = [1, 3, 3];
+
+		assert!(tokens_on_line(0, &tokens, &line_numbers).is_empty());
+		assert!(tokens_on_line(2, &tokens, &line_numbers).is_empty());
+		assert_eq!(tokens_on_line(3, &tokens, &line_numbers), &['b']);
+	}
+}
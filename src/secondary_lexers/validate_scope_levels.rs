@@ -0,0 +1,112 @@
+use crate::abstracts::{
+	AbstractToken,
+	AbstractBoundary,
+	AbstractScopeLevelToken,
+	AbstractBoundaryCollection
+};
+use crate::readable_token_queue::ReadableTokenQueue;
+use crate::scope_error::{ScopeError, ScopeErrorKind};
+use crate::token::TokenKind;
+
+/// Returns an error if the scope levels in the token queue do not form a valid step pattern.
+///
+/// The scope level starts at 0. Each scope level token may increase the level by exactly one or
+/// decrease it by any amount. The error contains the first invalid transition found.
+///
+/// ## Examples
+/// ```
+/// use std::ops::Range;
+/// use std::collections::VecDeque;
+/// use chearmyp_lexer::{lex, ScopeError, ScopeErrorKind};
+/// use chearmyp_lexer::secondary_lexers::validate_scope_levels;
+/// use chearmyp_token::Token;
+///
+/// let source = b"a\n\tb\n\t\t\tc\nd";
+/// let queue: VecDeque<Token<Range<usize>, Vec<Range<usize>>>> = lex(&&source[..], VecDeque::new());
+///
+/// assert_eq!(validate_scope_levels(&queue), Err(ScopeError {
+/// 	kind: ScopeErrorKind::LevelJumpTooLarge,
+/// 	token_index: 3,
+/// 	from_level: 1,
+/// 	to_level: 3
+/// }));
+/// ```
+pub fn validate_scope_levels<U, V, W, X, Y>(queue: &Y) -> Result<(), ScopeError>
+where
+	U: AbstractBoundary<usize>,
+	V: AbstractBoundaryCollection<usize, U>,
+	W: AbstractToken<usize, U, usize, U, V> + From<X> + Clone,
+	X: AbstractScopeLevelToken + From<W>,
+	Y: ReadableTokenQueue<W> {
+	let mut level = 0;
+
+	for token_index in 0..queue.token_count() {
+		let token = queue.token_at(token_index).unwrap();
+		if W::kind(token) == TokenKind::ScopeLevel {
+			let new_level = X::level(&X::from(token.clone()));
+			if new_level > level + 1 {
+				return Err(ScopeError {
+					kind: ScopeErrorKind::LevelJumpTooLarge,
+					token_index,
+					from_level: level,
+					to_level: new_level
+				});
+			}
+
+			level = new_level;
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use crate::abstracts::AbstractToken;
+	use crate::scope_error::{ScopeError, ScopeErrorKind};
+	use crate::token::Token;
+	use super::validate_scope_levels;
+
+	type SampleToken = Token<Range<usize>, Vec<Range<usize>>>;
+
+	#[test]
+	fn can_validate_step_pattern() {
+		let queue: Vec<SampleToken> = vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_complex(3..4),
+			Token::new_scope_level(2),
+			Token::new_complex(7..8),
+			Token::new_scope_level(0),
+			Token::new_complex(9..10)
+		];
+
+		assert_eq!(validate_scope_levels(&queue), Ok(()));
+	}
+
+	#[test]
+	fn can_validate_initial_scope_level() {
+		let queue: Vec<SampleToken> = vec![Token::new_scope_level(1), Token::new_complex(1..2)];
+
+		assert_eq!(validate_scope_levels(&queue), Ok(()));
+	}
+
+	#[test]
+	fn cannot_validate_large_jump() {
+		let queue: Vec<SampleToken> = vec![
+			Token::new_complex(0..1),
+			Token::new_scope_level(1),
+			Token::new_complex(3..4),
+			Token::new_scope_level(0),
+			Token::new_scope_level(2)
+		];
+
+		assert_eq!(validate_scope_levels(&queue), Err(ScopeError {
+			kind: ScopeErrorKind::LevelJumpTooLarge,
+			token_index: 4,
+			from_level: 0,
+			to_level: 2
+		}));
+	}
+}
@@ -0,0 +1,152 @@
+use crate::structure_error::{StructureError, StructureErrorKind};
+use crate::token::TokenKind;
+
+/// Returns an error if the kinds of the tokens do not form a valid structure.
+///
+/// It needs the kinds of the tokens in the order they were lexed, like the ones returned by
+/// [`lex_with_token_kinds()`]. A scope level token must not be followed by another scope level
+/// token. The error contains the index of the first token kind that breaks it.
+///
+/// ## Notes
+/// A blank line with only tabs is lexed into a scope level token. Therefore, the kinds from a
+/// source with such lines may have consecutive scope levels. Lex the source with the blank lines
+/// ignored through [`lex_with_config()`] before verifying it.
+///
+/// The intermediate scope levels pushed by [`lex_with_config()`] when
+/// `LexerConfig::emit_intermediate_scope_levels` is true are consecutive too. Since the kinds do
+/// not tell the levels apart, they are rejected like the blank lines. Verify the kinds lexed
+/// without that option instead.
+///
+/// The same goes for the line endings pushed as scope levels when `LexerConfig::emit_newlines` is
+/// true. A line ending followed by a change of indentation or a blank line is rejected.
+///
+/// ## Examples
+/// ```
+/// use abstract_chearmyp_token::TokenKind;
+/// use chearmyp_lexer::{StructureError, StructureErrorKind};
+/// use chearmyp_lexer::secondary_lexers::verify_structure;
+///
+/// let valid_kinds = [TokenKind::Complex, TokenKind::ScopeLevel, TokenKind::Simplex];
+/// assert_eq!(verify_structure(&valid_kinds), Ok(()));
+///
+/// let invalid_kinds = [TokenKind::Complex, TokenKind::ScopeLevel, TokenKind::ScopeLevel];
+/// assert_eq!(verify_structure(&invalid_kinds), Err(StructureError {
+/// 	kind: StructureErrorKind::ConsecutiveScopeLevels,
+/// 	token_index: 2
+/// }));
+/// ```
+///
+/// [`lex_with_token_kinds()`]: ./fn.lex_with_token_kinds.html
+/// [`lex_with_config()`]: ./fn.lex_with_config.html
+pub fn verify_structure(kinds: &[TokenKind]) -> Result<(), StructureError> {
+	for (token_index, pair) in kinds.windows(2).enumerate() {
+		if pair[0] == TokenKind::ScopeLevel && pair[1] == TokenKind::ScopeLevel {
+			return Err(StructureError {
+				kind: StructureErrorKind::ConsecutiveScopeLevels,
+				token_index: token_index + 1
+			});
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec, VecDeque};
+	use crate::abstracts::AbstractToken;
+	use crate::lexer_config::{BlankLinePolicy, LexerConfig};
+	use crate::structure_error::{StructureError, StructureErrorKind};
+	use crate::token::{Token, TokenKind};
+	use crate::secondary_lexers::{lex_with_config, lex_with_token_kinds};
+	use super::verify_structure;
+
+	type Queue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+
+	#[test]
+	fn can_verify_lexed_kinds() {
+		let source = b"a\n\tb:\tc\n\t\td|\n# e";
+
+		let (_, kinds): (Queue, Vec<TokenKind>)
+			= lex_with_token_kinds(&&source[..], VecDeque::new());
+
+		assert_eq!(verify_structure(&kinds), Ok(()));
+		assert_eq!(verify_structure(&[]), Ok(()));
+	}
+
+	#[test]
+	fn cannot_verify_consecutive_scope_levels() {
+		let source = b"a\n\t\t\nb";
+
+		let (_, kinds): (Queue, Vec<TokenKind>)
+			= lex_with_token_kinds(&&source[..], VecDeque::new());
+
+		assert_eq!(verify_structure(&kinds), Err(StructureError {
+			kind: StructureErrorKind::ConsecutiveScopeLevels,
+			token_index: 2
+		}));
+	}
+
+	#[test]
+	fn can_verify_kinds_without_blank_lines() {
+		let source = b"a\n\t\t\nb";
+		let config = LexerConfig {
+			blank_line_policy: BlankLinePolicy::Ignore,
+			..LexerConfig::default()
+		};
+
+		let queue: Queue = lex_with_config(&&source[..], VecDeque::new(), &config).unwrap();
+		let kinds = queue.iter().map(|token| token.kind()).collect::<Vec<_>>();
+
+		assert_eq!(verify_structure(&kinds), Ok(()));
+	}
+
+	#[test]
+	fn cannot_verify_kinds_with_intermediate_scope_levels() {
+		let source = b"a\n\tb\n\t\tc\nd";
+		let config = LexerConfig {
+			emit_intermediate_scope_levels: true,
+			..LexerConfig::default()
+		};
+
+		let queue: Queue = lex_with_config(&&source[..], VecDeque::new(), &config).unwrap();
+		let kinds = queue.iter().map(|token| token.kind()).collect::<Vec<_>>();
+
+		assert_eq!(verify_structure(&kinds), Err(StructureError {
+			kind: StructureErrorKind::ConsecutiveScopeLevels,
+			token_index: 6
+		}));
+
+		let queue: Queue = lex_with_config(&&source[..], VecDeque::new(), &LexerConfig::default())
+			.unwrap();
+		let kinds = queue.iter().map(|token| token.kind()).collect::<Vec<_>>();
+
+		assert_eq!(verify_structure(&kinds), Ok(()));
+	}
+
+	#[test]
+	fn cannot_verify_kinds_with_newlines() {
+		let source = b"a\n\tb\n\nc";
+		let config = LexerConfig {
+			emit_newlines: true,
+			..LexerConfig::default()
+		};
+
+		let queue: Queue = lex_with_config(&&source[..], VecDeque::new(), &config).unwrap();
+		let kinds = queue.iter().map(|token| token.kind()).collect::<Vec<_>>();
+
+		assert_eq!(verify_structure(&kinds), Err(StructureError {
+			kind: StructureErrorKind::ConsecutiveScopeLevels,
+			token_index: 2
+		}));
+
+		let config = LexerConfig {
+			blank_line_policy: BlankLinePolicy::Ignore,
+			..LexerConfig::default()
+		};
+		let queue: Queue = lex_with_config(&&source[..], VecDeque::new(), &config).unwrap();
+		let kinds = queue.iter().map(|token| token.kind()).collect::<Vec<_>>();
+
+		assert_eq!(verify_structure(&kinds), Ok(()));
+	}
+}
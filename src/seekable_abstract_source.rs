@@ -0,0 +1,38 @@
+use crate::abstracts::AbstractSource;
+
+/// Represents a source which can give a view of its part without consuming itself.
+///
+/// The abstract sources can only be sliced by value so lexers clone them first. The view may be
+/// created in a cheaper way if the source supports random access.
+///
+/// ## Notes
+/// All sources that can be cloned can give a view by cloning then slicing them. Sources that
+/// cannot be cloned may implement the trait directly.
+pub trait SeekableAbstractSource: AbstractSource {
+	/// Returns a view of the source from the start index up to the end index (exclusive).
+	fn view(&self, start: usize, end: usize) -> Self;
+}
+
+impl<T> SeekableAbstractSource for T
+where
+	T: AbstractSource + Clone {
+	fn view(&self, start: usize, end: usize) -> Self {
+		self.clone().slice(start, end)
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::abstracts::ComparableAbstractSource;
+	use super::SeekableAbstractSource;
+
+	#[test]
+	fn can_view_part_of_byte_slice() {
+		let source = &b"abc|def"[..];
+		let view = source.view(3, 5);
+
+		assert_eq!(view, &b"|d"[..]);
+		assert!(view.is_same_needle_at(0, "|"));
+		assert_eq!(source.len(), 7);
+	}
+}
@@ -0,0 +1,91 @@
+use crate::abstracts::{AbstractSource, ComparableAbstractSource};
+use crate::native::{Range, Vec};
+use crate::special_characters::NEW_LINE;
+
+/// Contains the offsets where the lines of a source start.
+///
+/// The lines of the tokens can be found from their byte ranges. Therefore, the source map stays
+/// correct even if the token queue is reordered or filtered.
+///
+/// ## Examples
+/// ```
+/// use chearmyp_lexer::SourceMap;
+///
+/// let source_map = SourceMap::new(&&b"a\n\tb|\n# c"[..]);
+/// assert_eq!(source_map.line_count(), 3);
+/// assert_eq!(source_map.line_of(0), 1);
+/// assert_eq!(source_map.line_of(3), 2);
+/// assert_eq!(source_map.line_of_token(6..9), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+	line_starts: Vec<usize>
+}
+
+impl SourceMap {
+	/// Creates a source map by finding every new line in the source.
+	pub fn new<T>(src: &T) -> Self
+	where
+		T: AbstractSource + ComparableAbstractSource<&'static str> {
+		let mut line_starts = vec![0];
+		let mut offset = 0;
+		while !src.is_empty_at(offset) {
+			if src.is_same_needle_at(offset, NEW_LINE) {
+				line_starts.push(offset + 1);
+			}
+
+			offset += 1;
+		}
+
+		Self { line_starts }
+	}
+
+	/// Returns the number of the line where the byte at the offset belongs. The first line is line
+	/// 1. A new line belongs to the line it ends.
+	pub fn line_of(&self, byte_offset: usize) -> usize {
+		self.line_starts.partition_point(|&line_start| line_start <= byte_offset)
+	}
+
+	/// Returns the number of the line where the token occupying the byte range starts.
+	pub fn line_of_token(&self, byte_range: Range<usize>) -> usize {
+		self.line_of(byte_range.start)
+	}
+
+	/// Returns the offset where the line starts if the line exists.
+	pub fn line_start(&self, line: usize) -> Option<usize> {
+		self.line_starts.get(line.checked_sub(1)?).copied()
+	}
+
+	/// Returns the number of lines in the source.
+	pub fn line_count(&self) -> usize {
+		self.line_starts.len()
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use super::SourceMap;
+
+	#[test]
+	fn can_find_lines_of_offsets() {
+		let source_map = SourceMap::new(&&b"ab\n\ncd\n"[..]);
+
+		assert_eq!(source_map.line_count(), 4);
+		assert_eq!(source_map.line_of(1), 1);
+		assert_eq!(source_map.line_of(2), 1);
+		assert_eq!(source_map.line_of(3), 2);
+		assert_eq!(source_map.line_of(4), 3);
+		assert_eq!(source_map.line_of(7), 4);
+		assert_eq!(source_map.line_of(100), 4);
+	}
+
+	#[test]
+	fn can_find_line_starts() {
+		let source_map = SourceMap::new(&&b"ab\n\ncd"[..]);
+
+		assert_eq!(source_map.line_start(0), None);
+		assert_eq!(source_map.line_start(1), Some(0));
+		assert_eq!(source_map.line_start(3), Some(4));
+		assert_eq!(source_map.line_start(4), None);
+	}
+}
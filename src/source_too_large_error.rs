@@ -0,0 +1,9 @@
+/// Contains the info of a source which has more bytes than allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceTooLargeError {
+	/// The number of bytes in the source.
+	pub actual_size: usize,
+
+	/// The maximum number of bytes allowed.
+	pub limit: usize
+}
@@ -6,3 +6,15 @@ pub const SPACE: &str = " ";
 pub const TAB: &str = "\t";
 pub const VERTICAL_LINE: &str = "|";
 pub const EQUAL_THEN_SPACE: &str = "= ";
+pub const SPACE_THEN_EQUAL: &str = " =";
+pub const QUOTATION_MARK: &str = "\"";
+pub const DOUBLE_COLON: &str = "::";
+pub const CARRIAGE_RETURN: &str = "\r";
+pub const TRIPLE_POUND_SIGN: &str = "###";
+pub const SEMICOLON: &str = ";";
+pub const LINE_SEPARATOR: &str = "\u{2028}";
+pub const PARAGRAPH_SEPARATOR: &str = "\u{2029}";
+pub const SPACED_ARROW: &str = " -> ";
+pub const BACKSLASH: &str = "\\";
+pub const TRIPLE_EQUAL: &str = "===";
+pub const PERIOD: &str = ".";
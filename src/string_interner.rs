@@ -0,0 +1,51 @@
+#[cfg(feature = "no_std")]
+use crate::native::BTreeMap;
+#[cfg(not(feature = "no_std"))]
+use crate::native::HashMap;
+use crate::native::String;
+
+/// Contains the IDs of the strings found in the interned tokens.
+///
+/// Each distinct string receives the next ID once, starting from 0. Therefore, equal strings always
+/// have equal IDs within the same interner.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StringInterner {
+	#[cfg(not(feature = "no_std"))]
+	ids: HashMap<String, u32>,
+
+	#[cfg(feature = "no_std")]
+	ids: BTreeMap<String, u32>
+}
+
+impl StringInterner {
+	/// Creates an interner without any string.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the ID of the string. The string is stored if it has not been interned yet.
+	pub fn intern(&mut self, text: &str) -> u32 {
+		if let Some(&id) = self.ids.get(text) {
+			return id;
+		}
+
+		let id = self.ids.len() as u32;
+		self.ids.insert(String::from(text), id);
+		id
+	}
+
+	/// Returns the ID of the string if it has been interned.
+	pub fn id_of(&self, text: &str) -> Option<u32> {
+		self.ids.get(text).copied()
+	}
+
+	/// Returns the number of strings interned.
+	pub fn len(&self) -> usize {
+		self.ids.len()
+	}
+
+	/// Returns true if there are no strings interned.
+	pub fn is_empty(&self) -> bool {
+		self.ids.is_empty()
+	}
+}
@@ -0,0 +1,16 @@
+/// Contains the kinds of invalid sequence of token kinds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureErrorKind {
+	/// A scope level token is followed by another scope level token.
+	ConsecutiveScopeLevels
+}
+
+/// Contains the info of the first invalid sequence found in the kinds of the tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StructureError {
+	/// The kind of invalid sequence.
+	pub kind: StructureErrorKind,
+
+	/// The index of the token kind that made the sequence invalid.
+	pub token_index: usize
+}
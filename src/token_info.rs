@@ -1,3 +1,9 @@
+use crate::native::Range;
+
 /// Contains the extracted token and its last index occupied in the source.
 /// This token is used as return value for some lexers.
 pub type TokenInfo<T> = (T, usize);
+
+/// Contains the extracted token and the byte range it occupies in the source.
+/// This token is used as return value for some lexers which need to know where the token starts.
+pub type SpannedTokenInfo<T> = (T, Range<usize>);
@@ -0,0 +1,67 @@
+use crate::raw_token::{RawToken, RawTokenKind};
+
+/// Contains the name of the lexer tried by `any_traced()` and what it returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEvent {
+	/// The name of the lexer function that was tried.
+	pub lexer_name: &'static str,
+
+	/// The result of the lexer.
+	pub result: TraceResult
+}
+
+/// Contains the possible results of the lexers tried by `any_traced()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceResult {
+	/// The lexer returned the raw token that it was tried for.
+	Matched,
+
+	/// The lexer did not return the raw token that it was tried for so the next lexer may be tried.
+	Invalid {
+		/// Why the raw token returned by the lexer was not matched.
+		reason: InvalidReason
+	}
+}
+
+/// Contains the reasons why a lexer tried by `any_traced()` did not match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidReason {
+	/// There is no character at the offset.
+	EndOfSource,
+
+	/// The lexer did not recognize the source at the offset.
+	Unrecognized,
+
+	/// The lexer returned another kind of raw token than the one it was tried for.
+	OtherRawToken(RawTokenKind)
+}
+
+impl<T, U> From<&RawToken<T, U>> for InvalidReason {
+	#[inline]
+	fn from(raw_token: &RawToken<T, U>) -> Self {
+		match raw_token {
+			RawToken::Empty => Self::EndOfSource,
+			RawToken::Invalid => Self::Unrecognized,
+			raw_token => Self::OtherRawToken(raw_token.kind())
+		}
+	}
+}
+
+#[cfg(test)]
+mod t {
+	use crate::native::{Range, Vec};
+	use crate::raw_token::{RawToken, RawTokenKind};
+	use super::InvalidReason;
+
+	type SampleRawToken = RawToken<Range<usize>, Vec<Range<usize>>>;
+
+	#[test]
+	fn can_tell_reasons_of_raw_tokens() {
+		assert_eq!(InvalidReason::from(&SampleRawToken::Empty), InvalidReason::EndOfSource);
+		assert_eq!(InvalidReason::from(&SampleRawToken::Invalid), InvalidReason::Unrecognized);
+		assert_eq!(
+			InvalidReason::from(&SampleRawToken::AttacherNoContent(0..1)),
+			InvalidReason::OtherRawToken(RawTokenKind::AttacherNoContent)
+		);
+	}
+}
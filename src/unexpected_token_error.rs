@@ -0,0 +1,14 @@
+use crate::native::String;
+
+/// Contains the info of the unexpected raw token returned by the last possible lexer or of the
+/// block raw token rejected by the fallible lexers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnexpectedTokenError {
+	/// The offset in the source where the token was being lexed.
+	pub byte_offset: usize,
+
+	/// The debug representation of the kind of the unexpected raw token, not of the raw token
+	/// itself. The raw tokens returned by the last possible lexer in unexpected cases have no
+	/// content so the kind is enough. The kind of a rejected block tells why it was rejected.
+	pub raw_token_kind_debug: String
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "memchr")]
+
+use chearmyp_lexer::helpers::{find_line_ending, find_line_ending_memchr};
+
+#[test]
+fn can_find_same_line_endings_in_long_lines() {
+	let mut content = Vec::new();
+	for line_size in [0, 1, 31, 32, 33, 4_096] {
+		content.extend(std::iter::repeat(b'a').take(line_size));
+		content.push(b'\n');
+	}
+	content.extend(std::iter::repeat(b'b').take(10_000));
+
+	for offset in 0..=content.len() + 1 {
+		assert_eq!(
+			find_line_ending_memchr(&&content[..], offset),
+			find_line_ending(&&content[..], offset),
+			"Offset {}",
+			offset
+		);
+	}
+}
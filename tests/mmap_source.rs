@@ -0,0 +1,49 @@
+#![cfg(feature = "mmap")]
+
+use std::ops::Range;
+use std::collections::VecDeque;
+use chearmyp_lexer::lex;
+use chearmyp_lexer::helpers::MmapSource;
+use chearmyp_token::Token;
+
+type SampleQueue = VecDeque<Token<Range<usize>, Vec<Range<usize>>>>;
+
+#[test]
+fn can_lex_same_as_byte_slice() {
+	let section = b"# a comment\nconcept\n\tname:\tvalue\n\tsimplex|\n\t= othertongue\n\
+		\t===\n\t\tblock\n\t===\n";
+	let mut content = Vec::new();
+	for _ in 0..10_000 {
+		content.extend_from_slice(section);
+	}
+
+	let path = std::env::temp_dir().join("chearmyp_lexer_mmap_source_test.txt");
+	std::fs::write(&path, &content).unwrap();
+
+	let source = MmapSource::open(&path).unwrap();
+	let mapped_queue: SampleQueue = lex(&source, VecDeque::new());
+	let sliced_queue: SampleQueue = lex(&&content[..], VecDeque::new());
+
+	std::fs::remove_file(&path).unwrap();
+	assert_eq!(mapped_queue, sliced_queue);
+	assert!(!mapped_queue.is_empty());
+}
+
+#[test]
+fn can_be_sliced_past_its_end() {
+	use abstract_chearmyp_source::AbstractSource;
+	use chearmyp_lexer::MeasurableAbstractSource;
+
+	let path = std::env::temp_dir().join("chearmyp_lexer_mmap_source_slice_test.txt");
+	std::fs::write(&path, b"a\nb").unwrap();
+
+	let source = MmapSource::open(&path).unwrap();
+	let sliced = source.clone().forward_slice(5);
+	let widened = source.clone().slice(1, 2).slice(0, 10);
+
+	std::fs::remove_file(&path).unwrap();
+	assert!(sliced.is_empty_at(0));
+	assert_eq!(sliced.byte_len(), 0);
+	assert_eq!(sliced.as_bytes(), b"");
+	assert_eq!(widened.as_bytes(), b"\n");
+}